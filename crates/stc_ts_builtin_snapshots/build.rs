@@ -0,0 +1,36 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+use rayon::prelude::*;
+use stc_ts_builtin_types::SNAPSHOT_LIBS;
+use stc_ts_file_analyzer::env::create_builtin_snapshot;
+
+/// Analyzes the default libraries of each target and embeds them.
+///
+/// Cargo runs this again if the analyzer or the libraries, which are
+/// build-dependencies, are changed.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+
+    let snapshots = SNAPSHOT_LIBS
+        .par_iter()
+        .map(|lib| {
+            let (name, data) = create_builtin_snapshot(lib);
+
+            let path = out_dir.join(format!("{}.rmp", name));
+            fs::write(&path, data).unwrap_or_else(|err| panic!("failed to write snapshot at {:?}: {:?}", path, err));
+
+            (name, path)
+        })
+        .collect::<Vec<_>>();
+
+    let mut code = String::from("static SNAPSHOTS: &[(&str, &[u8])] = &[\n");
+    for (name, path) in &snapshots {
+        writeln!(code, "    ({:?}, include_bytes!({:?})),", name, path).unwrap();
+    }
+    code.push_str("];\n");
+
+    fs::write(out_dir.join("snapshots.rs"), code).unwrap();
+}
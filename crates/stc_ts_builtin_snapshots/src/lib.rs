@@ -0,0 +1,9 @@
+//! Snapshots of the default libraries of each target, which are created while
+//! building this crate.
+
+include!(concat!(env!("OUT_DIR"), "/snapshots.rs"));
+
+/// Makes `Env::simple` and `BuiltIn::from_ts_libs` use the embedded snapshots.
+pub fn register() {
+    stc_ts_builtin_types::set_snapshots(SNAPSHOTS);
+}
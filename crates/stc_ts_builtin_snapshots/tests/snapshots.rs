@@ -0,0 +1,31 @@
+use stc_ts_builtin_types::{snapshot, Lib, SNAPSHOT_LIBS};
+use stc_ts_env::{BuiltIn, StableEnv};
+use stc_ts_file_analyzer::env::BuiltInGen;
+use swc_common::GLOBALS;
+
+fn encode(env: &StableEnv, f: impl FnOnce() -> BuiltIn) -> Vec<u8> {
+    let builtin = GLOBALS.set(env.swc_globals(), f);
+
+    rmp_serde::encode::to_vec(&builtin).unwrap()
+}
+
+#[test]
+fn snapshots_match_analysis() {
+    stc_ts_builtin_snapshots::register();
+
+    for name in SNAPSHOT_LIBS {
+        let mut libs = Lib::load(name);
+        libs.sort();
+        libs.dedup();
+
+        let data = snapshot(&libs).unwrap_or_else(|| panic!("snapshot of `{}` is not embedded", name));
+
+        let env = StableEnv::default();
+        let loaded = encode(&env, || BuiltIn::from_ts_libs(&env, &libs));
+        assert!(loaded == data, "snapshot of `{}` should be used", name);
+
+        let env = StableEnv::default();
+        let analyzed = encode(&env, || BuiltIn::analyze_ts_libs(&env, &libs));
+        assert!(loaded == analyzed, "snapshot of `{}` is stale", name);
+    }
+}
@@ -0,0 +1,3 @@
+/////////////////////////////
+/// Window Async Iterable APIs
+/////////////////////////////
@@ -0,0 +1,2 @@
+/// <reference lib="es2022" />
+/// <reference lib="es2023.array" />
@@ -1,5 +1,2 @@
-/// <reference lib="es2020" />
+/// <reference lib="es2023" />
 /// <reference lib="esnext.intl" />
-/// <reference lib="esnext.string" />
-/// <reference lib="esnext.promise" />
-/// <reference lib="esnext.weakref" />
@@ -2,4 +2,5 @@
 /// <reference lib="dom" />
 /// <reference lib="webworker.importscripts" />
 /// <reference lib="scripthost" />
-/// <reference lib="dom.iterable" />
\ No newline at end of file
+/// <reference lib="dom.iterable" />
+/// <reference lib="dom.asynciterable" />
@@ -2,11 +2,12 @@
 
 use std::{
     cmp::Ordering,
+    hash::{Hash, Hasher},
     sync::{Arc, RwLock},
 };
 
-use fxhash::FxHashMap;
-use once_cell::sync::Lazy;
+use fxhash::{FxHashMap, FxHasher64};
+use once_cell::sync::{Lazy, OnceCell};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use stc_ts_builtin_macro::builtin;
 use swc_atoms::js_word;
@@ -20,6 +21,55 @@ use swc_ecma_visit::{span_remover, FoldWith};
 
 builtin!();
 
+static SNAPSHOTS: OnceCell<&'static [(&'static str, &'static [u8])]> = OnceCell::new();
+
+/// Libraries of which snapshots are embedded, which are the default libraries
+/// of each target.
+pub const SNAPSHOT_LIBS: &[&str] = &[
+    "es5.full",
+    "es2015.full",
+    "es2016.full",
+    "es2017.full",
+    "es2018.full",
+    "es2019.full",
+    "es2020.full",
+    "es2021.full",
+    "es2022.full",
+    "es2023.full",
+    "esnext.full",
+];
+
+/// Returns the file name of the snapshot of `libs`, without the extension.
+///
+/// `libs` should be sorted and deduplicated. The name also depends on the
+/// sources of `libs`, so a stale snapshot is not used.
+pub fn snapshot_name(libs: &[Lib]) -> String {
+    let mut hasher = FxHasher64::default();
+    for lib in libs {
+        format!("{:?}", lib).hash(&mut hasher);
+        lib.content().hash(&mut hasher);
+    }
+
+    format!("{:016x}", hasher.finish())
+}
+
+/// Registers snapshots embedded in the binary, as pairs of [snapshot_name] and
+/// the serialized `BuiltIn`.
+///
+/// Snapshots are created while building `stc_ts_builtin_snapshots`, which
+/// calls this from `register()`. Only the first call has effect.
+pub fn set_snapshots(snapshots: &'static [(&'static str, &'static [u8])]) {
+    let _ = SNAPSHOTS.set(snapshots);
+}
+
+/// Returns the serialized `BuiltIn` of `libs`, if it's registered with
+/// [set_snapshots].
+pub fn snapshot(libs: &[Lib]) -> Option<&'static [u8]> {
+    let name = snapshot_name(libs);
+
+    SNAPSHOTS.get()?.iter().find(|(n, _)| *n == name).map(|(_, data)| *data)
+}
+
 impl Lib {
     pub fn load(lib_str: &str) -> Vec<Self> {
         let lib: Self = match lib_str.parse() {
@@ -56,56 +106,74 @@ impl Lib {
 
     fn prec(&self) -> usize {
         match self {
-            Self::Es5 => 1,
-            Self::Es2015Core => 2,
-            Self::Es2015Collection => 3,
-            Self::Es2015Symbol => 4,
-            Self::Es2015Iterable => 5,
-            Self::Es2015Generator => 6,
-            Self::Es2015Promise => 7,
-            Self::Es2015Proxy => 8,
-            Self::Es2015Reflect => 9,
-            Self::Es2015SymbolWellknown => 10,
-            Self::Es2015 => 11,
-            Self::Es2016ArrayInclude => 12,
-            Self::Es2016 => 13,
-            Self::Es2017Object => 14,
-            Self::Es2017Sharedmemory => 15,
-            Self::Es2017String => 16,
-            Self::Es2017Intl => 17,
-            Self::Es2017Typedarrays => 18,
-            Self::Es2017 => 19,
-            Self::Es2018Asynciterable => 20,
-            Self::Es2018Asyncgenerator => 21,
-            Self::Es2018Promise => 22,
-            Self::Es2018Regexp => 23,
-            Self::Es2018Intl => 24,
-            Self::Es2018 => 25,
-            Self::Es2019Array => 26,
-            Self::Es2019Object => 27,
-            Self::Es2019String => 28,
-            Self::Es2019Symbol => 29,
-            Self::Es2019 => 30,
-            Self::Es2020Bigint => 31,
-            Self::Es2020Promise => 32,
-            Self::Es2020Sharedmemory => 33,
-            Self::Es2020String => 34,
-            Self::Es2020SymbolWellknown => 35,
-            Self::Es2020Intl => 36,
-            Self::Es2020 => 37,
-            Self::EsnextIntl => 38,
-            Self::EsnextString => 39,
-            Self::EsnextPromise => 40,
-            Self::EsnextWeakref => 41,
-            Self::Esnext => 42,
-            Self::Dom => 43,
-            Self::WebworkerImportscripts => 44,
-            Self::Scripthost => 45,
-            Self::DomIterable => 46,
-            Self::DomIterableGenerated => 47,
-            Self::Header => 48,
-            Self::WebworkerGenerated => 49,
-            Self::WebworkerIterableGenerated => 50,
+            Self::Decorators => 1,
+            Self::DecoratorsLegacy => 2,
+            Self::Es5 => 3,
+            Self::Es2015Core => 4,
+            Self::Es2015Collection => 5,
+            Self::Es2015Symbol => 6,
+            Self::Es2015Iterable => 7,
+            Self::Es2015Generator => 8,
+            Self::Es2015Promise => 9,
+            Self::Es2015Proxy => 10,
+            Self::Es2015Reflect => 11,
+            Self::Es2015SymbolWellknown => 12,
+            Self::Es2015 => 13,
+            Self::Es2016ArrayInclude => 14,
+            Self::Es2016 => 15,
+            Self::Es2017Object => 16,
+            Self::Es2017Sharedmemory => 17,
+            Self::Es2017String => 18,
+            Self::Es2017Intl => 19,
+            Self::Es2017Typedarrays => 20,
+            Self::Es2017 => 21,
+            Self::Es2018Asynciterable => 22,
+            Self::Es2018Asyncgenerator => 23,
+            Self::Es2018Promise => 24,
+            Self::Es2018Regexp => 25,
+            Self::Es2018Intl => 26,
+            Self::Es2018 => 27,
+            Self::Es2019Array => 28,
+            Self::Es2019Object => 29,
+            Self::Es2019String => 30,
+            Self::Es2019Symbol => 31,
+            Self::Es2019 => 32,
+            Self::Es2020Bigint => 33,
+            Self::Es2020Promise => 34,
+            Self::Es2020Sharedmemory => 35,
+            Self::Es2020String => 36,
+            Self::Es2020SymbolWellknown => 37,
+            Self::Es2020Intl => 38,
+            Self::Es2020 => 39,
+            Self::Es2021Promise => 40,
+            Self::Es2021String => 41,
+            Self::Es2021Weakref => 42,
+            Self::Es2021Intl => 43,
+            Self::Es2021 => 44,
+            Self::Es2022Array => 45,
+            Self::Es2022Error => 46,
+            Self::Es2022Intl => 47,
+            Self::Es2022Object => 48,
+            Self::Es2022Sharedmemory => 49,
+            Self::Es2022String => 50,
+            Self::Es2022Regexp => 51,
+            Self::Es2022 => 52,
+            Self::Es2023Array => 53,
+            Self::Es2023 => 54,
+            Self::EsnextIntl => 55,
+            Self::EsnextString => 56,
+            Self::EsnextPromise => 57,
+            Self::EsnextWeakref => 58,
+            Self::Esnext => 59,
+            Self::Dom => 60,
+            Self::WebworkerImportscripts => 61,
+            Self::Scripthost => 62,
+            Self::DomIterable => 63,
+            Self::DomAsynciterable => 64,
+            Self::DomIterableGenerated => 65,
+            Self::Header => 66,
+            Self::WebworkerGenerated => 67,
+            Self::WebworkerIterableGenerated => 68,
 
             Self::Es5Full => 100,
             Self::Es2015Full => 101,
@@ -114,7 +182,10 @@ impl Lib {
             Self::Es2018Full => 104,
             Self::Es2019Full => 105,
             Self::Es2020Full => 106,
-            Self::EsnextFull => 107,
+            Self::Es2021Full => 107,
+            Self::Es2022Full => 108,
+            Self::Es2023Full => 109,
+            Self::EsnextFull => 110,
         }
     }
 }
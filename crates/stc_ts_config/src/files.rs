@@ -0,0 +1,173 @@
+//! Expansion of `files`, `include` and `exclude`.
+
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{Context, Error};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use path_clean::PathClean;
+use walkdir::WalkDir;
+
+/// Extensions of files matched by `include` patterns.
+static EXTENSIONS: &[&str] = &[".d.ts", ".d.mts", ".d.cts", ".ts", ".tsx", ".mts", ".cts"];
+
+/// Directories excluded when `exclude` is not specified.
+static DEFAULT_EXCLUDE: &[&str] = &["node_modules", "bower_components", "jspm_packages"];
+
+/// Input of [expand]. All paths and patterns are absolute.
+#[derive(Debug, Default)]
+pub(crate) struct FileSpecs {
+    pub files: Vec<PathBuf>,
+    pub include: Vec<String>,
+    pub exclude: Option<Vec<String>>,
+}
+
+/// Returns the sorted list of files matched by `specs`.
+pub(crate) fn expand(root: &Path, specs: &FileSpecs) -> Result<Vec<PathBuf>, Error> {
+    let exclude = match &specs.exclude {
+        Some(exclude) => exclude.clone(),
+        None => DEFAULT_EXCLUDE
+            .iter()
+            .map(|dir| root.join(dir).to_string_lossy().into_owned())
+            .collect(),
+    };
+    let exclude = build_glob_set(&exclude, true)?;
+
+    let mut matched = vec![];
+
+    for pat in &specs.include {
+        let pat = normalize_pattern(pat);
+        let glob = build_glob_set(&[pat.clone()], false)?;
+
+        let base = wildcard_free_prefix(Path::new(&pat));
+        if base.is_file() {
+            if glob.is_match(&base) {
+                matched.push(base);
+            }
+            continue;
+        }
+
+        let walker = WalkDir::new(&base)
+            .follow_links(true)
+            .into_iter()
+            .filter_entry(|entry| !(entry.file_type().is_dir() && exclude.is_match(entry.path())));
+
+        for entry in walker {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    tracing::warn!("failed to read an entry while expanding `{}`: {:?}", pat, err);
+                    continue;
+                }
+            };
+            let path = entry.path();
+
+            if !entry.file_type().is_file() || !has_supported_extension(path) {
+                continue;
+            }
+
+            if glob.is_match(path) && !exclude.is_match(path) {
+                matched.push(path.to_path_buf());
+            }
+        }
+    }
+
+    remove_shadowed_dts(&mut matched);
+
+    // Files specified by `files` are not affected by `exclude`.
+    matched.extend(specs.files.iter().cloned());
+
+    matched.sort();
+    matched.dedup();
+
+    Ok(matched)
+}
+
+/// The last component of a pattern without an extension or a wildcard is
+/// treated as a directory.
+fn normalize_pattern(pat: &str) -> String {
+    let last = Path::new(pat)
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    if !last.contains('*') && !last.contains('?') && !last.contains('.') {
+        format!("{}/**/*", pat.trim_end_matches('/'))
+    } else {
+        pat.to_string()
+    }
+}
+
+fn build_glob_set(patterns: &[String], match_dir_contents: bool) -> Result<GlobSet, Error> {
+    let mut builder = GlobSetBuilder::new();
+
+    for pat in patterns {
+        let pat = Path::new(pat).clean().to_string_lossy().into_owned();
+
+        builder.add(
+            GlobBuilder::new(&pat)
+                .literal_separator(true)
+                .build()
+                .with_context(|| format!("invalid pattern `{}`", pat))?,
+        );
+
+        if match_dir_contents {
+            builder.add(
+                GlobBuilder::new(&format!("{}/**", pat.trim_end_matches('/')))
+                    .literal_separator(true)
+                    .build()
+                    .with_context(|| format!("invalid pattern `{}`", pat))?,
+            );
+        }
+    }
+
+    builder.build().context("failed to build glob set")
+}
+
+fn wildcard_free_prefix(pat: &Path) -> PathBuf {
+    let mut buf = PathBuf::new();
+
+    for c in pat.components() {
+        if let Component::Normal(s) = c {
+            let s = s.to_string_lossy();
+            if s.contains('*') || s.contains('?') {
+                break;
+            }
+        }
+
+        buf.push(c);
+    }
+
+    buf.clean()
+}
+
+fn has_supported_extension(path: &Path) -> bool {
+    let s = path.to_string_lossy();
+
+    EXTENSIONS.iter().any(|ext| s.ends_with(ext))
+}
+
+/// `foo.d.ts` is not included if `foo.ts` or `foo.tsx` is included. Likewise,
+/// `foo.d.mts` and `foo.d.cts` are shadowed by `foo.mts` and `foo.cts`.
+fn remove_shadowed_dts(files: &mut Vec<PathBuf>) {
+    let shadowed = files
+        .iter()
+        .filter_map(|path| {
+            let s = path.to_string_lossy();
+            if [".d.ts", ".d.mts", ".d.cts"].iter().any(|ext| s.ends_with(ext)) {
+                return None;
+            }
+
+            let (stem, ext) = s.rsplit_once('.')?;
+            let dts_ext = match ext {
+                "ts" | "tsx" => "d.ts",
+                "mts" => "d.mts",
+                "cts" => "d.cts",
+                _ => return None,
+            };
+
+            Some(PathBuf::from(format!("{}.{}", stem, dts_ext)))
+        })
+        .collect::<Vec<_>>();
+
+    files.retain(|path| !shadowed.contains(path));
+}
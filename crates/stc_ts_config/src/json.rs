@@ -0,0 +1,126 @@
+//! `tsconfig.json` is not a strict json file. It allows comments and trailing
+//! commas, so we strip them before passing the content to `serde_json`.
+
+/// Replaces comments and trailing commas with whitespaces.
+///
+/// Characters are replaced instead of being removed, so line and column
+/// numbers reported by `serde_json` still point to the original source.
+pub(crate) fn strip_jsonc(src: &str) -> String {
+    let without_comments = strip_comments(src);
+    strip_trailing_commas(&without_comments)
+}
+
+fn strip_comments(src: &str) -> String {
+    let mut buf = String::with_capacity(src.len());
+    let mut chars = src.chars().peekable();
+    let mut in_str = false;
+
+    while let Some(c) = chars.next() {
+        if in_str {
+            buf.push(c);
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        buf.push(escaped);
+                    }
+                }
+                '"' => in_str = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_str = true;
+                buf.push(c);
+            }
+            ('/', Some('/')) => {
+                // Line comment
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        buf.push('\n');
+                        break;
+                    }
+                    buf.push(' ');
+                }
+            }
+            ('/', Some('*')) => {
+                // Block comment
+                chars.next();
+                buf.push_str("  ");
+
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        buf.push('\n');
+                    } else {
+                        buf.push(' ');
+                    }
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+fn strip_trailing_commas(src: &str) -> String {
+    let mut buf = src.chars().collect::<Vec<_>>();
+    let mut in_str = false;
+    let mut idx = 0;
+
+    while idx < buf.len() {
+        let c = buf[idx];
+        if in_str {
+            match c {
+                '\\' => idx += 1,
+                '"' => in_str = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_str = true;
+        } else if c == ',' {
+            let next = buf[idx + 1..].iter().copied().find(|c| !c.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                buf[idx] = ' ';
+            }
+        }
+
+        idx += 1;
+    }
+
+    buf.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_jsonc;
+
+    #[test]
+    fn comments() {
+        let src = r#"{
+            // line comment
+            "a": "// not a comment", /* block
+            comment */ "b": 1
+        }"#;
+
+        let v: serde_json::Value = serde_json::from_str(&strip_jsonc(src)).unwrap();
+        assert_eq!(v["a"], "// not a comment");
+        assert_eq!(v["b"], 1);
+    }
+
+    #[test]
+    fn trailing_commas() {
+        let src = r#"{ "a": [1, 2,], "b": { "c": "\",}", }, }"#;
+
+        let v: serde_json::Value = serde_json::from_str(&strip_jsonc(src)).unwrap();
+        assert_eq!(v["a"], serde_json::json!([1, 2]));
+        assert_eq!(v["b"]["c"], "\",}");
+    }
+}
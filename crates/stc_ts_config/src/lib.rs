@@ -0,0 +1,315 @@
+//! Loader for `tsconfig.json`.
+//!
+//! This crate resolves `extends` chains, merges `compilerOptions` and expands
+//! `files` / `include` / `exclude` into the list of files to check.
+#![deny(unused)]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Error};
+use path_clean::PathClean;
+use serde::Deserialize;
+
+pub use self::options::{default_libs, parse_module, parse_module_resolution, parse_target, CompilerOptions};
+use self::{
+    files::{expand, FileSpecs},
+    json::strip_jsonc,
+};
+
+mod files;
+mod json;
+mod options;
+
+pub const CONFIG_FILE_NAME: &str = "tsconfig.json";
+
+/// Fully resolved configuration of a project.
+#[derive(Debug, Clone)]
+pub struct ProjectConfig {
+    /// Path to the root `tsconfig.json`.
+    pub path: PathBuf,
+    /// Merged `compilerOptions`, including inherited ones.
+    pub compiler_options: CompilerOptions,
+    /// Absolute paths of files matched by `files`, `include` and `exclude`.
+    pub files: Vec<PathBuf>,
+    /// Absolute paths of the root `tsconfig.json` and config files it extends.
+    pub config_files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawConfig {
+    #[serde(default)]
+    extends: Option<Extends>,
+    #[serde(default)]
+    compiler_options: CompilerOptions,
+    #[serde(default)]
+    files: Option<Vec<String>>,
+    #[serde(default)]
+    include: Option<Vec<String>>,
+    #[serde(default)]
+    exclude: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Extends {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// A config file with `extends` applied and relative paths resolved.
+#[derive(Debug, Default)]
+struct Resolved {
+    compiler_options: CompilerOptions,
+    files: Option<Vec<PathBuf>>,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    config_files: Vec<PathBuf>,
+}
+
+impl ProjectConfig {
+    /// Loads a project from `path`, which may be a `tsconfig.json` or a
+    /// directory containing one.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let path = if path.is_dir() {
+            path.join(CONFIG_FILE_NAME)
+        } else {
+            path.to_path_buf()
+        };
+        let path = absolute(&path)?;
+        let root = path.parent().expect("config file should have a parent directory").to_path_buf();
+
+        let resolved = load_resolved(&path, &mut vec![])?;
+
+        let specs = match (resolved.files, resolved.include) {
+            (files, Some(include)) => FileSpecs {
+                files: files.unwrap_or_default(),
+                include,
+                exclude: resolved.exclude,
+            },
+            (Some(files), None) => FileSpecs {
+                files,
+                include: vec![],
+                exclude: resolved.exclude,
+            },
+            (None, None) => FileSpecs {
+                files: vec![],
+                include: vec![root.join("**/*").to_string_lossy().into_owned()],
+                exclude: resolved.exclude,
+            },
+        };
+
+        let files = expand(&root, &specs).with_context(|| format!("failed to expand files of `{}`", path.display()))?;
+
+        Ok(ProjectConfig {
+            path,
+            compiler_options: resolved.compiler_options,
+            files,
+            config_files: resolved.config_files,
+        })
+    }
+
+    /// Directory containing the root `tsconfig.json`.
+    pub fn dir(&self) -> &Path {
+        self.path.parent().expect("config file should have a parent directory")
+    }
+}
+
+/// Searches `tsconfig.json` from `dir` and its ancestors.
+pub fn find_config(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors().map(|dir| dir.join(CONFIG_FILE_NAME)).find(|path| path.is_file())
+}
+
+fn load_resolved(path: &Path, stack: &mut Vec<PathBuf>) -> Result<Resolved, Error> {
+    if stack.iter().any(|p| p == path) {
+        bail!("circularity detected while resolving `extends` of `{}`", path.display())
+    }
+    stack.push(path.to_path_buf());
+
+    let dir = path.parent().expect("config file should have a parent directory");
+
+    let content = fs::read_to_string(path).with_context(|| format!("failed to read `{}`", path.display()))?;
+    let raw: RawConfig = serde_json::from_str(&strip_jsonc(&content)).with_context(|| format!("failed to parse `{}`", path.display()))?;
+
+    let mut resolved = Resolved {
+        compiler_options: raw.compiler_options,
+        files: raw
+            .files
+            .map(|files| files.into_iter().map(|file| dir.join(file).clean()).collect()),
+        include: raw
+            .include
+            .map(|pats| pats.into_iter().map(|pat| join_pattern(dir, &pat)).collect()),
+        exclude: raw
+            .exclude
+            .map(|pats| pats.into_iter().map(|pat| join_pattern(dir, &pat)).collect()),
+        config_files: vec![path.to_path_buf()],
+    };
+    resolved.compiler_options.resolve_paths(dir);
+
+    let bases = match raw.extends {
+        Some(Extends::One(base)) => vec![base],
+        Some(Extends::Many(bases)) => bases,
+        None => vec![],
+    };
+
+    // Later entries of `extends` override earlier ones.
+    for base in bases.iter().rev() {
+        let base_path = resolve_extends(dir, base).with_context(|| format!("failed to resolve `extends` of `{}`", path.display()))?;
+        let base = load_resolved(&base_path, stack)?;
+
+        resolved.compiler_options.inherit(base.compiler_options);
+        if resolved.files.is_none() {
+            resolved.files = base.files;
+        }
+        if resolved.include.is_none() {
+            resolved.include = base.include;
+        }
+        if resolved.exclude.is_none() {
+            resolved.exclude = base.exclude;
+        }
+        resolved.config_files.extend(base.config_files);
+    }
+
+    stack.pop();
+
+    Ok(resolved)
+}
+
+/// https://www.typescriptlang.org/tsconfig#extends
+fn resolve_extends(dir: &Path, specifier: &str) -> Result<PathBuf, Error> {
+    let with_json = |path: PathBuf| {
+        if path.is_file() {
+            return Some(path);
+        }
+        let path = PathBuf::from(format!("{}.json", path.display()));
+        if path.is_file() {
+            return Some(path);
+        }
+        None
+    };
+
+    if specifier.starts_with("./") || specifier.starts_with("../") || Path::new(specifier).is_absolute() {
+        return with_json(dir.join(specifier).clean()).with_context(|| format!("file not found: `{}`", specifier));
+    }
+
+    for dir in dir.ancestors() {
+        let path = dir.join("node_modules").join(specifier);
+
+        if let Some(path) = with_json(path.clone()) {
+            return Ok(path);
+        }
+
+        if path.is_dir() {
+            let config = path.join(CONFIG_FILE_NAME);
+            if config.is_file() {
+                return Ok(config);
+            }
+        }
+    }
+
+    bail!("failed to find `{}` in node_modules", specifier)
+}
+
+fn join_pattern(dir: &Path, pat: &str) -> String {
+    dir.join(pat.trim_start_matches("./")).to_string_lossy().into_owned()
+}
+
+fn absolute(path: &Path) -> Result<PathBuf, Error> {
+    if path.is_absolute() {
+        return Ok(path.clean());
+    }
+
+    let cwd = std::env::current_dir().context("failed to get the current directory")?;
+    Ok(cwd.join(path).clean())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use stc_ts_env::ModuleConfig;
+    use swc_ecma_ast::EsVersion;
+    use tempdir::TempDir;
+
+    use super::ProjectConfig;
+
+    #[test]
+    fn extends_and_include() {
+        let dir = TempDir::new("stc-config").unwrap();
+        let root = dir.path();
+
+        fs::create_dir_all(root.join("src/nested")).unwrap();
+        fs::create_dir_all(root.join("node_modules/dep")).unwrap();
+        fs::write(root.join("src/a.ts"), "").unwrap();
+        fs::write(root.join("src/nested/b.tsx"), "").unwrap();
+        fs::write(root.join("src/nested/b.d.ts"), "").unwrap();
+        fs::write(root.join("src/c.js"), "").unwrap();
+        fs::write(root.join("node_modules/dep/index.d.ts"), "").unwrap();
+
+        fs::write(
+            root.join("base.json"),
+            r#"{
+                // Comments are allowed
+                "compilerOptions": {
+                    "target": "ES2017",
+                    "strict": true,
+                },
+                "include": ["src"],
+            }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{
+                "extends": "./base",
+                "compilerOptions": {
+                    "module": "CommonJS",
+                    "noImplicitAny": false
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let config = ProjectConfig::load(root).unwrap();
+        let opts = &config.compiler_options;
+
+        assert_eq!(opts.target().unwrap(), EsVersion::Es2017);
+        assert!(matches!(opts.module().unwrap(), ModuleConfig::CommonJs));
+        assert!(opts.rule().strict_null_checks);
+        assert!(!opts.rule().no_implicit_any);
+
+        assert_eq!(config.files, vec![root.join("src/a.ts"), root.join("src/nested/b.tsx")]);
+        assert_eq!(config.config_files, vec![root.join("tsconfig.json"), root.join("base.json")]);
+    }
+
+    #[test]
+    fn paths_relative_to_declaring_config() {
+        let dir = TempDir::new("stc-config").unwrap();
+        let root = dir.path();
+
+        fs::create_dir_all(root.join("configs")).unwrap();
+        fs::write(
+            root.join("configs/base.json"),
+            r#"{
+                "compilerOptions": {
+                    "paths": { "@app/*": ["../src/*"] },
+                    "rootDirs": ["../src", "../generated"]
+                }
+            }"#,
+        )
+        .unwrap();
+        fs::write(root.join("tsconfig.json"), r#"{ "extends": "./configs/base.json", "files": [] }"#).unwrap();
+
+        let config = ProjectConfig::load(root).unwrap();
+        let opts = &config.compiler_options;
+
+        assert_eq!(
+            opts.path_mappings(),
+            vec![("@app/*".to_string(), vec![root.join("src/*").to_string_lossy().into_owned()])]
+        );
+        assert_eq!(opts.root_dirs, Some(vec![root.join("src"), root.join("generated")]));
+    }
+}
@@ -0,0 +1,403 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Error};
+use path_clean::PathClean;
+use serde::Deserialize;
+use stc_ts_builtin_types::Lib;
+use stc_ts_env::{JsxConfig, ModuleConfig, Rule};
+use stc_ts_module_loader::resolvers::node::ModuleResolution;
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::TsConfig;
+
+/// `compilerOptions` of a `tsconfig.json`.
+///
+/// All fields are optional so options inherited via `extends` can be merged
+/// using [CompilerOptions::inherit].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerOptions {
+    pub target: Option<String>,
+    pub module: Option<String>,
+    pub module_resolution: Option<String>,
+    pub lib: Option<Vec<String>>,
+    pub no_lib: Option<bool>,
+
+    pub types: Option<Vec<String>>,
+    pub type_roots: Option<Vec<PathBuf>>,
+
+    pub base_url: Option<PathBuf>,
+    pub paths: Option<BTreeMap<String, Vec<String>>>,
+    /// Directory of the `tsconfig.json` which declares `paths`, used if
+    /// `baseUrl` is not specified.
+    #[serde(skip)]
+    pub paths_base_path: Option<PathBuf>,
+    pub root_dirs: Option<Vec<PathBuf>>,
+
+    pub declaration: Option<bool>,
+    pub declaration_dir: Option<PathBuf>,
+    pub declaration_map: Option<bool>,
+    pub emit_declaration_only: Option<bool>,
+    pub isolated_declarations: Option<bool>,
+    pub out_dir: Option<PathBuf>,
+    pub root_dir: Option<PathBuf>,
+
+    pub incremental: Option<bool>,
+    pub ts_build_info_file: Option<PathBuf>,
+
+    pub experimental_decorators: Option<bool>,
+
+    pub jsx: Option<String>,
+    pub jsx_factory: Option<String>,
+    pub jsx_fragment_factory: Option<String>,
+    pub jsx_import_source: Option<String>,
+
+    pub strict: Option<bool>,
+    pub no_implicit_any: Option<bool>,
+    pub no_implicit_this: Option<bool>,
+    pub always_strict: Option<bool>,
+    pub strict_null_checks: Option<bool>,
+    pub strict_function_types: Option<bool>,
+    pub strict_bind_call_apply: Option<bool>,
+    pub strict_property_initialization: Option<bool>,
+    pub use_unknown_in_catch_variables: Option<bool>,
+    pub no_unchecked_indexed_access: Option<bool>,
+    pub exact_optional_property_types: Option<bool>,
+
+    pub allow_unreachable_code: Option<bool>,
+    pub allow_unused_labels: Option<bool>,
+    pub no_fallthrough_cases_in_switch: Option<bool>,
+    pub no_implicit_returns: Option<bool>,
+    pub suppress_excess_property_errors: Option<bool>,
+    pub suppress_implicit_any_index_errors: Option<bool>,
+    pub no_strict_generic_checks: Option<bool>,
+    pub no_unused_locals: Option<bool>,
+    pub no_unused_parameters: Option<bool>,
+    pub use_define_for_class_fields: Option<bool>,
+}
+
+macro_rules! inherit {
+    ($self:ident, $base:ident, [$($field:ident),* $(,)?]) => {
+        $(
+            if $self.$field.is_none() {
+                $self.$field = $base.$field;
+            }
+        )*
+    };
+}
+
+impl CompilerOptions {
+    /// Fills options which are not specified in `self` using `base`.
+    pub fn inherit(&mut self, base: CompilerOptions) {
+        inherit!(
+            self,
+            base,
+            [
+                target,
+                module,
+                module_resolution,
+                lib,
+                no_lib,
+                types,
+                type_roots,
+                base_url,
+                paths,
+                paths_base_path,
+                root_dirs,
+                declaration,
+                declaration_dir,
+                declaration_map,
+                emit_declaration_only,
+                isolated_declarations,
+                out_dir,
+                root_dir,
+                incremental,
+                ts_build_info_file,
+                experimental_decorators,
+                jsx,
+                jsx_factory,
+                jsx_fragment_factory,
+                jsx_import_source,
+                strict,
+                no_implicit_any,
+                no_implicit_this,
+                always_strict,
+                strict_null_checks,
+                strict_function_types,
+                strict_bind_call_apply,
+                strict_property_initialization,
+                use_unknown_in_catch_variables,
+                no_unchecked_indexed_access,
+                exact_optional_property_types,
+                allow_unreachable_code,
+                allow_unused_labels,
+                no_fallthrough_cases_in_switch,
+                no_implicit_returns,
+                suppress_excess_property_errors,
+                suppress_implicit_any_index_errors,
+                no_strict_generic_checks,
+                no_unused_locals,
+                no_unused_parameters,
+                use_define_for_class_fields,
+            ]
+        );
+    }
+
+    /// Paths in `compilerOptions` are relative to the `tsconfig.json` which
+    /// declares them, so they should be resolved before merging.
+    pub(crate) fn resolve_paths(&mut self, dir: &Path) {
+        if let Some(type_roots) = &mut self.type_roots {
+            for root in type_roots {
+                *root = dir.join(&*root).clean();
+            }
+        }
+
+        if let Some(base_url) = &mut self.base_url {
+            *base_url = dir.join(&*base_url).clean();
+        }
+
+        if self.paths.is_some() {
+            self.paths_base_path = Some(dir.to_path_buf());
+        }
+
+        if let Some(root_dirs) = &mut self.root_dirs {
+            for root in root_dirs {
+                *root = dir.join(&*root).clean();
+            }
+        }
+
+        for path in [
+            &mut self.declaration_dir,
+            &mut self.out_dir,
+            &mut self.root_dir,
+            &mut self.ts_build_info_file,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *path = dir.join(&*path).clean();
+        }
+    }
+
+    /// Returns patterns of `paths` with absolute substitutions.
+    ///
+    /// Substitutions are relative to `baseUrl`, or to the `tsconfig.json`
+    /// declaring `paths` if `baseUrl` is not specified.
+    pub fn path_mappings(&self) -> Vec<(String, Vec<String>)> {
+        let paths = match &self.paths {
+            Some(paths) => paths,
+            None => return vec![],
+        };
+        let base = match self.base_url.as_ref().or(self.paths_base_path.as_ref()) {
+            Some(base) => base,
+            None => return vec![],
+        };
+
+        paths
+            .iter()
+            .map(|(pat, substitutions)| {
+                let substitutions = substitutions
+                    .iter()
+                    .map(|sub| base.join(sub).clean().to_string_lossy().into_owned())
+                    .collect();
+
+                (pat.clone(), substitutions)
+            })
+            .collect()
+    }
+
+    pub fn target(&self) -> Result<EsVersion, Error> {
+        match &self.target {
+            Some(s) => parse_target(s),
+            None => Ok(EsVersion::Es5),
+        }
+    }
+
+    pub fn module(&self) -> Result<ModuleConfig, Error> {
+        if let Some(s) = &self.module {
+            return parse_module(s);
+        }
+
+        // https://www.typescriptlang.org/tsconfig#module
+        Ok(match self.target()? {
+            EsVersion::Es3 | EsVersion::Es5 => ModuleConfig::CommonJs,
+            _ => ModuleConfig::Es2015,
+        })
+    }
+
+    pub fn module_resolution(&self) -> Result<ModuleResolution, Error> {
+        if let Some(s) = &self.module_resolution {
+            return parse_module_resolution(s);
+        }
+
+        // https://www.typescriptlang.org/tsconfig#moduleResolution
+        Ok(match self.module()? {
+            ModuleConfig::Node16 => ModuleResolution::Node16,
+            ModuleConfig::NodeNext => ModuleResolution::NodeNext,
+            _ => ModuleResolution::Node10,
+        })
+    }
+
+    pub fn jsx(&self) -> Result<JsxConfig, Error> {
+        let mut jsx = JsxConfig::default();
+
+        if let Some(s) = &self.jsx {
+            jsx.mode = match s.to_ascii_lowercase().parse() {
+                Ok(v) => v,
+                Err(..) => bail!("unknown jsx mode `{}`", s),
+            };
+        }
+        if let Some(s) = &self.jsx_factory {
+            jsx.factory = s.clone().into();
+        }
+        if let Some(s) = &self.jsx_fragment_factory {
+            jsx.fragment_factory = s.clone().into();
+        }
+        if let Some(s) = &self.jsx_import_source {
+            jsx.import_source = s.clone().into();
+        }
+
+        Ok(jsx)
+    }
+
+    /// Builtin libraries to load, including their dependencies.
+    pub fn libs(&self) -> Result<Vec<Lib>, Error> {
+        if self.no_lib.unwrap_or(false) {
+            return Ok(vec![]);
+        }
+
+        let mut libs = match &self.lib {
+            Some(names) => {
+                let mut libs = vec![];
+                for name in names {
+                    let loaded = Lib::load(&lib_file_name(name));
+                    if loaded.is_empty() {
+                        bail!("unknown library `{}` specified in `compilerOptions.lib`", name)
+                    }
+                    libs.extend(loaded);
+                }
+                libs
+            }
+            None => match self.target.as_deref().map(str::to_ascii_lowercase).as_deref() {
+                // They are parsed as `Es2022`.
+                Some("es2023") => Lib::load("es2023.full"),
+                Some("esnext") => Lib::load("esnext.full"),
+                _ => default_libs(self.target()?),
+            },
+        };
+        libs.sort();
+        libs.dedup();
+
+        Ok(libs)
+    }
+
+    pub fn rule(&self) -> Rule {
+        let strict = self.strict.unwrap_or(false);
+
+        Rule {
+            no_implicit_any: self.no_implicit_any.unwrap_or(strict),
+            no_implicit_this: self.no_implicit_this.unwrap_or(strict),
+            always_strict: self.always_strict.unwrap_or(strict),
+            strict_null_checks: self.strict_null_checks.unwrap_or(strict),
+            strict_function_types: self.strict_function_types.unwrap_or(strict),
+            strict_bind_call_apply: self.strict_bind_call_apply.unwrap_or(strict),
+            strict_property_initialization: self.strict_property_initialization.unwrap_or(strict),
+            use_unknown_in_catch_variables: self.use_unknown_in_catch_variables.unwrap_or(strict),
+            no_unchecked_indexed_access: self.no_unchecked_indexed_access.unwrap_or(false),
+            exact_optional_property_types: self.exact_optional_property_types.unwrap_or(false),
+            allow_unreachable_code: self.allow_unreachable_code.unwrap_or(false),
+            allow_unused_labels: self.allow_unused_labels.unwrap_or(false),
+            no_fallthrough_cases_in_switch: self.no_fallthrough_cases_in_switch.unwrap_or(false),
+            no_implicit_returns: self.no_implicit_returns.unwrap_or(false),
+            suppress_excess_property_errors: self.suppress_excess_property_errors.unwrap_or(false),
+            suppress_implicit_any_index_errors: self.suppress_implicit_any_index_errors.unwrap_or(false),
+            no_strict_generic_checks: self.no_strict_generic_checks.unwrap_or(false),
+            no_unused_locals: self.no_unused_locals.unwrap_or(false),
+            no_unused_parameters: self.no_unused_parameters.unwrap_or(false),
+            use_define_property_for_class_fields: self.use_define_for_class_fields.unwrap_or(false),
+            experimental_decorators: self.experimental_decorators.unwrap_or(false),
+        }
+    }
+
+    /// Config for the parser. `tsx` and `dts` are decided by the module loader
+    /// for each file, based on the extension.
+    pub fn parser_config(&self) -> TsConfig {
+        TsConfig {
+            // Standard decorators are allowed without `experimentalDecorators`.
+            decorators: true,
+            ..Default::default()
+        }
+    }
+}
+
+pub fn parse_target(s: &str) -> Result<EsVersion, Error> {
+    Ok(match &*s.to_ascii_lowercase() {
+        "es3" => EsVersion::Es3,
+        "es5" => EsVersion::Es5,
+        "es6" | "es2015" => EsVersion::Es2015,
+        "es2016" => EsVersion::Es2016,
+        "es2017" => EsVersion::Es2017,
+        "es2018" => EsVersion::Es2018,
+        "es2019" => EsVersion::Es2019,
+        "es2020" => EsVersion::Es2020,
+        "es2021" => EsVersion::Es2021,
+        // `EsVersion` does not have variants for newer versions yet.
+        "es2022" | "es2023" | "esnext" => EsVersion::Es2022,
+        _ => bail!("unknown target `{}`", s),
+    })
+}
+
+pub fn parse_module(s: &str) -> Result<ModuleConfig, Error> {
+    match s.to_ascii_lowercase().parse() {
+        Ok(v) => Ok(v),
+        Err(..) => bail!("unknown module kind `{}`", s),
+    }
+}
+
+pub fn parse_module_resolution(s: &str) -> Result<ModuleResolution, Error> {
+    Ok(match &*s.to_ascii_lowercase() {
+        "node" | "node10" => ModuleResolution::Node10,
+        "node16" => ModuleResolution::Node16,
+        "nodenext" => ModuleResolution::NodeNext,
+        "bundler" => ModuleResolution::Bundler,
+        "classic" => bail!("`classic` module resolution is not supported"),
+        _ => bail!("unknown module resolution `{}`", s),
+    })
+}
+
+/// Converts a name used in `compilerOptions.lib` to the name of the file in
+/// `stc_ts_builtin_types/lib`.
+fn lib_file_name(name: &str) -> String {
+    let name = name.to_ascii_lowercase();
+
+    match &*name {
+        "es6" => "es2015".into(),
+        "es7" => "es2016".into(),
+        "dom" => "dom.generated".into(),
+        "dom.iterable" => "dom.iterable.generated".into(),
+        "webworker" => "webworker.generated".into(),
+        "webworker.iterable" => "webworker.iterable.generated".into(),
+        _ => name,
+    }
+}
+
+/// Libraries loaded when `compilerOptions.lib` is not specified.
+///
+/// https://www.typescriptlang.org/tsconfig#lib
+pub fn default_libs(target: EsVersion) -> Vec<Lib> {
+    let name = match target {
+        EsVersion::Es3 | EsVersion::Es5 => "es5.full",
+        EsVersion::Es2015 => "es2015.full",
+        EsVersion::Es2016 => "es2016.full",
+        EsVersion::Es2017 => "es2017.full",
+        EsVersion::Es2018 => "es2018.full",
+        EsVersion::Es2019 => "es2019.full",
+        EsVersion::Es2020 => "es2020.full",
+        EsVersion::Es2021 => "es2021.full",
+        EsVersion::Es2022 => "es2022.full",
+    };
+
+    Lib::load(name)
+}
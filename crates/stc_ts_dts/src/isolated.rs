@@ -0,0 +1,522 @@
+//! Type annotations for `.d.ts` files generated without type information,
+//! like `isolatedDeclarations` of tsc.
+
+use fxhash::FxHashSet;
+use rnode::NodeId;
+use stc_ts_ast_rnode::{
+    RAssignPat, RBindingIdent, RClass, RClassMember, RDecl, RDefaultDecl, RExportDefaultExpr, RExportSpecifier, RExpr, RIdent, RLit,
+    RModuleDecl, RModuleExportName, RModuleItem, RNamedExport, RNumber, RObjectLit, RParam, RParamOrTsParamProp, RParenExpr, RPat, RProp,
+    RPropName, RPropOrSpread, RStmt, RStr, RTsAsExpr, RTsConstAssertion, RTsFnOrConstructorType, RTsFnParam, RTsFnType, RTsKeywordType,
+    RTsLit, RTsLitType, RTsParamPropParam, RTsPropertySignature, RTsSatisfiesExpr, RTsType, RTsTypeAnn, RTsTypeAssertion, RTsTypeElement,
+    RTsTypeLit, RTsTypeParamDecl, RUnaryExpr, RVarDecl, RVarDeclarator,
+};
+use stc_ts_errors::{Error, ErrorKind};
+use stc_ts_types::Id;
+use stc_ts_utils::{find_ids_in_pat, MapWithMut};
+use swc_atoms::JsWord;
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+
+/// Adds type annotations to declarations visible from other files, using only
+/// the syntax, and removes initializers of variables.
+///
+/// An error is reported for each declaration whose type requires type
+/// inference.
+pub(crate) fn annotate_exports(items: &mut Vec<RModuleItem>) -> Vec<Error> {
+    let is_module = items.iter().any(|item| matches!(item, RModuleItem::ModuleDecl(..)));
+    let exported = exported_locals(items);
+
+    let mut v = Annotator::default();
+    let mut new = Vec::with_capacity(items.len());
+
+    for mut item in items.take() {
+        match &mut item {
+            RModuleItem::ModuleDecl(RModuleDecl::ExportDecl(export)) => v.decl(&mut export.decl),
+            RModuleItem::ModuleDecl(RModuleDecl::ExportDefaultDecl(export)) => match &mut export.decl {
+                RDefaultDecl::Class(c) => v.class(&mut c.class),
+                RDefaultDecl::Fn(f) => {
+                    let span = f.ident.as_ref().map_or(f.function.span, |ident| ident.span);
+                    if f.function.return_type.is_none() && f.function.body.is_some() {
+                        v.report(ErrorKind::IsolatedDeclFnReturnType { span });
+                    }
+                    v.params(&mut f.function.params);
+                }
+                RDefaultDecl::TsInterfaceDecl(..) => {}
+            },
+            RModuleItem::ModuleDecl(RModuleDecl::ExportDefaultExpr(export)) => {
+                if let Some(decl) = v.default_expr(export) {
+                    new.push(RModuleItem::Stmt(RStmt::Decl(decl)));
+                }
+            }
+            RModuleItem::Stmt(RStmt::Decl(decl)) => {
+                // Declarations in scripts are global.
+                if !is_module || declared_ids(decl).iter().any(|id| exported.contains(id)) {
+                    v.decl(decl);
+                }
+            }
+            _ => {}
+        }
+
+        new.push(item);
+    }
+
+    *items = new;
+
+    v.errors
+}
+
+/// Local declarations exported by `export { foo }` or `export default foo`.
+fn exported_locals(items: &[RModuleItem]) -> FxHashSet<Id> {
+    let mut exported = FxHashSet::default();
+
+    for item in items {
+        match item {
+            RModuleItem::ModuleDecl(RModuleDecl::ExportNamed(RNamedExport { src: None, specifiers, .. })) => {
+                for specifier in specifiers {
+                    if let RExportSpecifier::Named(named) = specifier {
+                        if let RModuleExportName::Ident(i) = &named.orig {
+                            exported.insert(Id::from(i));
+                        }
+                    }
+                }
+            }
+            RModuleItem::ModuleDecl(RModuleDecl::ExportDefaultExpr(RExportDefaultExpr { expr, .. })) => {
+                if let RExpr::Ident(i) = &**expr {
+                    exported.insert(Id::from(i));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    exported
+}
+
+fn declared_ids(decl: &RDecl) -> Vec<Id> {
+    match decl {
+        RDecl::Class(c) => vec![Id::from(&c.ident)],
+        RDecl::Fn(f) => vec![Id::from(&f.ident)],
+        RDecl::Var(var) => find_ids_in_pat(&var.decls),
+        RDecl::TsInterface(..) | RDecl::TsTypeAlias(..) | RDecl::TsEnum(..) | RDecl::TsModule(..) => vec![],
+    }
+}
+
+#[derive(Default)]
+struct Annotator {
+    errors: Vec<Error>,
+}
+
+impl Annotator {
+    fn report(&mut self, kind: ErrorKind) {
+        self.errors.push(kind.into());
+    }
+
+    fn decl(&mut self, decl: &mut RDecl) {
+        match decl {
+            RDecl::Class(c) => self.class(&mut c.class),
+            RDecl::Fn(f) => {
+                // Overloads are removed before this pass, and signatures without return
+                // types return `any`.
+                if f.function.return_type.is_none() && f.function.body.is_some() {
+                    self.report(ErrorKind::IsolatedDeclFnReturnType { span: f.ident.span });
+                }
+                self.params(&mut f.function.params);
+            }
+            RDecl::Var(var) => {
+                let mode = if var.kind == VarDeclKind::Const {
+                    LitMode::Keep
+                } else {
+                    LitMode::Widen
+                };
+
+                for d in var.decls.iter_mut() {
+                    self.var_declarator(d, mode);
+                }
+            }
+            RDecl::TsInterface(..) | RDecl::TsTypeAlias(..) | RDecl::TsEnum(..) | RDecl::TsModule(..) => {}
+        }
+    }
+
+    fn var_declarator(&mut self, d: &mut RVarDeclarator, mode: LitMode) {
+        // `let a;` is `any`.
+        let init = match d.init.take() {
+            Some(init) => init,
+            None => return,
+        };
+
+        match &mut d.name {
+            RPat::Ident(i) => {
+                if i.type_ann.is_some() {
+                    return;
+                }
+
+                match infer(&init, mode) {
+                    Some(ty) => i.type_ann = Some(type_ann(ty)),
+                    None => self.report(ErrorKind::IsolatedDeclVarType { span: i.id.span }),
+                }
+            }
+            pat => {
+                if !has_type_ann(pat) {
+                    self.report(ErrorKind::IsolatedDeclVarType { span: pat.span() });
+                }
+            }
+        }
+    }
+
+    fn class(&mut self, class: &mut RClass) {
+        // An accessor pair is typed if one of them is annotated.
+        let typed_accessors = class
+            .body
+            .iter()
+            .filter_map(|member| match member {
+                RClassMember::Method(m) => {
+                    let typed = match m.kind {
+                        MethodKind::Getter => m.function.return_type.is_some(),
+                        MethodKind::Setter => m.function.params.first().map_or(false, |p| has_type_ann(&p.pat)),
+                        MethodKind::Method => false,
+                    };
+
+                    if typed {
+                        prop_name_sym(&m.key)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            })
+            .collect::<FxHashSet<_>>();
+
+        for member in class.body.iter_mut() {
+            match member {
+                RClassMember::Constructor(c) => {
+                    if c.accessibility == Some(Accessibility::Private) {
+                        continue;
+                    }
+
+                    for param in c.params.iter_mut() {
+                        match param {
+                            RParamOrTsParamProp::Param(p) => self.param(&mut p.pat),
+                            RParamOrTsParamProp::TsParamProp(p) => match &mut p.param {
+                                RTsParamPropParam::Ident(..) => {}
+                                RTsParamPropParam::Assign(assign) => self.assign_pat(assign),
+                            },
+                        }
+                    }
+                }
+                RClassMember::Method(m) => {
+                    // Types of private members are not emitted.
+                    if m.accessibility == Some(Accessibility::Private) {
+                        continue;
+                    }
+
+                    match m.kind {
+                        MethodKind::Method => {
+                            if m.function.return_type.is_none() && m.function.body.is_some() {
+                                self.report(ErrorKind::IsolatedDeclMethodReturnType { span: m.key.span() });
+                            }
+                        }
+                        MethodKind::Getter | MethodKind::Setter => {
+                            let typed = prop_name_sym(&m.key).map_or(false, |sym| typed_accessors.contains(&sym));
+                            if !typed {
+                                self.report(ErrorKind::IsolatedDeclAccessorType { span: m.key.span() });
+                            }
+                        }
+                    }
+
+                    self.params(&mut m.function.params);
+                }
+                RClassMember::ClassProp(p) => {
+                    if p.accessibility == Some(Accessibility::Private) || p.type_ann.is_some() {
+                        continue;
+                    }
+
+                    if let Some(value) = &p.value {
+                        let mode = if p.readonly { LitMode::Keep } else { LitMode::Widen };
+
+                        match infer(value, mode) {
+                            Some(ty) => p.type_ann = Some(type_ann(ty)),
+                            None => self.report(ErrorKind::IsolatedDeclPropType { span: p.key.span() }),
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn params(&mut self, params: &mut [RParam]) {
+        for p in params {
+            self.param(&mut p.pat);
+        }
+    }
+
+    /// Parameters without type annotations are `any`, unless they have default
+    /// values.
+    fn param(&mut self, pat: &mut RPat) {
+        if let RPat::Assign(assign) = pat {
+            self.assign_pat(assign);
+        }
+    }
+
+    fn assign_pat(&mut self, assign: &mut RAssignPat) {
+        if assign.type_ann.is_some() {
+            return;
+        }
+
+        match &mut *assign.left {
+            RPat::Ident(i) => {
+                if i.type_ann.is_some() {
+                    return;
+                }
+
+                match infer(&assign.right, LitMode::Widen) {
+                    Some(ty) => i.type_ann = Some(type_ann(ty)),
+                    None => self.report(ErrorKind::IsolatedDeclParamType { span: i.id.span }),
+                }
+            }
+            pat => {
+                if !has_type_ann(pat) {
+                    self.report(ErrorKind::IsolatedDeclParamType { span: assign.span });
+                }
+            }
+        }
+    }
+
+    /// `export default expr` is converted to
+    ///
+    /// ```ts
+    /// declare const _default: T;
+    /// export default _default;
+    /// ```
+    ///
+    /// and the declaration is returned.
+    fn default_expr(&mut self, export: &mut RExportDefaultExpr) -> Option<RDecl> {
+        if let RExpr::Ident(..) = &*export.expr {
+            return None;
+        }
+
+        let ty = match infer(&export.expr, LitMode::Keep) {
+            Some(ty) => ty,
+            None => {
+                self.report(ErrorKind::IsolatedDeclDefaultExport { span: export.expr.span() });
+                keyword(TsKeywordTypeKind::TsAnyKeyword)
+            }
+        };
+
+        let id = RIdent::new("_default".into(), DUMMY_SP);
+        export.expr = box RExpr::Ident(id.clone());
+
+        Some(RDecl::Var(box RVarDecl {
+            node_id: NodeId::invalid(),
+            span: DUMMY_SP,
+            kind: VarDeclKind::Const,
+            declare: true,
+            decls: vec![RVarDeclarator {
+                node_id: NodeId::invalid(),
+                span: DUMMY_SP,
+                name: RPat::Ident(RBindingIdent {
+                    node_id: NodeId::invalid(),
+                    id,
+                    type_ann: Some(type_ann(ty)),
+                }),
+                init: None,
+                definite: false,
+            }],
+        }))
+    }
+}
+
+/// How literals are typed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LitMode {
+    /// `let a = 1` is `number`.
+    Widen,
+    /// `const a = 1` is `1`.
+    Keep,
+    /// `as const`, which also makes properties readonly.
+    Const,
+}
+
+/// Returns the type of `e` if it can be known without type checking.
+fn infer(e: &RExpr, mode: LitMode) -> Option<RTsType> {
+    match e {
+        RExpr::Lit(lit) => {
+            let (kind, lit) = match lit {
+                RLit::Str(s) => (TsKeywordTypeKind::TsStringKeyword, RTsLit::Str(s.clone())),
+                RLit::Num(n) => (TsKeywordTypeKind::TsNumberKeyword, RTsLit::Number(n.clone())),
+                RLit::Bool(b) => (TsKeywordTypeKind::TsBooleanKeyword, RTsLit::Bool(b.clone())),
+                RLit::BigInt(b) => (TsKeywordTypeKind::TsBigIntKeyword, RTsLit::BigInt(b.clone())),
+                RLit::Null(..) => return Some(keyword(TsKeywordTypeKind::TsNullKeyword)),
+                RLit::Regex(..) | RLit::JSXText(..) => return None,
+            };
+
+            Some(match mode {
+                LitMode::Widen => keyword(kind),
+                LitMode::Keep | LitMode::Const => lit_type(lit),
+            })
+        }
+
+        RExpr::Tpl(tpl) if tpl.exprs.is_empty() => Some(match mode {
+            LitMode::Widen => keyword(TsKeywordTypeKind::TsStringKeyword),
+            LitMode::Keep | LitMode::Const => lit_type(RTsLit::Str(RStr {
+                span: DUMMY_SP,
+                value: tpl.quasis.first()?.cooked.as_deref()?.into(),
+                raw: None,
+            })),
+        }),
+
+        RExpr::Unary(RUnaryExpr {
+            op: UnaryOp::Minus,
+            arg: box RExpr::Lit(RLit::Num(n)),
+            ..
+        }) => Some(match mode {
+            LitMode::Widen => keyword(TsKeywordTypeKind::TsNumberKeyword),
+            LitMode::Keep | LitMode::Const => lit_type(RTsLit::Number(RNumber {
+                span: DUMMY_SP,
+                value: -n.value,
+                raw: None,
+            })),
+        }),
+
+        RExpr::Paren(RParenExpr { expr, .. }) | RExpr::TsSatisfies(RTsSatisfiesExpr { expr, .. }) => infer(expr, mode),
+
+        RExpr::TsConstAssertion(RTsConstAssertion { expr, .. }) => infer(expr, LitMode::Const),
+
+        RExpr::TsAs(RTsAsExpr { type_ann, .. }) | RExpr::TsTypeAssertion(RTsTypeAssertion { type_ann, .. }) => Some((**type_ann).clone()),
+
+        RExpr::Arrow(arrow) => fn_type(arrow.params.iter(), &arrow.type_params, &arrow.return_type),
+
+        RExpr::Fn(f) => fn_type(
+            f.function.params.iter().map(|p| &p.pat),
+            &f.function.type_params,
+            &f.function.return_type,
+        ),
+
+        RExpr::Object(obj) => object_type(obj, mode),
+
+        _ => None,
+    }
+}
+
+fn fn_type<'a>(
+    params: impl Iterator<Item = &'a RPat>,
+    type_params: &Option<Box<RTsTypeParamDecl>>,
+    return_type: &Option<Box<RTsTypeAnn>>,
+) -> Option<RTsType> {
+    let type_ann = return_type.clone()?;
+    let params = params.map(fn_param).collect::<Option<Vec<_>>>()?;
+
+    Some(RTsType::TsFnOrConstructorType(RTsFnOrConstructorType::TsFnType(RTsFnType {
+        node_id: NodeId::invalid(),
+        span: DUMMY_SP,
+        params,
+        type_params: type_params.clone(),
+        type_ann,
+    })))
+}
+
+fn fn_param(pat: &RPat) -> Option<RTsFnParam> {
+    match pat {
+        RPat::Ident(i) => Some(RTsFnParam::Ident(i.clone())),
+        RPat::Array(a) => Some(RTsFnParam::Array(a.clone())),
+        RPat::Object(o) => Some(RTsFnParam::Object(o.clone())),
+        RPat::Rest(r) => Some(RTsFnParam::Rest(r.clone())),
+        RPat::Assign(assign) => {
+            let mut i = match &*assign.left {
+                RPat::Ident(i) => i.clone(),
+                _ => return None,
+            };
+
+            if i.type_ann.is_none() {
+                i.type_ann = Some(type_ann(infer(&assign.right, LitMode::Widen)?));
+            }
+            i.id.optional = true;
+
+            Some(RTsFnParam::Ident(i))
+        }
+        RPat::Invalid(..) | RPat::Expr(..) => None,
+    }
+}
+
+/// Properties are mutable, so their types are widened unless `as const` is
+/// used.
+fn object_type(obj: &RObjectLit, mode: LitMode) -> Option<RTsType> {
+    let (prop_mode, readonly) = match mode {
+        LitMode::Widen | LitMode::Keep => (LitMode::Widen, false),
+        LitMode::Const => (LitMode::Const, true),
+    };
+
+    let members = obj
+        .props
+        .iter()
+        .map(|prop| match prop {
+            RPropOrSpread::Prop(box RProp::KeyValue(kv)) => {
+                let key = match &kv.key {
+                    RPropName::Ident(i) => RExpr::Ident(i.clone()),
+                    RPropName::Str(s) => RExpr::Lit(RLit::Str(s.clone())),
+                    RPropName::Num(n) => RExpr::Lit(RLit::Num(n.clone())),
+                    RPropName::BigInt(..) | RPropName::Computed(..) => return None,
+                };
+
+                Some(RTsTypeElement::TsPropertySignature(RTsPropertySignature {
+                    node_id: NodeId::invalid(),
+                    span: DUMMY_SP,
+                    readonly,
+                    key: box key,
+                    computed: false,
+                    optional: false,
+                    init: None,
+                    params: vec![],
+                    type_ann: Some(type_ann(infer(&kv.value, prop_mode)?)),
+                    type_params: None,
+                }))
+            }
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(RTsType::TsTypeLit(RTsTypeLit {
+        node_id: NodeId::invalid(),
+        span: DUMMY_SP,
+        members,
+    }))
+}
+
+fn has_type_ann(pat: &RPat) -> bool {
+    match pat {
+        RPat::Ident(i) => i.type_ann.is_some(),
+        RPat::Array(a) => a.type_ann.is_some(),
+        RPat::Object(o) => o.type_ann.is_some(),
+        RPat::Rest(r) => r.type_ann.is_some(),
+        RPat::Assign(a) => a.type_ann.is_some() || has_type_ann(&a.left),
+        RPat::Invalid(..) | RPat::Expr(..) => false,
+    }
+}
+
+fn prop_name_sym(key: &RPropName) -> Option<JsWord> {
+    match key {
+        RPropName::Ident(i) => Some(i.sym.clone()),
+        RPropName::Str(s) => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+fn keyword(kind: TsKeywordTypeKind) -> RTsType {
+    RTsType::TsKeywordType(RTsKeywordType { span: DUMMY_SP, kind })
+}
+
+fn lit_type(lit: RTsLit) -> RTsType {
+    RTsType::TsLitType(RTsLitType {
+        node_id: NodeId::invalid(),
+        span: DUMMY_SP,
+        lit,
+    })
+}
+
+fn type_ann(ty: RTsType) -> Box<RTsTypeAnn> {
+    box RTsTypeAnn {
+        node_id: NodeId::invalid(),
+        span: DUMMY_SP,
+        type_ann: box ty,
+    }
+}
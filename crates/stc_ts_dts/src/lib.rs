@@ -7,11 +7,13 @@
 use fxhash::FxHashSet;
 use rnode::{NodeId, Visit, VisitMut, VisitMutWith, VisitWith};
 use stc_ts_ast_rnode::{
-    RArrayPat, RAssignPat, RBlockStmt, RClass, RClassDecl, RClassMember, RClassProp, RDecl, RExportDecl, RExportDefaultExpr, RExpr,
-    RFnDecl, RIdent, RImportDecl, RImportSpecifier, RMemberExpr, RMemberProp, RModuleDecl, RModuleItem, RNamedExport, RParamOrTsParamProp,
-    RPat, RPrivateName, RPrivateProp, RPropName, RStmt, RTsEntityName, RTsEnumDecl, RTsIndexSignature, RTsInterfaceDecl, RTsKeywordType,
-    RTsModuleDecl, RTsParamProp, RTsParamPropParam, RTsPropertySignature, RTsType, RTsTypeAliasDecl, RTsTypeAnn, RVarDecl, RVarDeclarator,
+    RArrayPat, RAssignPat, RBlockStmt, RClass, RClassDecl, RClassMember, RClassProp, RDecl, RExportDecl, RExportDefaultExpr,
+    RExportNamedSpecifier, RExportSpecifier, RExpr, RFnDecl, RIdent, RImportDecl, RImportSpecifier, RMemberExpr, RMemberProp, RModuleDecl,
+    RModuleExportName, RModuleItem, RNamedExport, RParamOrTsParamProp, RPat, RPrivateName, RPrivateProp, RPropName, RStmt, RTsEntityName,
+    RTsEnumDecl, RTsIndexSignature, RTsInterfaceDecl, RTsKeywordType, RTsModuleDecl, RTsParamProp, RTsParamPropParam, RTsPropertySignature,
+    RTsType, RTsTypeAliasDecl, RTsTypeAnn, RVarDecl, RVarDeclarator,
 };
+use stc_ts_errors::Error;
 use stc_ts_types::{Id, ModuleTypeData};
 use stc_ts_utils::{find_ids_in_pat, MapWithMut};
 use swc_common::DUMMY_SP;
@@ -20,11 +22,13 @@ use swc_ecma_ast::*;
 use self::{
     ambient::RealImplRemover,
     dce::{get_used, DceForDts},
+    isolated::annotate_exports,
 };
 pub use crate::mutations::apply_mutations;
 
 mod ambient;
 mod dce;
+mod isolated;
 mod mutations;
 
 /// Make `module` suitable for .d.ts file.
@@ -38,6 +42,41 @@ pub fn cleanup_module_for_dts(module: &mut Vec<RModuleItem>, type_data: &ModuleT
 
     module.visit_mut_with(&mut RealImplRemover::default());
 
+    remove_impls(module, is_module);
+
+    module.visit_mut_with(&mut DceForDts {
+        used: get_used(type_data),
+        info: type_data,
+        current_class: None,
+        in_declare: false,
+        top_level: true,
+        forced_module: false,
+        prevent_empty_export: false,
+    })
+}
+
+/// Make `module` suitable for .d.ts file without type information, so it can
+/// be done for each file in parallel.
+///
+/// Types of exported declarations are inferred only from the syntax, and an
+/// error is returned for each declaration which requires type inference.
+pub fn isolated_dts(module: &mut Vec<RModuleItem>) -> Vec<Error> {
+    let is_module = module.iter().any(|item| match item {
+        RModuleItem::ModuleDecl(_) => true,
+        RModuleItem::Stmt(_) => false,
+    });
+
+    module.visit_mut_with(&mut RealImplRemover::default());
+
+    let errors = annotate_exports(module);
+
+    remove_impls(module, is_module);
+
+    errors
+}
+
+/// Removes implementations and declarations not used by exports.
+fn remove_impls(module: &mut Vec<RModuleItem>, is_module: bool) {
     let (used_types, used_vars) = {
         let mut v = TypeUsageCollector {
             phase: CollectorPhase::SearchExports,
@@ -58,16 +97,6 @@ pub fn cleanup_module_for_dts(module: &mut Vec<RModuleItem>, type_data: &ModuleT
         used_types,
         used_vars,
     });
-
-    module.visit_mut_with(&mut DceForDts {
-        used: get_used(type_data),
-        info: type_data,
-        current_class: None,
-        in_declare: false,
-        top_level: true,
-        forced_module: false,
-        prevent_empty_export: false,
-    })
 }
 
 enum CollectorPhase {
@@ -142,6 +171,25 @@ impl Visit<RExportDefaultExpr> for TypeUsageCollector {
     }
 }
 
+impl Visit<RNamedExport> for TypeUsageCollector {
+    fn visit(&mut self, export: &RNamedExport) {
+        if export.src.is_some() {
+            return;
+        }
+
+        for specifier in &export.specifiers {
+            if let RExportSpecifier::Named(RExportNamedSpecifier {
+                orig: RModuleExportName::Ident(i),
+                ..
+            }) = specifier
+            {
+                self.used_types.insert(i.into());
+                self.used_vars.insert(i.into());
+            }
+        }
+    }
+}
+
 impl Visit<RClass> for TypeUsageCollector {
     fn visit(&mut self, class: &RClass) {
         class.visit_children_with(self);
@@ -453,8 +501,8 @@ impl VisitMut<RPat> for Dts {
 
 impl VisitMut<Vec<RClassMember>> for Dts {
     fn visit_mut(&mut self, members: &mut Vec<RClassMember>) {
-        // Remove empty members.
-        members.retain(|member| !matches!(member, RClassMember::Empty(..)));
+        // Remove empty members and static blocks.
+        members.retain(|member| !matches!(member, RClassMember::Empty(..) | RClassMember::StaticBlock(..)));
 
         members.visit_mut_children_with(self);
 
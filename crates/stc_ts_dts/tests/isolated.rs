@@ -0,0 +1,93 @@
+#![feature(box_syntax)]
+
+use rnode::{NodeIdGenerator, RNode};
+use stc_ts_ast_rnode::RModule;
+use stc_ts_dts::isolated_dts;
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+
+/// Returns the generated `.d.ts` file and error codes.
+fn isolated(src: &str) -> (String, Vec<usize>) {
+    let cm = Lrc::new(SourceMap::default());
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+
+    let lexer = Lexer::new(
+        Syntax::Typescript(Default::default()),
+        EsVersion::latest(),
+        StringInput::from(&*fm),
+        None,
+    );
+    let module = Parser::new_from(lexer).parse_module().unwrap();
+    let mut module = RModule::from_orig(&mut NodeIdGenerator::default(), module);
+
+    let errors = isolated_dts(&mut module.body);
+
+    let mut buf = vec![];
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            comments: None,
+            cm: cm.clone(),
+            wr: box JsWriter::new(cm, "\n", &mut buf, None),
+        };
+
+        emitter.emit_module(&module.into_orig()).unwrap();
+    }
+
+    (String::from_utf8(buf).unwrap(), errors.iter().map(|err| err.code()).collect())
+}
+
+#[test]
+fn annotated_exports() {
+    let (dts, errors) = isolated(
+        "export function add(a: number, b = 1): number { return a + b; }
+export const a = 'a';
+export let b = 1;
+export const c = { d: 1 } as const;
+",
+    );
+
+    assert_eq!(errors, Vec::<usize>::new());
+    assert!(
+        dts.contains("export declare function add(a: number, b?: number): number;"),
+        "{}",
+        dts
+    );
+    assert!(dts.contains("export declare const a: 'a';"), "{}", dts);
+    assert!(dts.contains("export declare let b: number;"), "{}", dts);
+    assert!(dts.contains("readonly d: 1;"), "{}", dts);
+}
+
+#[test]
+fn inference_required() {
+    let (_, errors) = isolated(
+        "declare function foo(): number;
+export function a() { return 1; }
+export const b = foo();
+export class C {
+    d = foo();
+    e() {}
+    private f() {}
+}
+export default foo();
+",
+    );
+
+    assert_eq!(errors, vec![9007, 9010, 9012, 9008, 9037]);
+}
+
+#[test]
+fn named_exports() {
+    let (dts, errors) = isolated(
+        "const a = 1;
+const b = foo();
+export { a };
+",
+    );
+
+    assert_eq!(errors, Vec::<usize>::new());
+    assert!(dts.contains("declare const a: 1;"), "{}", dts);
+    assert!(!dts.contains("b"), "{}", dts);
+}
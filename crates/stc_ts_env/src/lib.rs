@@ -36,6 +36,7 @@ pub struct Env {
     rule: Rule,
     target: EsVersion,
     module: ModuleConfig,
+    jsx: JsxConfig,
     builtin: Arc<BuiltIn>,
     global_types: Arc<Mutex<FxHashMap<JsWord, Type>>>,
     global_vars: Arc<Mutex<FxHashMap<JsWord, Type>>>,
@@ -48,6 +49,7 @@ impl Env {
             builtin,
             target,
             module,
+            jsx: Default::default(),
             global_types: Default::default(),
             global_vars: Default::default(),
             rule,
@@ -70,6 +72,15 @@ impl Env {
         self.rule
     }
 
+    pub const fn jsx(&self) -> &JsxConfig {
+        &self.jsx
+    }
+
+    pub fn with_jsx(mut self, jsx: JsxConfig) -> Self {
+        self.jsx = jsx;
+        self
+    }
+
     pub fn declare_global_var(&mut self, name: JsWord, ty: Type) {
         ty.assert_clone_cheap();
 
@@ -130,6 +141,24 @@ impl Env {
         }
         .into())
     }
+
+    /// Returns names of all global variables, including builtin ones.
+    pub fn global_var_names(&self) -> Vec<JsWord> {
+        let mut names = self.global_vars.lock().keys().cloned().collect::<Vec<_>>();
+        names.extend(self.builtin.vars.keys().cloned());
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Returns names of all global types, including builtin ones.
+    pub fn global_type_names(&self) -> Vec<JsWord> {
+        let mut names = self.global_types.lock().keys().cloned().collect::<Vec<_>>();
+        names.extend(self.builtin.types.keys().cloned());
+        names.sort();
+        names.dedup();
+        names
+    }
 }
 
 /// Stuffs which are not changed regardless
@@ -174,6 +203,8 @@ pub enum ModuleConfig {
     Es2015,
     /// `es2020`
     Es2020,
+    /// `es2022`
+    Es2022,
     /// `none`
     None,
     /// `umd`
@@ -184,6 +215,79 @@ pub enum ModuleConfig {
     System,
     /// `esnext`
     EsNext,
+    /// `node16`
+    Node16,
+    /// `nodenext`
+    NodeNext,
+}
+
+/// `jsx` of `compilerOptions`.
+#[derive(Clone, Copy, StringEnum)]
+pub enum JsxMode {
+    /// `preserve`
+    Preserve,
+    /// `react`
+    React,
+    /// `react-native`
+    ReactNative,
+    /// `react-jsx`
+    ReactJsx,
+    /// `react-jsxdev`
+    ReactJsxDev,
+}
+
+/// Options used to type JSX elements.
+#[derive(Debug, Clone)]
+pub struct JsxConfig {
+    pub mode: JsxMode,
+    /// `jsxFactory`
+    pub factory: JsWord,
+    /// `jsxFragmentFactory`
+    pub fragment_factory: JsWord,
+    /// `jsxImportSource`
+    pub import_source: JsWord,
+}
+
+impl JsxConfig {
+    /// The module which provides the `JSX` namespace if the automatic runtime
+    /// is used.
+    pub fn runtime_module(&self) -> Option<JsWord> {
+        match self.mode {
+            JsxMode::ReactJsx => Some(format!("{}/jsx-runtime", self.import_source).into()),
+            JsxMode::ReactJsxDev => Some(format!("{}/jsx-dev-runtime", self.import_source).into()),
+            _ => None,
+        }
+    }
+
+    /// Returns the first identifier of the factory, like `React` for
+    /// `React.createElement`.
+    ///
+    /// If `mode` is `react` or `react-native`, this should be in scope for
+    /// `<div />` or `<></>`. The `JSX` namespace is also searched from it.
+    pub fn factory_root(&self, fragment: bool) -> JsWord {
+        let factory = if fragment { &self.fragment_factory } else { &self.factory };
+
+        factory.split('.').next().unwrap_or_default().into()
+    }
+
+    /// Returns `true` if the factory is called by the emitted code.
+    ///
+    /// `react-native` preserves JSX like `preserve`, so the factory is not
+    /// required to be in scope.
+    pub fn requires_factory(&self) -> bool {
+        matches!(self.mode, JsxMode::React)
+    }
+}
+
+impl Default for JsxConfig {
+    fn default() -> Self {
+        Self {
+            mode: JsxMode::Preserve,
+            factory: "React.createElement".into(),
+            fragment_factory: "React.Fragment".into(),
+            import_source: "react".into(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -193,6 +297,11 @@ pub struct Rule {
     pub always_strict: bool,
     pub strict_null_checks: bool,
     pub strict_function_types: bool,
+    pub strict_bind_call_apply: bool,
+    pub strict_property_initialization: bool,
+    pub use_unknown_in_catch_variables: bool,
+    pub no_unchecked_indexed_access: bool,
+    pub exact_optional_property_types: bool,
 
     pub allow_unreachable_code: bool,
     pub allow_unused_labels: bool,
@@ -204,4 +313,5 @@ pub struct Rule {
     pub no_unused_locals: bool,
     pub no_unused_parameters: bool,
     pub use_define_property_for_class_fields: bool,
+    pub experimental_decorators: bool,
 }
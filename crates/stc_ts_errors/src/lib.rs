@@ -24,11 +24,15 @@ use swc_common::{
 };
 use swc_ecma_ast::{AssignOp, BinaryOp, UpdateOp};
 
-pub use self::result_ext::DebugExt;
+pub use self::{
+    message::{key_to_string, type_to_string},
+    result_ext::DebugExt,
+};
 use crate::context::with_ctx;
 
 pub mod context;
 pub mod debug;
+mod message;
 mod result_ext;
 #[cfg(debug_assertions)]
 type Contexts = Vec<String>;
@@ -77,7 +81,7 @@ impl Error {
 
         let mut err = h.struct_span_err_with_code(
             span,
-            &format!("{:#?}", self),
+            &self.message(),
             DiagnosticId::Error(format!("TS{}", ErrorKind::normalize_error_code(self.code()))),
         );
 
@@ -339,6 +343,12 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS2729
+    PropertyUsedBeforeInit {
+        span: Span,
+        name: JsWord,
+    },
+
     /// TS2528
     DuplicateDefaultExport {
         span: Span,
@@ -362,6 +372,8 @@ pub enum ErrorKind {
     /// TS2307
     ModuleNotFound {
         span: Span,
+        /// The module specifier.
+        src: JsWord,
     },
 
     /// TS5061
@@ -689,6 +701,7 @@ pub enum ErrorKind {
     /// TS2339
     NoSuchEnumVariant {
         span: Span,
+        enum_name: JsWord,
         name: JsWord,
     },
 
@@ -732,8 +745,11 @@ pub enum ErrorKind {
         inner: Box<Error>,
     },
 
+    /// TS2305
     ImportFailed {
         span: Span,
+        /// The module specifier.
+        src: JsWord,
         orig: Id,
         id: Id,
     },
@@ -772,6 +788,7 @@ pub enum ErrorKind {
     NoSuchPropertyInModule {
         span: Span,
         name: Box<RTsModuleName>,
+        prop: Box<Key>,
     },
 
     /// TS2355
@@ -1053,6 +1070,13 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS2375
+    AssignFailedDueToExactOptionalPropertyTypes {
+        span: Span,
+        left: Box<Type>,
+        right: Box<Type>,
+    },
+
     ObjectAssignFailed {
         span: Span,
         errors: Vec<Error>,
@@ -1360,6 +1384,7 @@ pub enum ErrorKind {
         span: Span,
         min: usize,
         max: Option<usize>,
+        actual: usize,
     },
 
     ExpectedAtLeastNArgsButGotM {
@@ -1434,6 +1459,150 @@ pub enum ErrorKind {
     RestPropertyNotLast {
         span: Span,
     },
+
+    /// TS2604
+    JsxElementTypeHasNoSignatures {
+        span: Span,
+        name: JsWord,
+    },
+
+    /// TS2786
+    InvalidJsxComponent {
+        span: Span,
+        name: JsWord,
+        cause: Box<Error>,
+    },
+
+    /// TS7026
+    NoJsxIntrinsicElements {
+        span: Span,
+    },
+
+    /// TS6133
+    UnusedLocal {
+        span: Span,
+        name: JsWord,
+    },
+
+    /// TS6138
+    UnusedProperty {
+        span: Span,
+        name: JsWord,
+    },
+
+    /// TS6192
+    AllImportsUnused {
+        span: Span,
+    },
+
+    /// TS6196
+    UnusedType {
+        span: Span,
+        name: JsWord,
+    },
+
+    /// TS6198
+    AllDestructuredElementsUnused {
+        span: Span,
+    },
+
+    /// TS6199
+    AllVariablesUnused {
+        span: Span,
+    },
+
+    /// TS7029
+    FallthroughCaseInSwitch {
+        span: Span,
+    },
+
+    /// TS7030
+    NotAllCodePathsReturnValue {
+        span: Span,
+    },
+
+    /// TS2578
+    UnusedExpectErrorDirective {
+        span: Span,
+    },
+
+    /// TS9007
+    IsolatedDeclFnReturnType {
+        span: Span,
+    },
+
+    /// TS9008
+    IsolatedDeclMethodReturnType {
+        span: Span,
+    },
+
+    /// TS9009
+    IsolatedDeclAccessorType {
+        span: Span,
+    },
+
+    /// TS9010
+    IsolatedDeclVarType {
+        span: Span,
+    },
+
+    /// TS9011
+    IsolatedDeclParamType {
+        span: Span,
+    },
+
+    /// TS9012
+    IsolatedDeclPropType {
+        span: Span,
+    },
+
+    /// TS9037
+    IsolatedDeclDefaultExport {
+        span: Span,
+    },
+
+    /// TS6059
+    FileNotUnderRootDir {
+        span: Span,
+        file: JsWord,
+        root_dir: JsWord,
+    },
+
+    /// TS1206
+    DecoratorNotValidHere {
+        span: Span,
+    },
+
+    /// TS1238
+    InvalidClassDecorator {
+        span: Span,
+        cause: Vec<Error>,
+    },
+
+    /// TS1239
+    InvalidParamDecorator {
+        span: Span,
+        cause: Vec<Error>,
+    },
+
+    /// TS1240
+    InvalidPropDecorator {
+        span: Span,
+        cause: Vec<Error>,
+    },
+
+    /// TS1241
+    InvalidMethodDecorator {
+        span: Span,
+        cause: Vec<Error>,
+    },
+
+    /// TS1270
+    DecoratorReturnTypeNotAssignable {
+        span: Span,
+        ret: Box<Type>,
+        expected: Box<Type>,
+    },
 }
 
 #[cfg(target_pointer_width = "64")]
@@ -1634,6 +1803,8 @@ impl ErrorKind {
             | ErrorKind::InvalidOpAssign { .. }
             | ErrorKind::TupleAssignError { .. } => 2322,
 
+            ErrorKind::AssignFailedDueToExactOptionalPropertyTypes { .. } => 2375,
+
             ErrorKind::NonOverlappingTypeCast { .. } => 2352,
 
             ErrorKind::SuperInClassWithoutSuper { .. } => 2335,
@@ -1880,6 +2051,8 @@ impl ErrorKind {
 
             ErrorKind::BlockScopedVarUsedBeforeInit { .. } => 2448,
 
+            ErrorKind::PropertyUsedBeforeInit { .. } => 2729,
+
             ErrorKind::SuperCannotUseTypeArgs { .. } => 2754,
 
             ErrorKind::DeleteOperandMustBeOptional { .. } => 2790,
@@ -1968,6 +2141,58 @@ impl ErrorKind {
 
             ErrorKind::TargetLacksConstructSignature { .. } => 7009,
 
+            ErrorKind::JsxElementTypeHasNoSignatures { .. } => 2604,
+
+            ErrorKind::InvalidJsxComponent { .. } => 2786,
+
+            ErrorKind::NoJsxIntrinsicElements { .. } => 7026,
+
+            ErrorKind::UnusedLocal { .. } => 6133,
+
+            ErrorKind::UnusedProperty { .. } => 6138,
+
+            ErrorKind::AllImportsUnused { .. } => 6192,
+
+            ErrorKind::UnusedType { .. } => 6196,
+
+            ErrorKind::AllDestructuredElementsUnused { .. } => 6198,
+
+            ErrorKind::AllVariablesUnused { .. } => 6199,
+
+            ErrorKind::FallthroughCaseInSwitch { .. } => 7029,
+
+            ErrorKind::NotAllCodePathsReturnValue { .. } => 7030,
+
+            ErrorKind::UnusedExpectErrorDirective { .. } => 2578,
+
+            ErrorKind::IsolatedDeclFnReturnType { .. } => 9007,
+
+            ErrorKind::IsolatedDeclMethodReturnType { .. } => 9008,
+
+            ErrorKind::IsolatedDeclAccessorType { .. } => 9009,
+
+            ErrorKind::IsolatedDeclVarType { .. } => 9010,
+
+            ErrorKind::IsolatedDeclParamType { .. } => 9011,
+
+            ErrorKind::IsolatedDeclPropType { .. } => 9012,
+
+            ErrorKind::IsolatedDeclDefaultExport { .. } => 9037,
+
+            ErrorKind::FileNotUnderRootDir { .. } => 6059,
+
+            ErrorKind::DecoratorNotValidHere { .. } => 1206,
+
+            ErrorKind::InvalidClassDecorator { .. } => 1238,
+
+            ErrorKind::InvalidParamDecorator { .. } => 1239,
+
+            ErrorKind::InvalidPropDecorator { .. } => 1240,
+
+            ErrorKind::InvalidMethodDecorator { .. } => 1241,
+
+            ErrorKind::DecoratorReturnTypeNotAssignable { .. } => 1270,
+
             _ => 0,
         }
     }
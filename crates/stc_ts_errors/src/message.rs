@@ -0,0 +1,1088 @@
+//! Human-readable messages for errors.
+//!
+//! Messages follow the wording of `tsc` as closely as the information stored
+//! in [ErrorKind] allows. Nested errors (like the causes of an assignment
+//! failure) are rendered as indented elaboration, just like `tsc` does.
+
+use std::borrow::Cow;
+
+use rnode::{Fold, FoldWith, RNode};
+use stc_ts_ast_rnode::{RIdent, RTsEntityName, RTsModuleName, RTsType};
+use stc_ts_types::{name::Name, Key, Module, Namespace, Ref, Type, TypeElement};
+use stc_utils::cache::ALLOW_DEEP_CLONE;
+use swc_common::{sync::Lrc, SourceMap, Span};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter, Node};
+use swc_ecma_utils::DropSpan;
+use swc_ecma_visit::VisitMutWith;
+
+use crate::{Error, ErrorKind};
+
+#[cfg(test)]
+mod tests;
+
+impl ErrorKind {
+    /// Message of this error, including elaboration of nested errors.
+    pub fn message(&self) -> String {
+        let mut buf = String::new();
+        write_message(&mut buf, self, 0, None);
+        buf
+    }
+
+    /// Nested errors which are printed as elaboration of this error.
+    fn causes(&self) -> Vec<&Error> {
+        match self {
+            ErrorKind::InvalidInterfaceInheritance { cause, .. }
+            | ErrorKind::VarDeclNotCompatible { cause, .. }
+            | ErrorKind::ImcompatibleFnOverload { cause, .. }
+            | ErrorKind::InvalidImplOfInterface { cause, .. }
+            | ErrorKind::InvalidJsxComponent { cause, .. } => vec![&**cause],
+
+            ErrorKind::SimpleAssignFailed { cause: Some(cause), .. } => vec![&**cause],
+
+            ErrorKind::IntersectionError { error, .. } => vec![&**error],
+
+            // The headline of `WrongArgType` already describes the assignment failure.
+            ErrorKind::WrongArgType { inner, .. } => match &***inner {
+                ErrorKind::AssignFailed { cause, .. } => cause.iter().collect(),
+                _ => vec![&**inner],
+            },
+
+            ErrorKind::AssignFailed { cause, .. }
+            | ErrorKind::SimpleAssignFailedWithCause { cause, .. }
+            | ErrorKind::InvalidClassDecorator { cause, .. }
+            | ErrorKind::InvalidParamDecorator { cause, .. }
+            | ErrorKind::InvalidPropDecorator { cause, .. }
+            | ErrorKind::InvalidMethodDecorator { cause, .. } => cause.iter().collect(),
+
+            ErrorKind::ObjectAssignFailed { errors, .. } | ErrorKind::UnionError { errors, .. } => errors.iter().collect(),
+
+            ErrorKind::ModuleLoadFailed { errors, .. } => errors.0.iter().collect(),
+
+            _ => vec![],
+        }
+    }
+
+    /// `(target, source)` of the assignment described by this error.
+    fn assigned_types(&self) -> Option<(&Type, &Type)> {
+        match self {
+            ErrorKind::AssignFailed { left, right, .. } => Some((&**left, &**right)),
+            ErrorKind::WrongArgType { inner, .. } => inner.assigned_types(),
+            _ => None,
+        }
+    }
+
+    /// The first line of the message, without elaboration.
+    ///
+    /// This is empty if `tsc` prints only the causes of the error.
+    fn headline(&self) -> Cow<'static, str> {
+        match self {
+            ErrorKind::InvalidInterfaceInheritance { .. } => "Interface incorrectly extends its base interface.".into(),
+
+            ErrorKind::TupleTooShort { .. } => "Tuple type does not have an element at this index.".into(),
+
+            ErrorKind::VarDeclNotCompatible { .. } | ErrorKind::RedeclaredVarWithDifferentType { .. } => {
+                "Subsequent variable declarations must have the same type.".into()
+            }
+
+            ErrorKind::IntrinsicIsBuiltinOnly { .. } => {
+                "The 'intrinsic' keyword can only be used to declare compiler provided intrinsic types.".into()
+            }
+
+            ErrorKind::TypeParamsProvidedButCalleeIsNotGeneric { .. } | ErrorKind::AnyTypeUsedAsCalleeWithTypeArgs { .. } => {
+                "Untyped function calls may not accept type arguments.".into()
+            }
+
+            ErrorKind::OptionalAndNonOptionalMethodPropertyMixed { .. } => "Overload signatures must all be optional or required.".into(),
+
+            ErrorKind::UpdateArgMustBeVariableOrPropertyAccess { .. } | ErrorKind::ExprInvalidForUpdateArg { .. } => {
+                "The operand of an increment or decrement operator must be a variable or a property access.".into()
+            }
+
+            ErrorKind::InterfaceNotCompatible { .. } => "Interface cannot simultaneously extend types with conflicting members.".into(),
+
+            ErrorKind::CannotUseTypeAsIndexIndex { .. } => "Type cannot be used as an index type.".into(),
+
+            ErrorKind::OnlyOneEnumCanOmitInit { .. } => {
+                "In an enum with multiple declarations, only one declaration can omit an initializer for its first enum element.".into()
+            }
+
+            ErrorKind::ConstEnumMemberHasInifinityAsInit { .. } => {
+                "'const' enum member initializer was evaluated to a non-finite value.".into()
+            }
+
+            ErrorKind::ConstEnumMemberHasNaNAsInit { .. } => {
+                "'const' enum member initializer was evaluated to disallowed value 'NaN'.".into()
+            }
+
+            ErrorKind::UnreachableCode { .. } => "Unreachable code detected.".into(),
+
+            ErrorKind::VarMayNotBeInitialized { .. } => "Variable is used before being assigned.".into(),
+
+            ErrorKind::ClassPropNotInitialized { .. } | ErrorKind::ClassPropertyInitRequired { .. } => {
+                "Property has no initializer and is not definitely assigned in the constructor.".into()
+            }
+
+            ErrorKind::DefinedWithAccessorInSuper { .. } => {
+                "Property is defined as an accessor in the base class, but is overridden here as an instance property.".into()
+            }
+
+            ErrorKind::OptionInvalidForEs3 { .. } => "Option cannot be specified when option 'target' is 'ES3'.".into(),
+
+            ErrorKind::ThisUsedBeforeCallingSuper { .. } => {
+                "'super' must be called before accessing 'this' in the constructor of a derived class.".into()
+            }
+
+            ErrorKind::SuperUsedBeforeCallingSuper { .. } => {
+                "'super' must be called before accessing a property of 'super' in the constructor of a derived class.".into()
+            }
+
+            ErrorKind::SuperInNestedFunction { .. } => {
+                "Super calls are not permitted outside constructors or in nested functions inside constructors.".into()
+            }
+
+            ErrorKind::SuperNotCalled { .. } => "Constructors for derived classes must contain a 'super' call.".into(),
+
+            ErrorKind::CannotAccessAbstractMember { .. } => "Abstract member cannot be accessed via super expression.".into(),
+
+            ErrorKind::OperatorCannotBeAppliedToTypes { .. } | ErrorKind::TS2365 { .. } => {
+                "Operator cannot be applied to these types.".into()
+            }
+
+            ErrorKind::AbstractClassMethodShouldBeSequntial { .. } => "All declarations of an abstract method must be consecutive.".into(),
+
+            ErrorKind::ClassMemberNotCompatibleWithStringIndexSignature { .. } => {
+                "Property is not assignable to 'string' index type.".into()
+            }
+
+            ErrorKind::ClassMemberNotCompatibleWithNumericIndexSignature { .. } => {
+                "Property is not assignable to 'number' index type.".into()
+            }
+
+            ErrorKind::AssignFailedBecauseTupleLengthDiffers { .. } => {
+                "Source has a different number of elements than the target tuple type.".into()
+            }
+
+            ErrorKind::InvalidUsageOfNewTarget { .. } => "Meta-property 'new.target' is only allowed in the body of a function \
+                                                          declaration, function expression, or constructor."
+                .into(),
+
+            ErrorKind::ReturnPropertyOfIteratorMustBeMethod { .. } => "The 'return' property of an iterator must be a method.".into(),
+
+            ErrorKind::NextOfItertorShouldReturnTypeWithPropertyValue { .. } => {
+                "The type returned by the 'next()' method of an iterator must have a 'value' property.".into()
+            }
+
+            ErrorKind::CannotAssignToNamespace { .. } => "Cannot assign to a namespace.".into(),
+
+            ErrorKind::RestArgMustBeVarOrMemberAccess { .. } => {
+                "The target of an object rest assignment must be a variable or a property access.".into()
+            }
+
+            ErrorKind::InvalidOperandOfIncDecOptionalProp { .. } => {
+                "The operand of an increment or decrement operator may not be an optional property access.".into()
+            }
+
+            ErrorKind::InvalidRestPatternInOptionalChain { .. } => {
+                "The target of an object rest assignment may not be an optional property access.".into()
+            }
+
+            ErrorKind::InvalidLhsOfAssignOptionalProp { .. } => {
+                "The left-hand side of an assignment expression may not be an optional property access.".into()
+            }
+
+            ErrorKind::InvalidRestPatternInForIn { .. } => {
+                "The left-hand side of a 'for...in' statement may not be an optional property access.".into()
+            }
+
+            ErrorKind::InvalidRestPatternInForOf { .. } => {
+                "The left-hand side of a 'for...of' statement may not be an optional property access.".into()
+            }
+
+            ErrorKind::BindingPatNotAllowedInRestPatArg { .. } => "A rest element cannot contain a binding pattern.".into(),
+
+            ErrorKind::DeleteOperandMustBeOptional { .. } => "The operand of a 'delete' operator must be optional.".into(),
+
+            ErrorKind::SuperCannotUseTypeArgs { .. } => "'super' may not use type arguments.".into(),
+
+            ErrorKind::TargetLacksConstructSignature { .. } => {
+                "'new' expression, whose target lacks a construct signature, implicitly has an 'any' type.".into()
+            }
+
+            ErrorKind::BlockScopedVarUsedBeforeInit { .. } => "Block-scoped variable used before its declaration.".into(),
+
+            ErrorKind::PropertyUsedBeforeInit { name, .. } => format!("Property '{}' is used before its initialization.", name).into(),
+
+            ErrorKind::DuplicateDefaultExport { .. } => "A module cannot have multiple default exports.".into(),
+
+            ErrorKind::DuplicateExport { .. } => "Cannot redeclare exported variable.".into(),
+
+            ErrorKind::DuplicateFnImpl { .. } => "Duplicate function implementation.".into(),
+
+            ErrorKind::DuplicateConstructor { .. } => "Multiple constructor implementations are not allowed.".into(),
+
+            ErrorKind::ModuleNotFound { src, .. } | ErrorKind::ResolvedFailed { src, .. } => {
+                format!("Cannot find module '{}' or its corresponding type declarations.", src).into()
+            }
+
+            ErrorKind::TooManyAsterisk { .. } => "Pattern can have at most one '*' character.".into(),
+
+            ErrorKind::DuplicateVar { name, .. } => format!("Cannot redeclare block-scoped variable '{}'.", name.sym()).into(),
+
+            ErrorKind::ClassNameCannotBeObjectWhenTargetingEs5WithModule { .. } => {
+                "Class name cannot be 'Object' when targeting ES5 with a module.".into()
+            }
+
+            ErrorKind::NotArrayType { .. } => "Type is not an array type.".into(),
+
+            ErrorKind::NotArrayTypeNorStringType { .. } => "Type is not an array type or a string type.".into(),
+
+            ErrorKind::NotArrayTypeNorStringTypeButDownlevelIterationWouldWork { .. } => {
+                "Type is not an array type or a string type. Use compiler option '--downlevelIteration' to allow iterating of iterators."
+                    .into()
+            }
+
+            ErrorKind::ForOfStringUsedInEs3 { .. } => {
+                "Using a string in a 'for...of' statement is only supported in ECMAScript 5 and higher.".into()
+            }
+
+            ErrorKind::LetOrConstIsNotValidIdInLetOrConstVarDecls { .. } => {
+                "'let' is not allowed to be used as a name in 'let' or 'const' declarations.".into()
+            }
+
+            ErrorKind::InvalidExprOfLhsOfForIn { .. } => {
+                "The left-hand side of a 'for...in' statement must be a variable or a property access.".into()
+            }
+
+            ErrorKind::InvalidExprOfLhsOfForOf { .. } => {
+                "The left-hand side of a 'for...of' statement must be a variable or a property access.".into()
+            }
+
+            ErrorKind::WrongTypeForLhsOfForInLoop { .. } => {
+                "The left-hand side of a 'for...in' statement must be of type 'string' or 'any'.".into()
+            }
+
+            ErrorKind::DestructuringBindingNotAllowedInLhsOfForIn { .. } => {
+                "The left-hand side of a 'for...in' statement cannot be a destructuring pattern.".into()
+            }
+
+            ErrorKind::TypeAnnOnLhsOfForInLoops { .. } => {
+                "The left-hand side of a 'for...in' statement cannot use a type annotation.".into()
+            }
+
+            ErrorKind::TypeAnnOnLhsOfForOfLoops { .. } => {
+                "The left-hand side of a 'for...of' statement cannot use a type annotation.".into()
+            }
+
+            ErrorKind::CannotAccessPrivatePropertyFromOutside { .. } => {
+                "Property is not accessible outside class because it has a private identifier.".into()
+            }
+
+            ErrorKind::OptionalChainCannotContainPrivateIdentifier { .. } => "An optional chain cannot contain private identifiers.".into(),
+
+            ErrorKind::CannotDeletePrivateProperty { .. } => "The operand of a 'delete' operator cannot be a private identifier.".into(),
+
+            ErrorKind::ConstructorIsKeyword { .. } => "'#constructor' is a reserved word.".into(),
+
+            ErrorKind::PrivateIdUsedAsMethodName { .. } => "A method cannot be named with a private identifier.".into(),
+
+            ErrorKind::UndefinedOrNullIsNotValidOperand { .. } => "The value 'null' or 'undefined' cannot be used here.".into(),
+
+            ErrorKind::ThisInConstructorParam { .. } => "'this' cannot be referenced in constructor arguments.".into(),
+
+            ErrorKind::ThisInStaticPropertyInitializer { .. } => "'this' cannot be referenced in a static property initializer.".into(),
+
+            ErrorKind::InvalidSuperClass { .. } | ErrorKind::NotConstructorType { .. } => "Type is not a constructor function type.".into(),
+
+            ErrorKind::WithStmtNotSupported { .. } => {
+                "The 'with' statement is not supported. All symbols in a 'with' block will have type 'any'.".into()
+            }
+
+            ErrorKind::NamspaceNotFound { name, .. } => format!("Cannot find namespace '{}'.", name_to_string(name)).into(),
+
+            ErrorKind::EnumMemberIdCannotBeNumber { .. } => "An enum member cannot have a numeric name.".into(),
+
+            ErrorKind::InvalidLhsOfAssign { .. } => {
+                "The left-hand side of an assignment expression must be a variable or a property access.".into()
+            }
+
+            ErrorKind::ImplicitReturnType { .. } => {
+                "Function, which lacks return-type annotation, implicitly has an 'any' return type.".into()
+            }
+
+            ErrorKind::ImcompatibleFnOverload { .. } | ErrorKind::WrongOverloadSignature { .. } => {
+                "This overload signature is not compatible with its implementation signature.".into()
+            }
+
+            ErrorKind::InitializerDisallowedInAmbientContext { .. } => {
+                "A parameter initializer is only allowed in a function or constructor implementation.".into()
+            }
+
+            ErrorKind::InvalidClassName { .. } => "This name cannot be used as a class name.".into(),
+
+            ErrorKind::NoSuchVarForShorthand { name, .. } => format!(
+                "No value exists in scope for the shorthand property '{}'. Either declare one or provide an initializer.",
+                name.sym()
+            )
+            .into(),
+
+            ErrorKind::NoMatchingOverload { .. } => "No overload matches this call.".into(),
+
+            ErrorKind::InvalidInterfaceName { .. } => "This name cannot be used as an interface name.".into(),
+
+            ErrorKind::CannotCallWithNewNonVoidFunction { .. } => "Only a void function can be called with the 'new' keyword.".into(),
+
+            ErrorKind::DuplicateProperty { .. } | ErrorKind::DuplicateNameWithoutName { .. } => "Duplicate identifier.".into(),
+
+            ErrorKind::CannotExportNonLocalVar { .. } => "Only local declarations can be exported from a module.".into(),
+
+            ErrorKind::StaticPropertyCannotBeNamedPrototype { .. } => {
+                "Static property 'prototype' conflicts with built-in property 'Function.prototype' of the constructor function.".into()
+            }
+
+            ErrorKind::SelfReferentialSuperClass { .. } => "Class is referenced directly or indirectly in its own base expression.".into(),
+
+            ErrorKind::ExportMixedWithLocal { .. } => {
+                "Individual declarations in merged declaration must be all exported or all local.".into()
+            }
+
+            ErrorKind::ClassIncorrectlyImplementsInterface { .. } | ErrorKind::InvalidImplOfInterface { .. } => {
+                "Class incorrectly implements interface.".into()
+            }
+
+            ErrorKind::StackOverflow { .. } => "Stack overflow detected while checking this node.".into(),
+
+            ErrorKind::StaticMemberCannotUseTypeParamOfClass { .. } => "Static members cannot reference class type parameters.".into(),
+
+            ErrorKind::DeclaringTypeParamReferencedByComputedPropName { .. } => {
+                "A computed property name cannot reference a type parameter from its containing type.".into()
+            }
+
+            ErrorKind::CannotReferenceThisInComputedPropName { .. } => "'this' cannot be referenced in a computed property name.".into(),
+
+            ErrorKind::CannotReferenceSuperInComputedPropName { .. } => "'super' cannot be referenced in a computed property name.".into(),
+
+            ErrorKind::ThisRefToModuleOrNamespace { .. } => "'this' cannot be referenced in a module or namespace body.".into(),
+
+            ErrorKind::SuperInClassWithoutSuper { .. } => "'super' can only be referenced in a derived class.".into(),
+
+            ErrorKind::GeneratorCannotHaveVoidAsReturnType { .. } => "A generator cannot have a 'void' type annotation.".into(),
+
+            ErrorKind::NoSuchVarButThisHasSuchProperty { name, .. } => format!(
+                "Cannot find name '{}'. Did you mean the instance member 'this.{}'?",
+                name.sym(),
+                name.sym()
+            )
+            .into(),
+
+            ErrorKind::DestructuringAssignInAmbientContext { .. } => {
+                "Destructuring declarations are not allowed in ambient contexts.".into()
+            }
+
+            ErrorKind::OptionalBindingPatternInImplSignature { .. } => {
+                "A binding pattern parameter cannot be optional in an implementation signature.".into()
+            }
+
+            ErrorKind::NullishCoalescingMixedWithLogicalWithoutParen { .. } => {
+                "'??' operations cannot be mixed with '||' or '&&' operations without parentheses.".into()
+            }
+
+            ErrorKind::SwitchCaseTestNotCompatible { disc, test, .. } => format!(
+                "Type '{}' is not comparable to type '{}'.",
+                type_to_string(test),
+                type_to_string(disc)
+            )
+            .into(),
+
+            ErrorKind::EnumCannotBeLValue { .. } => "Cannot assign to an enum member because it is a read-only property.".into(),
+
+            ErrorKind::TypeInvalidForUpdateArg { .. } | ErrorKind::InvalidNumericOperand { .. } => {
+                "An arithmetic operand must be of type 'any', 'number', 'bigint' or an enum type.".into()
+            }
+
+            ErrorKind::PrivatePropertyIsDifferent { .. } => "Types have separate declarations of a private property.".into(),
+
+            ErrorKind::PrivateMethodIsDifferent { .. } => "Types have separate declarations of a private method.".into(),
+
+            ErrorKind::CannotCompareWithOp { op, left, right, .. } | ErrorKind::InvalidBinaryOp { op, left, right, .. } => format!(
+                "Operator '{}' cannot be applied to types '{}' and '{}'.",
+                op,
+                type_to_string(left),
+                type_to_string(right)
+            )
+            .into(),
+
+            ErrorKind::NoSuchEnumVariant { enum_name, name, .. } => {
+                format!("Property '{}' does not exist on type 'typeof {}'.", name, enum_name).into()
+            }
+
+            ErrorKind::ObjectIsPossiblyNull { .. } | ErrorKind::TS2531 { .. } => "Object is possibly 'null'.".into(),
+
+            ErrorKind::ObjectIsPossiblyUndefined { .. } | ErrorKind::MayBeUndefined { .. } => "Object is possibly 'undefined'.".into(),
+
+            ErrorKind::ObjectIsPossiblyUndefinedWithType { ty, .. } => {
+                format!("Object of type '{}' is possibly 'undefined'.", type_to_string(ty)).into()
+            }
+
+            ErrorKind::ObjectIsPossiblyNullOrUndefined { .. } => "Object is possibly 'null' or 'undefined'.".into(),
+
+            ErrorKind::CannotAssignAbstractConstructorToNonAbstractConstructor { .. } => {
+                "Cannot assign an abstract constructor type to a non-abstract constructor type.".into()
+            }
+
+            ErrorKind::InvalidUseOfConstEnum { .. } => "'const' enums can only be used in property or index access expressions or the \
+                                                        right hand side of an import declaration or export assignment or type query."
+                .into(),
+
+            ErrorKind::ComputedMemberInEnumWithStrMember { .. } => {
+                "Computed values are not permitted in an enum with string valued members.".into()
+            }
+
+            ErrorKind::CannotCreateInstanceOfAbstractClass { .. } => "Cannot create an instance of an abstract class.".into(),
+
+            ErrorKind::WrongArgType { inner, .. } => match &***inner {
+                ErrorKind::AssignFailed { left, right, .. } => format!(
+                    "Argument of type '{}' is not assignable to parameter of type '{}'.",
+                    type_to_string(right),
+                    type_to_string(left)
+                )
+                .into(),
+                _ => "".into(),
+            },
+
+            ErrorKind::ImportFailed { src, orig, .. } => format!("Module '\"{}\"' has no exported member '{}'.", src, orig.sym()).into(),
+
+            ErrorKind::ExportFailed { orig, .. } => format!("Cannot find name '{}' to export.", orig.sym()).into(),
+
+            ErrorKind::ExportAllFailed { .. } => "Failed to re-export the members of the module.".into(),
+
+            ErrorKind::NoSuchPropertyInThis { .. } => "Property does not exist on type 'this'.".into(),
+
+            ErrorKind::NoSuchPropertyInClass { class_name, prop, .. } => match class_name {
+                Some(class_name) => format!("Property '{}' does not exist on type '{}'.", key_to_string(prop), class_name.sym()).into(),
+                None => format!("Property '{}' does not exist on the class.", key_to_string(prop)).into(),
+            },
+
+            ErrorKind::TypeParameterCountMismatch { min, max, actual, .. } => {
+                format!("Expected {} type arguments, but got {}.", count_to_string(*min, *max), actual).into()
+            }
+
+            ErrorKind::ParameterCountMismatch { min, max, actual, .. } => {
+                format!("Expected {} arguments, but got {}.", count_to_string(*min, *max), actual).into()
+            }
+
+            ErrorKind::NoSuchPropertyInModule { name, prop, .. } => format!(
+                "Property '{}' does not exist on type '{}'.",
+                key_to_string(prop),
+                module_name_to_string(name)
+            )
+            .into(),
+
+            ErrorKind::ReturnRequired { .. } => {
+                "A function whose declared type is neither 'undefined', 'void', nor 'any' must return a value.".into()
+            }
+
+            ErrorKind::ConstructorRequired { .. } => "Target type requires a constructor.".into(),
+
+            ErrorKind::CannotAssignToNonVariable { .. } | ErrorKind::NotVariable { .. } => {
+                "Cannot assign to this expression because it is not a variable.".into()
+            }
+
+            ErrorKind::CannotAssignToModule { .. } => "Cannot use namespace as a value.".into(),
+
+            ErrorKind::CannotAssignToClass { .. } => "Cannot assign to a class.".into(),
+
+            ErrorKind::CannotAssignToEnum { .. } => "Cannot assign to an enum.".into(),
+
+            ErrorKind::CannotAssignToFunction { .. } => "Cannot assign to a function.".into(),
+
+            ErrorKind::AssignedWrapperToPrimitive { .. } => "A wrapper object type is not assignable to a primitive type.".into(),
+
+            ErrorKind::AccessibilityDiffers { .. } | ErrorKind::AssignFailedDueToAccessibility { .. } => {
+                "Property is private or protected in one type but not in the other.".into()
+            }
+
+            ErrorKind::InvalidInitInConstEnum { .. } => {
+                "const enum member initializers can only contain literal values and other computed enum values.".into()
+            }
+
+            ErrorKind::InvalidTupleCast { .. } | ErrorKind::NonOverlappingTypeCast { .. } => {
+                "Conversion of type may be a mistake because neither type sufficiently overlaps with the other. If this was intentional, \
+                 convert the expression to 'unknown' first."
+                    .into()
+            }
+
+            ErrorKind::NoOverlap { value, left, right, .. } => format!(
+                "This condition will always return '{}' since the types '{}' and '{}' have no overlap.",
+                value,
+                type_to_string(left),
+                type_to_string(right)
+            )
+            .into(),
+
+            ErrorKind::CannotAssignToReadonlyProperty { .. } | ErrorKind::InvalidLValue { .. } | ErrorKind::ReadOnly { .. } => {
+                "Cannot assign to this property because it is a read-only property.".into()
+            }
+
+            ErrorKind::ImplicitAny { .. } => "Variable implicitly has an 'any' type.".into(),
+
+            ErrorKind::ImplicitAnyBecauseNoIndexSignatureExists { .. } => {
+                "Element implicitly has an 'any' type because type has no index signature.".into()
+            }
+
+            ErrorKind::ImplicitAnyBecauseIndexTypeIsWrong { .. } => {
+                "Element implicitly has an 'any' type because expression can't be used to index type.".into()
+            }
+
+            ErrorKind::ImplicitAnyBecauseOfSelfRef { .. } => "Variable implicitly has type 'any' because it does not have a type \
+                                                              annotation and is referenced directly or indirectly in its own initializer."
+                .into(),
+
+            ErrorKind::TupleAssignError { errors, .. } | ErrorKind::Errors { errors, .. } => {
+                errors.iter().map(|err| err.headline()).collect::<Vec<_>>().join("\n").into()
+            }
+
+            ErrorKind::NoSuchType { name, .. } | ErrorKind::NoSuchVar { name, .. } => format!("Cannot find name '{}'.", name.sym()).into(),
+
+            ErrorKind::NoSuchTypeButVarExists { name, .. } => format!(
+                "'{}' refers to a value, but is being used as a type here. Did you mean 'typeof {}'?",
+                name.sym(),
+                name.sym()
+            )
+            .into(),
+
+            ErrorKind::InvalidUseOfArgumentsInEs3OrEs5 { .. } => "The 'arguments' object cannot be referenced in an arrow function in ES3 \
+                                                                  and ES5. Consider using a standard function expression."
+                .into(),
+
+            ErrorKind::ArgumentsCannotBeUsedInAsyncFnInEs3OrEs5 { .. } => "The 'arguments' object cannot be referenced in an async \
+                                                                           function or method in ES3 and ES5. Consider using a standard \
+                                                                           function or method."
+                .into(),
+
+            ErrorKind::TypeUsedAsVar { name, .. } => {
+                format!("'{}' only refers to a type, but is being used as a value here.", name.sym()).into()
+            }
+
+            ErrorKind::DuplicateName { name, .. } => format!("Duplicate identifier '{}'.", name.sym()).into(),
+
+            ErrorKind::UselessSeqExpr { .. } => "Left side of comma operator is unused and has no side effects.".into(),
+
+            ErrorKind::ReferencedInInit { .. } => "Parameter cannot reference itself.".into(),
+
+            ErrorKind::NotGeneric { .. } => "Type is not generic.".into(),
+
+            ErrorKind::Unknown { .. } => "Object is of type 'unknown'.".into(),
+
+            ErrorKind::NoSuchPropertyWhileDeclWithBidningPat { .. } | ErrorKind::NoInitAndNoDefault { .. } => {
+                "Initializer provides no value for this binding element and the binding element has no default value.".into()
+            }
+
+            ErrorKind::NoSuchProperty { obj, prop, .. } => match (obj, prop) {
+                (Some(obj), Some(prop)) => format!(
+                    "Property '{}' does not exist on type '{}'.",
+                    key_to_string(prop),
+                    type_to_string(obj)
+                )
+                .into(),
+                (None, Some(prop)) => format!("Property '{}' does not exist.", key_to_string(prop)).into(),
+                (Some(obj), None) => format!("Property does not exist on type '{}'.", type_to_string(obj)).into(),
+                (None, None) => "Property does not exist.".into(),
+            },
+
+            ErrorKind::TooManyTupleElements { .. } => "Tuple type has too many elements.".into(),
+
+            ErrorKind::NotTuple { .. } => "Type is not a tuple type.".into(),
+
+            ErrorKind::TypeNotFound { name, .. } => format!("Cannot find name '{}'.", name_to_string(name)).into(),
+
+            ErrorKind::TS2378 { .. } => "A 'get' accessor must return a value.".into(),
+
+            ErrorKind::ConstEnumNonIndexAccess { .. } => "A const enum member can only be accessed using a string literal.".into(),
+
+            ErrorKind::TupleIndexError { len, index, .. } => {
+                format!("Tuple type of length '{}' has no element at index '{}'.", len, index).into()
+            }
+
+            ErrorKind::NegativeTupleIndex { .. } => "A tuple type cannot be indexed with a negative value.".into(),
+
+            ErrorKind::Unimplemented { msg, .. } => format!("Not implemented yet: {}", msg).into(),
+
+            // Printed by `write_message`, as the message depends on the enclosing assignment.
+            ErrorKind::MissingFields { .. } => "".into(),
+
+            ErrorKind::AssignFailed { left, right, .. } => format!(
+                "Type '{}' is not assignable to type '{}'.",
+                type_to_string(right),
+                type_to_string(left)
+            )
+            .into(),
+
+            ErrorKind::AssignFailedDueToOptionalityDifference { .. } => {
+                "Property is optional in the source type but required in the target type.".into()
+            }
+
+            ErrorKind::AssignFailedDueToExactOptionalPropertyTypes { left, right, .. } => format!(
+                "Type '{}' is not assignable to type '{}' with 'exactOptionalPropertyTypes: true'. Consider adding 'undefined' to the \
+                 types of the target's properties.",
+                type_to_string(right),
+                type_to_string(left)
+            )
+            .into(),
+
+            // `tsc` does not print a message for these. Only the causes are printed.
+            ErrorKind::ObjectAssignFailed { .. }
+            | ErrorKind::SimpleAssignFailed { .. }
+            | ErrorKind::SimpleAssignFailedWithCause { .. }
+            | ErrorKind::InvalidAssignmentOfArray { .. }
+            | ErrorKind::UnionError { .. }
+            | ErrorKind::IntersectionError { .. }
+            | ErrorKind::ModuleLoadFailed { .. } => "".into(),
+
+            ErrorKind::CannotAssingToThis { .. } => "Cannot assign to 'this'.".into(),
+
+            ErrorKind::UndefinedSymbol { sym, .. } => format!("Cannot find name '{}'.", sym.sym()).into(),
+
+            ErrorKind::NoSuchExport { items, .. } => format!(
+                "Module has no exported member {}.",
+                items.iter().map(|id| format!("'{}'", id.sym())).collect::<Vec<_>>().join(", ")
+            )
+            .into(),
+
+            ErrorKind::NoNewSignature { callee, .. } => format!(
+                "This expression is not constructable.\n  Type '{}' has no construct signatures.",
+                type_to_string(callee)
+            )
+            .into(),
+
+            ErrorKind::NoConstructablePropertyWithName { obj, key, .. } => format!(
+                "This expression is not constructable.\n  Property '{}' of type '{}' has no construct signatures.",
+                key_to_string(key),
+                type_to_string(obj)
+            )
+            .into(),
+
+            ErrorKind::NoCallSignature { callee, .. } => format!(
+                "This expression is not callable.\n  Type '{}' has no call signatures.",
+                type_to_string(callee)
+            )
+            .into(),
+
+            ErrorKind::NoCallablePropertyWithName { obj, key, .. } => format!(
+                "This expression is not callable.\n  Property '{}' of type '{}' has no call signatures.",
+                key_to_string(key),
+                type_to_string(obj)
+            )
+            .into(),
+
+            ErrorKind::WrongTypeParams { expected, actual, .. } => format!(
+                "Expected {} type arguments, but got {}.",
+                count_to_string(*expected.start(), *expected.end()),
+                actual
+            )
+            .into(),
+
+            ErrorKind::WrongParams { expected, actual, .. } => format!(
+                "Expected {} arguments, but got {}.",
+                count_to_string(*expected.start(), *expected.end()),
+                actual
+            )
+            .into(),
+
+            ErrorKind::InvalidEnumInit { .. } => "Enum member initializer is not valid.".into(),
+
+            ErrorKind::TS1016 { .. } => "A required parameter cannot follow an optional parameter.".into(),
+
+            ErrorKind::TS1063 { .. } => "An export assignment cannot be used in a namespace.".into(),
+
+            ErrorKind::TS1094 { .. } => "An accessor cannot have type parameters.".into(),
+
+            ErrorKind::TS1095 { .. } => "A 'set' accessor cannot have a return type annotation.".into(),
+
+            ErrorKind::TS1168 { .. } => "A computed property name in a method overload must refer to an expression whose type is a \
+                                         literal type or a 'unique symbol' type."
+                .into(),
+
+            ErrorKind::TS1169 { .. } => "A computed property name in an interface must refer to an expression whose type is a literal \
+                                         type or a 'unique symbol' type."
+                .into(),
+
+            ErrorKind::TS1183 { .. } => "An implementation cannot be declared in ambient contexts.".into(),
+
+            ErrorKind::TS1318 { .. } => "Method cannot have an implementation because it is marked abstract.".into(),
+
+            ErrorKind::TS1319 { .. } => "A default export can only be used in an ECMAScript-style module.".into(),
+
+            ErrorKind::ExportEqualsMixedWithOtherExports { .. } => {
+                "An export assignment cannot be used in a module with other exported elements.".into()
+            }
+
+            ErrorKind::TS2360 { .. } => "The left-hand side of an 'in' expression must be a private identifier or of type 'any', \
+                                         'string', 'number', or 'symbol'."
+                .into(),
+
+            ErrorKind::InvalidRhsForInOperator { ty, .. } => format!(
+                "Type '{}' may represent a primitive value, which is not permitted as the right operand of the 'in' operator.",
+                type_to_string(ty)
+            )
+            .into(),
+
+            ErrorKind::WrongTypeForLhsOfNumericOperation { .. } => {
+                "The left-hand side of an arithmetic operation must be of type 'any', 'number', 'bigint' or an enum type.".into()
+            }
+
+            ErrorKind::WrongTypeForRhsOfNumericOperation { .. } => {
+                "The right-hand side of an arithmetic operation must be of type 'any', 'number', 'bigint' or an enum type.".into()
+            }
+
+            ErrorKind::TS2370 { .. } => "A rest parameter must be of an array type.".into(),
+
+            ErrorKind::TS1166 { .. } => {
+                "A computed property name in a class property declaration must have a simple literal type or a 'unique symbol' type.".into()
+            }
+
+            ErrorKind::TS1345 { .. } => "An expression of type 'void' cannot be tested for truthiness.".into(),
+
+            ErrorKind::TS2353 { .. } | ErrorKind::UnknownPropertyInObjectLiteralAssignment { .. } => {
+                "Object literal may only specify known properties.".into()
+            }
+
+            ErrorKind::ConstructorImplMissingOrNotFollowedByDecl { .. } => "Constructor implementation is missing.".into(),
+
+            ErrorKind::FnImplMissingOrNotFollowedByDecl { .. } => {
+                "Function implementation is missing or not immediately following the declaration.".into()
+            }
+
+            ErrorKind::InvalidTypeForComputedProperty { .. } => {
+                "A computed property name must be of type 'string', 'number', 'symbol', or 'any'.".into()
+            }
+
+            ErrorKind::ParamPropIsNotAllowedInAmbientConstructorx { .. } => {
+                "A parameter property is only allowed in a constructor implementation.".into()
+            }
+
+            ErrorKind::TS2389 { .. } => "Function implementation name must match the name of the overload signatures.".into(),
+
+            ErrorKind::TS2447 { .. } => "This operator is not allowed for boolean types.".into(),
+
+            ErrorKind::ClassDoesNotImplementMemeber { key, .. } => format!(
+                "Non-abstract class does not implement inherited abstract member '{}'.",
+                key_to_string(key)
+            )
+            .into(),
+
+            ErrorKind::TS2567 { .. } => "Enum declarations can only merge with namespace or other enum declarations.".into(),
+
+            ErrorKind::TS2585 { .. } => {
+                "This name only refers to a type, but is being used as a value here. Do you need to change your target library?".into()
+            }
+
+            ErrorKind::TS2704 { .. } => "The operand of a 'delete' operator cannot be a read-only property.".into(),
+
+            ErrorKind::InvalidLhsInInstanceOf { .. } => {
+                "The left-hand side of an 'instanceof' expression must be of type 'any', an object type or a type parameter.".into()
+            }
+
+            ErrorKind::InvalidRhsInInstanceOf { .. } => "The right-hand side of an 'instanceof' expression must be of type 'any' or of a \
+                                                         type assignable to the 'Function' interface type."
+                .into(),
+
+            ErrorKind::NumericOpToSymbol { .. } => "This operator cannot be applied to type 'symbol'.".into(),
+
+            ErrorKind::UpdateOpToSymbol { op, .. } => format!("The '{}' operator cannot be applied to type 'symbol'.", op).into(),
+
+            ErrorKind::InvalidOperatorForLhs { op, .. } => format!("Operator '{}' cannot be applied to the left-hand side.", op).into(),
+
+            ErrorKind::InvalidOpAssign { op, lhs, rhs, .. } => format!(
+                "Operator '{}' cannot be applied to types '{}' and '{}'.",
+                op,
+                type_to_string(lhs),
+                type_to_string(rhs)
+            )
+            .into(),
+
+            ErrorKind::AssignOpCannotBeApplied { op, .. } => format!("Operator '{}' cannot be applied to these types.", op).into(),
+
+            ErrorKind::NonSymbolComputedPropInFormOfSymbol { .. } => {
+                "A computed property name of the form 'Symbol.xxx' must be of type 'symbol'.".into()
+            }
+
+            ErrorKind::ExpectedNArgsButGotM { min, max, actual, .. } => format!(
+                "Expected {} arguments, but got {}.",
+                count_to_string(*min, max.unwrap_or(*min)),
+                actual
+            )
+            .into(),
+
+            ErrorKind::ExpectedAtLeastNArgsButGotM { min, .. } | ErrorKind::ExpectedAtLeastNArgsButGotMOrMore { min, .. } => {
+                format!("Expected at least {} arguments.", min).into()
+            }
+
+            ErrorKind::ExpectedNArgsButGotMOrMore { .. } | ErrorKind::SpreadMustBeTupleOrPassedToRest { .. } => {
+                "A spread argument must either have a tuple type or be passed to a rest parameter.".into()
+            }
+
+            ErrorKind::InvalidDeleteOperand { .. } => "The operand of a 'delete' operator must be a property reference.".into(),
+
+            ErrorKind::NoMethodNamedNext { .. } => "An iterator must have a 'next()' method.".into(),
+
+            ErrorKind::MustHaveSymbolIteratorThatReturnsIteratorOrMustBeArray { .. } => {
+                "Type is not an array type or does not have a '[Symbol.iterator]()' method that returns an iterator.".into()
+            }
+
+            ErrorKind::MustHaveSymbolIteratorThatReturnsIterator { .. } => {
+                "Type must have a '[Symbol.iterator]()' method that returns an iterator.".into()
+            }
+
+            ErrorKind::MustHaveSymbolAsyncIteratorThatReturnsIterator { .. } => {
+                "Type must have a '[Symbol.asyncIterator]()' method that returns an async iterator.".into()
+            }
+
+            ErrorKind::NoSuchConstructor { key, .. } => format!("Cannot find a constructor named '{}'.", key_to_string(key)).into(),
+
+            ErrorKind::AbstractAndConcreteIsMixed { .. } => "Overload signatures must all be abstract or non-abstract.".into(),
+
+            ErrorKind::ShouldBeStaticMethod { .. } => "Function overload must be static.".into(),
+
+            ErrorKind::ShouldBeInstanceMethod { .. } => "Function overload must not be static.".into(),
+
+            ErrorKind::RestPropertyNotLast { .. } => "A rest element must be last in a destructuring pattern.".into(),
+
+            ErrorKind::JsxElementTypeHasNoSignatures { name, .. } => {
+                format!("JSX element type '{}' does not have any construct or call signatures.", name).into()
+            }
+
+            ErrorKind::InvalidJsxComponent { name, .. } => format!("'{}' cannot be used as a JSX component.", name).into(),
+
+            ErrorKind::NoJsxIntrinsicElements { .. } => {
+                "JSX element implicitly has type 'any' because no interface 'JSX.IntrinsicElements' exists.".into()
+            }
+
+            ErrorKind::UnusedLocal { name, .. } => format!("'{}' is declared but its value is never read.", name).into(),
+
+            ErrorKind::UnusedProperty { name, .. } => format!("Property '{}' is declared but its value is never read.", name).into(),
+
+            ErrorKind::AllImportsUnused { .. } => "All imports in import declaration are unused.".into(),
+
+            ErrorKind::UnusedType { name, .. } => format!("'{}' is declared but never used.", name).into(),
+
+            ErrorKind::AllDestructuredElementsUnused { .. } => "All destructured elements are unused.".into(),
+
+            ErrorKind::AllVariablesUnused { .. } => "All variables are unused.".into(),
+
+            ErrorKind::FallthroughCaseInSwitch { .. } => "Fallthrough case in switch.".into(),
+
+            ErrorKind::NotAllCodePathsReturnValue { .. } => "Not all code paths return a value.".into(),
+
+            ErrorKind::UnusedExpectErrorDirective { .. } => "Unused '@ts-expect-error' directive.".into(),
+
+            ErrorKind::IsolatedDeclFnReturnType { .. } => {
+                "Function must have an explicit return type annotation with --isolatedDeclarations.".into()
+            }
+
+            ErrorKind::IsolatedDeclMethodReturnType { .. } => {
+                "Method must have an explicit return type annotation with --isolatedDeclarations.".into()
+            }
+
+            ErrorKind::IsolatedDeclAccessorType { .. } => {
+                "At least one accessor must have an explicit type annotation with --isolatedDeclarations.".into()
+            }
+
+            ErrorKind::IsolatedDeclVarType { .. } => "Variable must have an explicit type annotation with --isolatedDeclarations.".into(),
+
+            ErrorKind::IsolatedDeclParamType { .. } => {
+                "Parameter must have an explicit type annotation with --isolatedDeclarations.".into()
+            }
+
+            ErrorKind::IsolatedDeclPropType { .. } => "Property must have an explicit type annotation with --isolatedDeclarations.".into(),
+
+            ErrorKind::IsolatedDeclDefaultExport { .. } => "Default exports can't be inferred with --isolatedDeclarations.".into(),
+
+            ErrorKind::FileNotUnderRootDir { file, root_dir, .. } => format!(
+                "File '{}' is not under 'rootDir' '{}'. 'rootDir' is expected to contain all source files.",
+                file, root_dir
+            )
+            .into(),
+
+            ErrorKind::DecoratorNotValidHere { .. } => "Decorators are not valid here.".into(),
+
+            ErrorKind::InvalidClassDecorator { .. } => {
+                "Unable to resolve signature of class decorator when called as an expression.".into()
+            }
+
+            ErrorKind::InvalidParamDecorator { .. } => {
+                "Unable to resolve signature of parameter decorator when called as an expression.".into()
+            }
+
+            ErrorKind::InvalidPropDecorator { .. } => {
+                "Unable to resolve signature of property decorator when called as an expression.".into()
+            }
+
+            ErrorKind::InvalidMethodDecorator { .. } => {
+                "Unable to resolve signature of method decorator when called as an expression.".into()
+            }
+
+            ErrorKind::DecoratorReturnTypeNotAssignable { ret, expected, .. } => format!(
+                "Decorator function return type '{}' is not assignable to type '{}'.",
+                type_to_string(ret),
+                type_to_string(expected)
+            )
+            .into(),
+        }
+    }
+}
+
+/// `assign` is `(target, source)` of the innermost assignment containing
+/// `err`.
+fn write_message(buf: &mut String, err: &ErrorKind, depth: usize, assign: Option<(&Type, &Type)>) {
+    if let ErrorKind::Errors { errors, .. } | ErrorKind::TupleAssignError { errors, .. } = err {
+        for err in errors {
+            write_message(buf, err, depth, assign);
+        }
+        return;
+    }
+
+    let headline = match err {
+        ErrorKind::MissingFields { fields, .. } => missing_fields_message(fields, assign).into(),
+        _ => err.headline(),
+    };
+
+    for line in headline.lines() {
+        if !buf.is_empty() {
+            buf.push('\n');
+        }
+        for _ in 0..depth {
+            buf.push_str("  ");
+        }
+        buf.push_str(line);
+    }
+
+    let depth = if headline.is_empty() { depth } else { depth + 1 };
+    let assign = err.assigned_types().or(assign);
+    for cause in err.causes() {
+        write_message(buf, cause, depth, assign);
+    }
+}
+
+/// TS2741, TS2739 and TS2740
+fn missing_fields_message(fields: &[TypeElement], assign: Option<(&Type, &Type)>) -> String {
+    let names = fields.iter().filter_map(TypeElement::key).map(key_to_string).collect::<Vec<_>>();
+
+    let (left, right) = match assign {
+        Some((left, right)) => (type_to_string(left), type_to_string(right)),
+        None => {
+            return format!("Type is missing the following properties: {}", names.join(", "));
+        }
+    };
+
+    match &*names {
+        [name] => format!(
+            "Property '{}' is missing in type '{}' but required in type '{}'.",
+            name, right, left
+        ),
+        _ if names.len() <= 4 => format!(
+            "Type '{}' is missing the following properties from type '{}': {}",
+            right,
+            left,
+            names.join(", ")
+        ),
+        _ => format!(
+            "Type '{}' is missing the following properties from type '{}': {}, and {} more.",
+            right,
+            left,
+            names[..4].join(", "),
+            names.len() - 4
+        ),
+    }
+}
+
+/// `1` or `1-2`
+fn count_to_string(min: usize, max: usize) -> String {
+    if min == max {
+        min.to_string()
+    } else {
+        format!("{}-{}", min, max)
+    }
+}
+
+fn name_to_string(name: &Name) -> String {
+    name.as_ids().iter().map(|id| &**id.sym()).collect::<Vec<_>>().join(".")
+}
+
+/// Type of a module or a namespace, in the form of `typeof import("foo")`.
+fn module_name_to_string(name: &RTsModuleName) -> String {
+    match name {
+        RTsModuleName::Ident(i) => format!("typeof {}", i.sym),
+        RTsModuleName::Str(s) => format!("typeof import(\"{}\")", s.value),
+    }
+}
+
+pub fn key_to_string(key: &Key) -> String {
+    match key {
+        Key::Computed(key) => format!("[{}]", type_to_string(&key.ty)),
+        Key::Normal { sym, .. } => sym.to_string(),
+        Key::Num(n) => n.value.to_string(),
+        Key::BigInt(n) => format!("{}n", n.value),
+        Key::Private(n) => format!("#{}", n.id.sym()),
+    }
+}
+
+/// Prints a type in the form used by error messages.
+///
+/// This uses the same code path as [crate::debug::debugger::Debugger], but
+/// drops syntax contexts and prints the type in a single line.
+pub fn type_to_string(ty: &Type) -> String {
+    let ty = ALLOW_DEEP_CLONE.set(&(), || ty.clone().fold_with(&mut Printable));
+    let mut ty = RTsType::from(ty).into_orig();
+    ty.visit_mut_with(&mut DropSpan { preserve_ctxt: false });
+
+    let cm = Lrc::new(SourceMap::default());
+    let mut buf = vec![];
+
+    {
+        let mut emitter = Emitter {
+            cfg: swc_ecma_codegen::Config {
+                minify: false,
+                ..Default::default()
+            },
+            cm: cm.clone(),
+            comments: None,
+            wr: box JsWriter::new(cm, "\n", &mut buf, None),
+        };
+
+        ty.emit_with(&mut emitter).unwrap();
+    }
+
+    String::from_utf8_lossy(&buf).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Replaces types which cannot be converted to [RTsType].
+struct Printable;
+
+impl Fold<Type> for Printable {
+    fn fold(&mut self, mut ty: Type) -> Type {
+        match ty.normalize() {
+            Type::Module(Module { span, name, .. }) => {
+                let name = match name {
+                    RTsModuleName::Ident(i) => format!("typeof {}", i.sym),
+                    RTsModuleName::Str(s) => format!("typeof import(\"{}\")", s.value),
+                };
+                return named_ref(*span, name);
+            }
+            Type::Namespace(Namespace { span, name, .. }) => return named_ref(*span, format!("typeof {}", name.sym())),
+            _ => {}
+        }
+
+        ty.normalize_mut();
+        ty.fold_children_with(self)
+    }
+}
+
+/// A reference which is printed as `name`.
+fn named_ref(span: Span, name: String) -> Type {
+    Type::Ref(Ref {
+        span,
+        type_name: RTsEntityName::Ident(RIdent::new(name.into(), span)),
+        type_args: None,
+        metadata: Default::default(),
+    })
+}
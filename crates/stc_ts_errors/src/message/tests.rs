@@ -0,0 +1,208 @@
+//! Messages of common errors, pinned to the text `tsc` prints.
+
+use stc_ts_ast_rnode::{RIdent, RTsEntityName};
+use stc_ts_types::{Id, Key, KeywordType, PropertySignature, Ref, Type, TypeElement};
+use swc_common::{DUMMY_SP, GLOBALS};
+use swc_ecma_ast::TsKeywordTypeKind;
+
+use crate::ErrorKind;
+
+fn keyword(kind: TsKeywordTypeKind) -> Box<Type> {
+    box Type::Keyword(KeywordType {
+        span: DUMMY_SP,
+        kind,
+        metadata: Default::default(),
+    })
+}
+
+fn type_ref(name: &str) -> Box<Type> {
+    box Type::Ref(Ref {
+        span: DUMMY_SP,
+        type_name: RTsEntityName::Ident(RIdent::new(name.into(), DUMMY_SP)),
+        type_args: None,
+        metadata: Default::default(),
+    })
+}
+
+fn assign_failed(left: Box<Type>, right: Box<Type>) -> ErrorKind {
+    ErrorKind::AssignFailed {
+        span: DUMMY_SP,
+        left,
+        right_ident: None,
+        right,
+        cause: vec![],
+    }
+}
+
+#[track_caller]
+fn assert_message(err: ErrorKind, code: usize, expected: &str) {
+    GLOBALS.set(&Default::default(), || {
+        assert_eq!(err.code(), code);
+        assert_eq!(err.message(), expected);
+    })
+}
+
+#[test]
+fn ts2322() {
+    assert_message(
+        assign_failed(
+            keyword(TsKeywordTypeKind::TsNumberKeyword),
+            keyword(TsKeywordTypeKind::TsStringKeyword),
+        ),
+        2322,
+        "Type 'string' is not assignable to type 'number'.",
+    );
+}
+
+#[test]
+fn ts2339() {
+    assert_message(
+        ErrorKind::NoSuchProperty {
+            span: DUMMY_SP,
+            obj: Some(type_ref("Foo")),
+            prop: Some(box Key::Normal {
+                span: DUMMY_SP,
+                sym: "bar".into(),
+            }),
+        },
+        2339,
+        "Property 'bar' does not exist on type 'Foo'.",
+    );
+}
+
+#[test]
+fn ts2345() {
+    assert_message(
+        ErrorKind::WrongArgType {
+            span: DUMMY_SP,
+            inner: box assign_failed(
+                keyword(TsKeywordTypeKind::TsNumberKeyword),
+                keyword(TsKeywordTypeKind::TsStringKeyword),
+            )
+            .into(),
+        },
+        2345,
+        "Argument of type 'string' is not assignable to parameter of type 'number'.",
+    );
+}
+
+#[test]
+fn ts2304() {
+    assert_message(
+        ErrorKind::NoSuchVar {
+            span: DUMMY_SP,
+            name: Id::word("foo".into()),
+        },
+        2304,
+        "Cannot find name 'foo'.",
+    );
+}
+
+#[test]
+fn elaboration_is_indented() {
+    let cause = assign_failed(
+        keyword(TsKeywordTypeKind::TsNumberKeyword),
+        keyword(TsKeywordTypeKind::TsStringKeyword),
+    );
+    let err = ErrorKind::AssignFailed {
+        span: DUMMY_SP,
+        left: type_ref("Foo"),
+        right_ident: None,
+        right: type_ref("Bar"),
+        cause: vec![cause.into()],
+    };
+
+    assert_message(
+        err,
+        2322,
+        "Type 'Bar' is not assignable to type 'Foo'.\n  Type 'string' is not assignable to type 'number'.",
+    );
+}
+
+#[test]
+fn ts2307() {
+    assert_message(
+        ErrorKind::ModuleNotFound {
+            span: DUMMY_SP,
+            src: "./foo".into(),
+        },
+        2307,
+        "Cannot find module './foo' or its corresponding type declarations.",
+    );
+}
+
+#[test]
+fn ts2554() {
+    assert_message(
+        ErrorKind::ExpectedNArgsButGotM {
+            span: DUMMY_SP,
+            min: 1,
+            max: Some(2),
+            actual: 3,
+        },
+        2554,
+        "Expected 1-2 arguments, but got 3.",
+    );
+}
+
+#[test]
+fn missing_field_uses_types_of_assignment() {
+    let err = ErrorKind::AssignFailed {
+        span: DUMMY_SP,
+        left: type_ref("Foo"),
+        right_ident: None,
+        right: type_ref("Bar"),
+        cause: vec![ErrorKind::MissingFields {
+            span: DUMMY_SP,
+            fields: vec![TypeElement::Property(PropertySignature {
+                span: DUMMY_SP,
+                accessibility: None,
+                readonly: false,
+                key: Key::Normal {
+                    span: DUMMY_SP,
+                    sym: "foo".into(),
+                },
+                optional: false,
+                params: vec![],
+                type_ann: None,
+                type_params: None,
+                metadata: Default::default(),
+                accessor: Default::default(),
+            })],
+        }
+        .into()],
+    };
+
+    assert_message(
+        err,
+        2322,
+        "Type 'Bar' is not assignable to type 'Foo'.\n  Property 'foo' is missing in type 'Bar' but required in type 'Foo'.",
+    );
+}
+
+#[test]
+fn causes_without_message_are_not_indented() {
+    let cause = ErrorKind::SimpleAssignFailed {
+        span: DUMMY_SP,
+        cause: Some(
+            box assign_failed(
+                keyword(TsKeywordTypeKind::TsNumberKeyword),
+                keyword(TsKeywordTypeKind::TsStringKeyword),
+            )
+            .into(),
+        ),
+    };
+    let err = ErrorKind::AssignFailed {
+        span: DUMMY_SP,
+        left: type_ref("Foo"),
+        right_ident: None,
+        right: type_ref("Bar"),
+        cause: vec![cause.into()],
+    };
+
+    assert_message(
+        err,
+        2322,
+        "Type 'Bar' is not assignable to type 'Foo'.\n  Type 'string' is not assignable to type 'number'.",
+    );
+}
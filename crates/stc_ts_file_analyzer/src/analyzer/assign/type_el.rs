@@ -9,7 +9,10 @@ use stc_ts_types::{
     Array, Class, ClassDef, ClassMember, Function, Key, KeywordType, LitType, MethodSignature, Operator, PropertySignature, Ref, TplType,
     Tuple, Type, TypeElement, TypeLit, TypeLitMetadata, TypeParamInstantiation, Union, UnionMetadata,
 };
-use stc_utils::{cache::Freeze, ext::SpanExt};
+use stc_utils::{
+    cache::Freeze,
+    ext::{SpanExt, TypeVecExt},
+};
 use swc_atoms::js_word;
 use swc_common::{Span, Spanned, SyntaxContext, TypeEq, DUMMY_SP};
 use swc_ecma_ast::{Accessibility, TsKeywordTypeKind, TsTypeOperatorOp};
@@ -1111,12 +1114,42 @@ impl Analyzer<'_, '_> {
                                                 }
                                             }
 
-                                            self.assign_inner(
-                                                data,
-                                                lp.type_ann.as_deref().unwrap_or(&Type::any(span, Default::default())),
-                                                rp.type_ann.as_deref().unwrap_or(&Type::any(span, Default::default())),
-                                                opts,
-                                            )
+                                            let l_ty = lp
+                                                .type_ann
+                                                .as_deref()
+                                                .cloned()
+                                                .unwrap_or_else(|| Type::any(span, Default::default()));
+                                            let r_ty = rp
+                                                .type_ann
+                                                .as_deref()
+                                                .cloned()
+                                                .unwrap_or_else(|| Type::any(span, Default::default()));
+
+                                            if !lp.optional || !self.rule().strict_null_checks || !self.rule().exact_optional_property_types
+                                            {
+                                                return self.assign_inner(data, &l_ty, &r_ty, opts);
+                                            }
+
+                                            // Report a dedicated error if `undefined` is the only reason of the failure.
+                                            let mut types = vec![l_ty.clone(), Type::undefined(span, Default::default())];
+                                            types.dedup_type();
+                                            let l_ty_with_undefined = Type::union(types);
+
+                                            self.assign_inner(data, &l_ty, &r_ty, opts).map_err(|err| {
+                                                if self
+                                                    .assign_inner(&mut Default::default(), &l_ty_with_undefined, &r_ty, opts)
+                                                    .is_ok()
+                                                {
+                                                    ErrorKind::AssignFailedDueToExactOptionalPropertyTypes {
+                                                        span,
+                                                        left: box l_ty.clone(),
+                                                        right: box r_ty.clone(),
+                                                    }
+                                                    .into()
+                                                } else {
+                                                    err
+                                                }
+                                            })
                                         })()?;
 
                                         if let Some(pos) = unhandled_rhs.iter().position(|span| *span == rm.span()) {
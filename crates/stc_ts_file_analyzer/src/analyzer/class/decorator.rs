@@ -0,0 +1,516 @@
+//! Decorators are validated as calls with synthesized arguments, like tagged
+//! templates.
+
+use rnode::{NodeId, VisitWith};
+use stc_ts_ast_rnode::{
+    RBindingIdent, RClass, RClassMember, RDecorator, RIdent, RNumber, RParamOrTsParamProp, RPat, RPrivateName, RPropName, RStr,
+    RTsEntityName, RTsIndexedAccessType, RTsKeywordType, RTsLit, RTsLitType, RTsType, RTsTypeQuery, RTsTypeQueryExpr, RTsTypeRef,
+};
+use stc_ts_errors::{Error, ErrorKind};
+use stc_ts_types::{ClassDef, ClassMember, FnParam, Function, Id, Interface, Key, LitType, Type, TypeLit, TypeParamInstantiation};
+use stc_utils::cache::Freeze;
+use swc_common::{Span, SyntaxContext};
+use swc_ecma_ast::{MethodKind, TsKeywordTypeKind};
+
+use crate::{
+    analyzer::{util::ResultExt, Analyzer, ScopeKind},
+    validator::ValidateWith,
+    VResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DecoratorKind {
+    Class,
+    Method,
+    Prop,
+    Param,
+}
+
+impl Analyzer<'_, '_> {
+    /// Validates decorators of `c` and its members. `name` should be declared
+    /// as the class.
+    ///
+    /// Decorators of parameters are only validated as calls. Other parts are
+    /// validated while validating the parameters.
+    pub(super) fn validate_decorators(&mut self, name: Option<&RIdent>, c: &RClass) {
+        let experimental = self.rule().experimental_decorators;
+
+        // Types of contexts of standard decorators are declared in
+        // `lib.decorators.d.ts`.
+        let can_check = experimental || self.env.get_global_type(c.span, &"ClassDecoratorContext".into()).is_ok();
+
+        let name = match name {
+            Some(name) if can_check && !self.ctx.in_declare && has_decorators(c) => name,
+            _ => {
+                c.decorators.visit_with(self);
+                return;
+            }
+        };
+
+        let span = c.span.with_ctxt(SyntaxContext::empty());
+
+        let ctor_type = RTsType::TsTypeQuery(RTsTypeQuery {
+            node_id: NodeId::invalid(),
+            span,
+            expr_name: RTsTypeQueryExpr::TsEntityName(RTsEntityName::Ident(name.clone())),
+            type_args: None,
+        });
+        // Type arguments are not known.
+        let instance_type = if c.type_params.is_some() {
+            RTsType::TsKeywordType(RTsKeywordType {
+                span,
+                kind: TsKeywordTypeKind::TsAnyKeyword,
+            })
+        } else {
+            RTsType::TsTypeRef(RTsTypeRef {
+                node_id: NodeId::invalid(),
+                span,
+                type_name: RTsEntityName::Ident(name.clone()),
+                type_params: None,
+            })
+        };
+        let ctor = self.synthesized_type(span, ctor_type.clone());
+        let instance = self.synthesized_type(span, instance_type.clone());
+
+        for d in &c.decorators {
+            if experimental {
+                self.validate_decorator(d, DecoratorKind::Class, vec![ctor.clone()], Some(or_void(span, ctor.clone())));
+            } else {
+                let context = self.global_type(span, "ClassDecoratorContext", vec![ctor.clone()]);
+                self.validate_decorator(
+                    d,
+                    DecoratorKind::Class,
+                    vec![ctor.clone(), context],
+                    Some(or_void(span, ctor.clone())),
+                );
+            }
+        }
+
+        // Private members can't be accessed with an indexed access type, so their
+        // types are taken from the class.
+        let class = self.find_type(&Id::from(name)).ok().flatten().and_then(|mut types| {
+            types.find_map(|ty| match ty.normalize() {
+                Type::ClassDef(def) => Some(def.clone()),
+                _ => None,
+            })
+        });
+
+        for member in &c.body {
+            match member {
+                RClassMember::Constructor(constructor) => {
+                    if !experimental {
+                        continue;
+                    }
+
+                    for (idx, param) in constructor.params.iter().enumerate() {
+                        let decorators = match param {
+                            RParamOrTsParamProp::TsParamProp(p) => &p.decorators,
+                            RParamOrTsParamProp::Param(p) => &p.decorators,
+                        };
+
+                        for d in decorators {
+                            let args = vec![ctor.clone(), Type::undefined(span, Default::default()), num_lit(span, idx)];
+                            self.validate_decorator(d, DecoratorKind::Param, args, None);
+                        }
+                    }
+                }
+
+                RClassMember::Method(m) => {
+                    let has_param_decorators = experimental && m.function.params.iter().any(|param| !param.decorators.is_empty());
+                    if m.function.decorators.is_empty() && !has_param_decorators {
+                        continue;
+                    }
+
+                    let key_type = match prop_name_type(&m.key) {
+                        Some(key) => key,
+                        None => {
+                            m.function.decorators.visit_with(self);
+                            continue;
+                        }
+                    };
+                    let (this_type, this) = if m.is_static {
+                        (&ctor_type, &ctor)
+                    } else {
+                        (&instance_type, &instance)
+                    };
+                    let key = self.synthesized_type(span, key_type.clone());
+
+                    if !m.function.decorators.is_empty() {
+                        let value = self.synthesized_type(span, indexed_access(span, this_type.clone(), key_type));
+                        self.validate_method_decorators(span, &m.function.decorators, m.kind, this, Some(&key), value);
+                    }
+
+                    if has_param_decorators {
+                        for (idx, param) in m.function.params.iter().enumerate() {
+                            for d in &param.decorators {
+                                let args = vec![this.clone(), key.clone(), num_lit(span, idx)];
+                                self.validate_decorator(d, DecoratorKind::Param, args, None);
+                            }
+                        }
+                    }
+                }
+
+                RClassMember::PrivateMethod(m) => {
+                    if m.function.decorators.is_empty() {
+                        continue;
+                    }
+
+                    let this = if m.is_static { &ctor } else { &instance };
+                    let value = private_member_type(span, class.as_ref(), &m.key, m.is_static);
+                    self.validate_method_decorators(span, &m.function.decorators, m.kind, this, None, value);
+                }
+
+                RClassMember::ClassProp(p) => {
+                    if p.decorators.is_empty() {
+                        continue;
+                    }
+
+                    let key_type = match prop_name_type(&p.key) {
+                        Some(key) => key,
+                        None => {
+                            p.decorators.visit_with(self);
+                            continue;
+                        }
+                    };
+                    let (this_type, this) = if p.is_static {
+                        (&ctor_type, &ctor)
+                    } else {
+                        (&instance_type, &instance)
+                    };
+
+                    if experimental {
+                        let key = self.synthesized_type(span, key_type);
+                        for d in &p.decorators {
+                            self.validate_decorator(d, DecoratorKind::Prop, vec![this.clone(), key.clone()], None);
+                        }
+                    } else {
+                        let value = self.synthesized_type(span, indexed_access(span, this_type.clone(), key_type));
+                        self.validate_field_decorators(span, &p.decorators, this, value);
+                    }
+                }
+
+                RClassMember::PrivateProp(p) => {
+                    if p.decorators.is_empty() {
+                        continue;
+                    }
+
+                    if experimental {
+                        self.report_invalid_decorators(&p.decorators);
+                    } else {
+                        let this = if p.is_static { &ctor } else { &instance };
+                        let value = private_member_type(span, class.as_ref(), &p.key, p.is_static);
+                        self.validate_field_decorators(span, &p.decorators, this, value);
+                    }
+                }
+
+                // `accessor` fields are not supported by the parser yet.
+                RClassMember::TsIndexSignature(..) | RClassMember::StaticBlock(..) | RClassMember::Empty(..) => {}
+            }
+        }
+    }
+
+    /// `key` is [None] for private methods, which can't be decorated with
+    /// `experimentalDecorators`.
+    fn validate_method_decorators(
+        &mut self,
+        span: Span,
+        decorators: &[RDecorator],
+        kind: MethodKind,
+        this: &Type,
+        key: Option<&Type>,
+        value: Type,
+    ) {
+        if !self.rule().experimental_decorators {
+            // `Value` of contexts of accessors is the type of the property.
+            let (target, context) = match kind {
+                MethodKind::Method => (value.clone(), "ClassMethodDecoratorContext"),
+                MethodKind::Getter => (fn_type(span, None, value.clone()), "ClassGetterDecoratorContext"),
+                MethodKind::Setter => (
+                    fn_type(span, Some(value.clone()), Type::void(span, Default::default())),
+                    "ClassSetterDecoratorContext",
+                ),
+            };
+            let context = self.global_type(span, context, vec![this.clone(), value]);
+
+            for d in decorators {
+                let args = vec![target.clone(), context.clone()];
+                self.validate_decorator(d, DecoratorKind::Method, args, Some(or_void(span, target.clone())));
+            }
+            return;
+        }
+
+        let key = match key {
+            Some(key) => key,
+            None => {
+                self.report_invalid_decorators(decorators);
+                return;
+            }
+        };
+
+        let descriptor = self.global_type(span, "TypedPropertyDescriptor", vec![value]);
+        for d in decorators {
+            let args = vec![this.clone(), key.clone(), descriptor.clone()];
+            self.validate_decorator(d, DecoratorKind::Method, args, Some(or_void(span, descriptor.clone())));
+        }
+    }
+
+    /// Validates standard decorators of a field.
+    fn validate_field_decorators(&mut self, span: Span, decorators: &[RDecorator], this: &Type, value: Type) {
+        let context = self.global_type(span, "ClassFieldDecoratorContext", vec![this.clone(), value]);
+
+        for d in decorators {
+            let args = vec![Type::undefined(span, Default::default()), context.clone()];
+            self.validate_decorator(d, DecoratorKind::Prop, args, None);
+        }
+    }
+
+    fn report_invalid_decorators(&mut self, decorators: &[RDecorator]) {
+        for d in decorators {
+            self.storage.report(ErrorKind::DecoratorNotValidHere { span: d.span }.into());
+            d.visit_with(self);
+        }
+    }
+
+    /// Calls `d` with arguments of `arg_types`, and checks if the return type
+    /// is assignable to `ret_type`.
+    fn validate_decorator(&mut self, d: &RDecorator, kind: DecoratorKind, arg_types: Vec<Type>, ret_type: Option<Type>) {
+        let span = d.span;
+
+        let callee = if kind == DecoratorKind::Param {
+            // Errors are reported while validating the parameter.
+            self.with_child(ScopeKind::Flow, Default::default(), |analyzer: &mut Analyzer| {
+                analyzer.ctx.ignore_errors = true;
+                d.expr.validate_with_default(analyzer)
+            })
+        } else {
+            d.expr.validate_with_default(self)
+        };
+        let callee = match callee {
+            Ok(callee) => callee.freezed(),
+            Err(err) => {
+                if kind != DecoratorKind::Param {
+                    self.storage.report(err);
+                }
+                return;
+            }
+        };
+
+        let res = self.with_child(ScopeKind::Call, Default::default(), |analyzer: &mut Analyzer| {
+            let ret = analyzer.call_with_synthesized_args(span, callee, arg_types);
+            let errors: Vec<Error> = analyzer.storage.take_errors().into();
+
+            Ok((ret, errors))
+        });
+        let (ret, mut cause) = match res {
+            Ok(v) => v,
+            Err(err) => {
+                self.storage.report(err);
+                return;
+            }
+        };
+
+        let ret = match ret {
+            Ok(ret) => ret.freezed(),
+            Err(err) => {
+                cause.push(err);
+                Type::any(span, Default::default())
+            }
+        };
+
+        if !cause.is_empty() {
+            self.storage.report(
+                match kind {
+                    DecoratorKind::Class => ErrorKind::InvalidClassDecorator { span, cause },
+                    DecoratorKind::Method => ErrorKind::InvalidMethodDecorator { span, cause },
+                    DecoratorKind::Prop => ErrorKind::InvalidPropDecorator { span, cause },
+                    DecoratorKind::Param => ErrorKind::InvalidParamDecorator { span, cause },
+                }
+                .into(),
+            );
+            return;
+        }
+
+        if let Some(expected) = ret_type {
+            if self.assign(span, &mut Default::default(), &expected, &ret).is_err() {
+                self.storage.report(
+                    ErrorKind::DecoratorReturnTypeNotAssignable {
+                        span,
+                        ret: box ret,
+                        expected: box expected,
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
+    /// Validates a type which refers to the class or its members.
+    fn synthesized_type(&mut self, span: Span, ty: RTsType) -> Type {
+        ty.validate_with(self)
+            .report(&mut self.storage)
+            .unwrap_or_else(|| Type::any(span, Default::default()))
+            .freezed()
+    }
+
+    /// Instantiates the global type `name` with `args`.
+    ///
+    /// Unlike a type reference, this is not affected by local declarations with
+    /// the same name.
+    fn global_type(&mut self, span: Span, name: &str, args: Vec<Type>) -> Type {
+        let res: VResult<Type> = try {
+            let ty = self.env.get_global_type(span, &name.into())?;
+            let type_params = match ty.normalize() {
+                Type::Interface(Interface {
+                    type_params: Some(type_params),
+                    ..
+                }) => type_params.params.clone(),
+                _ => vec![],
+            };
+
+            if type_params.is_empty() {
+                ty
+            } else {
+                let type_args = TypeParamInstantiation {
+                    span,
+                    params: args.freezed(),
+                };
+                let mut inferred = self.infer_arg_types(
+                    span,
+                    Some(&type_args),
+                    &type_params,
+                    &[],
+                    &[],
+                    Some(&Type::TypeLit(TypeLit {
+                        span,
+                        members: vec![],
+                        metadata: Default::default(),
+                    })),
+                    Default::default(),
+                )?;
+                inferred.types.values_mut().for_each(|ty| ty.make_clone_cheap());
+
+                self.expand_type_params(&inferred.types, ty.foldable(), Default::default())?
+            }
+        };
+
+        res.report(&mut self.storage)
+            .unwrap_or_else(|| Type::any(span, Default::default()))
+            .freezed()
+    }
+}
+
+fn has_decorators(c: &RClass) -> bool {
+    !c.decorators.is_empty()
+        || c.body.iter().any(|member| match member {
+            RClassMember::Constructor(constructor) => constructor.params.iter().any(|param| match param {
+                RParamOrTsParamProp::TsParamProp(p) => !p.decorators.is_empty(),
+                RParamOrTsParamProp::Param(p) => !p.decorators.is_empty(),
+            }),
+            RClassMember::Method(m) => {
+                !m.function.decorators.is_empty() || m.function.params.iter().any(|param| !param.decorators.is_empty())
+            }
+            RClassMember::PrivateMethod(m) => !m.function.decorators.is_empty(),
+            RClassMember::ClassProp(p) => !p.decorators.is_empty(),
+            RClassMember::PrivateProp(p) => !p.decorators.is_empty(),
+            RClassMember::TsIndexSignature(..) | RClassMember::StaticBlock(..) | RClassMember::Empty(..) => false,
+        })
+}
+
+/// Returns the type of the value of the private member `key` of `class`.
+///
+/// Setters are not stored with the type of the value, so their type is `any`
+/// unless there's a getter.
+fn private_member_type(span: Span, class: Option<&ClassDef>, key: &RPrivateName, is_static: bool) -> Type {
+    let is_key = |k: &Key| matches!(k, Key::Private(name) if *name.id.sym() == key.id.sym);
+
+    class
+        .into_iter()
+        .flat_map(|class| class.body.iter())
+        .find_map(|member| match member {
+            ClassMember::Method(m) if m.is_static == is_static && is_key(&m.key) => Some(Type::Function(Function {
+                span: m.span,
+                type_params: m.type_params.clone(),
+                params: m.params.clone(),
+                ret_ty: m.ret_ty.clone(),
+                metadata: Default::default(),
+            })),
+            ClassMember::Property(p) if p.is_static == is_static && !p.accessor.setter && is_key(&p.key) => p.value.as_deref().cloned(),
+            _ => None,
+        })
+        .unwrap_or_else(|| Type::any(span, Default::default()))
+        .freezed()
+}
+
+/// Returns the literal type of a non-computed key.
+fn prop_name_type(key: &RPropName) -> Option<RTsType> {
+    let (span, lit) = match key {
+        RPropName::Ident(i) => (
+            i.span,
+            RTsLit::Str(RStr {
+                span: i.span,
+                value: i.sym.clone(),
+                raw: None,
+            }),
+        ),
+        RPropName::Str(s) => (s.span, RTsLit::Str(s.clone())),
+        RPropName::Num(n) => (n.span, RTsLit::Number(n.clone())),
+        _ => return None,
+    };
+
+    Some(RTsType::TsLitType(RTsLitType {
+        node_id: NodeId::invalid(),
+        span,
+        lit,
+    }))
+}
+
+/// `obj[index]`
+fn indexed_access(span: Span, obj: RTsType, index: RTsType) -> RTsType {
+    RTsType::TsIndexedAccessType(RTsIndexedAccessType {
+        node_id: NodeId::invalid(),
+        span,
+        readonly: false,
+        obj_type: box obj,
+        index_type: box index,
+    })
+}
+
+fn num_lit(span: Span, value: usize) -> Type {
+    Type::Lit(LitType {
+        span,
+        lit: RTsLit::Number(RNumber {
+            span,
+            value: value as f64,
+            raw: None,
+        }),
+        metadata: Default::default(),
+    })
+}
+
+/// `() => ret` or `(value: param) => ret`
+fn fn_type(span: Span, param: Option<Type>, ret: Type) -> Type {
+    Type::Function(Function {
+        span,
+        type_params: None,
+        params: param
+            .into_iter()
+            .map(|ty| FnParam {
+                span,
+                required: true,
+                pat: RPat::Ident(RBindingIdent {
+                    node_id: NodeId::invalid(),
+                    id: RIdent::new("value".into(), span),
+                    type_ann: None,
+                }),
+                ty: box ty,
+            })
+            .collect(),
+        ret_ty: box ret,
+        metadata: Default::default(),
+    })
+    .freezed()
+}
+
+fn or_void(span: Span, ty: Type) -> Type {
+    Type::new_union(span, vec![Type::void(span, Default::default()), ty]).freezed()
+}
@@ -4,8 +4,8 @@ use itertools::Itertools;
 use rnode::{FoldWith, IntoRNode, NodeId, NodeIdGenerator, VisitWith};
 use stc_ts_ast_rnode::{
     RAssignPat, RBindingIdent, RClass, RClassDecl, RClassExpr, RClassMember, RClassMethod, RClassProp, RConstructor, RDecl, RExpr,
-    RFunction, RIdent, RMemberExpr, RParam, RParamOrTsParamProp, RPat, RPrivateMethod, RPrivateProp, RPropName, RStmt, RTsEntityName,
-    RTsFnParam, RTsParamProp, RTsParamPropParam, RTsTypeAliasDecl, RTsTypeAnn, RVarDecl, RVarDeclarator,
+    RFunction, RIdent, RMemberExpr, RParam, RParamOrTsParamProp, RPat, RPrivateMethod, RPrivateProp, RPropName, RStaticBlock, RStmt,
+    RTsEntityName, RTsFnParam, RTsParamProp, RTsParamPropParam, RTsTypeAliasDecl, RTsTypeAnn, RVarDecl, RVarDeclarator,
 };
 use stc_ts_env::ModuleConfig;
 use stc_ts_errors::{DebugExt, ErrorKind, Errors};
@@ -37,6 +37,7 @@ use crate::{
     VResult,
 };
 
+mod decorator;
 mod order;
 mod type_param;
 
@@ -67,34 +68,6 @@ impl Analyzer<'_, '_> {
         };
 
         if !self.is_builtin {
-            // Disabled because of false positives when the constructor initializes the
-            // field.
-            #[allow(clippy::overly_complex_bool_expr)]
-            if false && self.rule().strict_null_checks {
-                if value.is_none() {
-                    if let Some(ty) = &ty {
-                        if self
-                            .assign_with_opts(
-                                &mut Default::default(),
-                                ty,
-                                &Type::Keyword(KeywordType {
-                                    span,
-                                    kind: TsKeywordTypeKind::TsUndefinedKeyword,
-                                    metadata: Default::default(),
-                                }),
-                                AssignOpts {
-                                    span,
-                                    ..Default::default()
-                                },
-                            )
-                            .is_err()
-                        {
-                            self.storage.report(ErrorKind::ClassPropNotInitialized { span }.into())
-                        }
-                    }
-                }
-            }
-
             // Report error if type is not found.
             if let Some(ty) = &ty {
                 self.normalize(Some(span), Cow::Borrowed(ty), Default::default())
@@ -745,6 +718,19 @@ impl Analyzer<'_, '_> {
     }
 }
 
+#[validator]
+impl Analyzer<'_, '_> {
+    fn validate(&mut self, b: &RStaticBlock) -> VResult<()> {
+        self.with_child(ScopeKind::Method { is_static: true }, Default::default(), |child: &mut Analyzer| {
+            child.bind_static_this(b.span);
+
+            b.body.visit_with(child);
+
+            Ok(())
+        })
+    }
+}
+
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, m: &RClassMember) -> VResult<Option<ClassMember>> {
@@ -752,7 +738,11 @@ impl Analyzer<'_, '_> {
             RClassMember::PrivateMethod(m) => Some(m.validate_with(self).map(From::from)?),
             RClassMember::PrivateProp(m) => Some(m.validate_with(self).map(From::from)?),
             RClassMember::Empty(..) => None,
-            RClassMember::StaticBlock(..) => todo!("static block"),
+            RClassMember::StaticBlock(v) => {
+                v.validate_with(self)?;
+
+                None
+            }
 
             RClassMember::Constructor(v) => {
                 if self.is_builtin {
@@ -1455,7 +1445,6 @@ impl Analyzer<'_, '_> {
             has_body: !self.ctx.in_declare,
         };
 
-        c.decorators.visit_with(self);
         let name = self.scope.this_class_name.take();
         if let Some(i) = &name {
             match &**i.sym() {
@@ -1711,6 +1700,8 @@ impl Analyzer<'_, '_> {
                     }
                 }
 
+                child.report_static_props_used_before_init(name.as_ref(), &c.body);
+
                 // Handle ts parameter properties
                 for (index, constructor) in c.body.iter().enumerate().filter_map(|(i, member)| match member {
                     RClassMember::Constructor(c) => Some((i, c)),
@@ -1833,6 +1824,8 @@ impl Analyzer<'_, '_> {
                     }
                 }
 
+                child.report_props_not_initialized(&c.body);
+
                 {
                     let mut ambient_cons: Vec<ConstructorSignature> = vec![];
                     let mut cons_with_body = None;
@@ -1970,6 +1963,8 @@ impl Analyzer<'_, '_> {
                     }
                 }
 
+                analyzer.validate_decorators(c.ident.as_ref(), &c.class);
+
                 c.visit_children_with(analyzer);
 
                 Ok(())
@@ -2235,6 +2230,8 @@ impl Analyzer<'_, '_> {
             }
         }
 
+        self.validate_decorators(Some(&c.ident), &c.class);
+
         self.scope.this = old_this;
     }
 }
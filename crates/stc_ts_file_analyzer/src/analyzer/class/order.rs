@@ -1,11 +1,20 @@
+use std::mem;
+
 use fxhash::{FxHashMap, FxHashSet};
 use petgraph::graphmap::DiGraphMap;
 use rnode::{Visit, VisitWith};
-use stc_ts_ast_rnode::{RClassMember, RExpr, RMemberExpr, RMemberProp};
-use stc_ts_types::{rprop_name_to_expr, Id};
+use stc_ts_ast_rnode::{
+    RArrowExpr, RAssignExpr, RBinExpr, RBlockStmt, RBreakStmt, RClass, RClassMember, RClassProp, RCondExpr, RConstructor, RContinueStmt,
+    RDecl, RExpr, RForInStmt, RForOfStmt, RFunction, RMemberExpr, RMemberProp, RPat, RPatOrExpr, RPropName, RStmt,
+};
+use stc_ts_errors::ErrorKind;
+use stc_ts_types::{rprop_name_to_expr, ClassMember, ClassProperty, Id, Type};
+use swc_atoms::JsWord;
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{AssignOp, BinaryOp};
 
 use crate::{
-    analyzer::Analyzer,
+    analyzer::{assign::AssignOpts, Analyzer},
     util::graph::{Inliner, NodeId},
 };
 
@@ -94,8 +103,147 @@ impl Analyzer<'_, '_> {
             }
         }
 
+        // Static blocks can use all static members, so they are evaluated last.
+        remaining_indexes.sort_by_key(|&index| matches!(members[index], RClassMember::StaticBlock(..)));
+
         remaining_indexes
     }
+
+    /// Reports static properties read by static blocks before they are
+    /// declared or assigned by a previous static block.
+    ///
+    /// `class_name` is the name of the class, which can be used instead of
+    /// `this`.
+    pub(super) fn report_static_props_used_before_init(&mut self, class_name: Option<&Id>, members: &[RClassMember]) {
+        let static_props = members
+            .iter()
+            .filter_map(|member| match member {
+                RClassMember::ClassProp(RClassProp {
+                    is_static: true,
+                    is_optional: false,
+                    key,
+                    ..
+                }) => prop_name_sym(key),
+                _ => None,
+            })
+            .collect::<FxHashSet<_>>();
+        if static_props.is_empty() {
+            return;
+        }
+
+        let mut initialized = FxHashSet::default();
+
+        for member in members {
+            match member {
+                RClassMember::ClassProp(RClassProp { is_static: true, key, .. }) => {
+                    if let Some(sym) = prop_name_sym(key) {
+                        initialized.insert(sym);
+                    }
+                }
+
+                RClassMember::StaticBlock(block) => {
+                    let mut visitor = StaticBlockAnalyzer {
+                        class_name,
+                        accesses: Default::default(),
+                    };
+                    block.body.visit_with(&mut visitor);
+
+                    for access in visitor.accesses {
+                        match access {
+                            StaticAccess::Read(span, sym) => {
+                                if static_props.contains(&sym) && !initialized.contains(&sym) {
+                                    self.storage.report(ErrorKind::PropertyUsedBeforeInit { span, name: sym }.into());
+                                }
+                            }
+                            StaticAccess::Write(sym) => {
+                                initialized.insert(sym);
+                            }
+                        }
+                    }
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    /// Reports instance properties which are not initialized and not assigned
+    /// in the constructor, if `strictPropertyInitialization` is enabled.
+    ///
+    /// This should be called after validating instance properties.
+    pub(super) fn report_props_not_initialized(&mut self, members: &[RClassMember]) {
+        if self.is_builtin || self.ctx.in_declare || !self.rule().strict_property_initialization || !self.rule().strict_null_checks {
+            return;
+        }
+
+        let initialized = members
+            .iter()
+            .find_map(|member| match member {
+                RClassMember::Constructor(RConstructor { body: Some(body), .. }) => Some(ConstructorAnalyzer::analyze(body)),
+                _ => None,
+            })
+            .unwrap_or_else(|| Some(Default::default()));
+        // Properties are not observable if the constructor always throws.
+        let initialized = match initialized {
+            Some(initialized) => initialized,
+            None => return,
+        };
+
+        for (index, member) in members.iter().enumerate() {
+            let p = match member {
+                RClassMember::ClassProp(
+                    p @ RClassProp {
+                        is_static: false,
+                        is_optional: false,
+                        is_abstract: false,
+                        declare: false,
+                        definite: false,
+                        value: None,
+                        type_ann: Some(..),
+                        ..
+                    },
+                ) => p,
+                _ => continue,
+            };
+
+            if prop_name_sym(&p.key).map_or(true, |sym| initialized.contains(&sym)) {
+                continue;
+            }
+
+            let ty = self.scope.this_class_members.iter().find_map(|(idx, member)| match member {
+                ClassMember::Property(ClassProperty { value: Some(ty), .. }) if *idx == index => Some(ty.clone()),
+                _ => None,
+            });
+            let ty = match ty {
+                Some(ty) => ty,
+                None => continue,
+            };
+
+            if self
+                .assign_with_opts(
+                    &mut Default::default(),
+                    &ty,
+                    &Type::undefined(p.span, Default::default()),
+                    AssignOpts {
+                        span: p.span,
+                        ..Default::default()
+                    },
+                )
+                .is_err()
+            {
+                self.storage
+                    .report(ErrorKind::ClassPropNotInitialized { span: p.key.span() }.into());
+            }
+        }
+    }
+}
+
+fn prop_name_sym(key: &RPropName) -> Option<JsWord> {
+    match key {
+        RPropName::Ident(i) => Some(i.sym.clone()),
+        RPropName::Str(s) => Some(s.value.clone()),
+        _ => None,
+    }
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -135,3 +283,354 @@ impl Visit<RMemberExpr> for MethodAnalyzer {
         }
     }
 }
+
+enum StaticAccess {
+    Read(Span, JsWord),
+    Write(JsWord),
+}
+
+/// Collects `this.foo` and `Class.foo` in a static block, in the order of
+/// evaluation.
+struct StaticBlockAnalyzer<'a> {
+    class_name: Option<&'a Id>,
+    accesses: Vec<StaticAccess>,
+}
+
+impl StaticBlockAnalyzer<'_> {
+    fn static_prop(&self, e: &RMemberExpr) -> Option<(Span, JsWord)> {
+        let is_class = match &*e.obj {
+            RExpr::This(..) => true,
+            RExpr::Ident(i) => self.class_name.map_or(false, |name| *name == Id::from(i)),
+            _ => false,
+        };
+        if !is_class {
+            return None;
+        }
+
+        match &e.prop {
+            RMemberProp::Ident(i) => Some((i.span, i.sym.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl Visit<RMemberExpr> for StaticBlockAnalyzer<'_> {
+    fn visit(&mut self, e: &RMemberExpr) {
+        e.visit_children_with(self);
+
+        if let Some((span, sym)) = self.static_prop(e) {
+            self.accesses.push(StaticAccess::Read(span, sym));
+        }
+    }
+}
+
+impl Visit<RAssignExpr> for StaticBlockAnalyzer<'_> {
+    fn visit(&mut self, e: &RAssignExpr) {
+        let left = match &e.left {
+            RPatOrExpr::Expr(box RExpr::Member(left)) | RPatOrExpr::Pat(box RPat::Expr(box RExpr::Member(left)))
+                if e.op == AssignOp::Assign =>
+            {
+                left
+            }
+            _ => {
+                e.visit_children_with(self);
+                return;
+            }
+        };
+
+        left.obj.visit_with(self);
+        left.prop.visit_with(self);
+        e.right.visit_with(self);
+
+        if let Some((_, sym)) = self.static_prop(left) {
+            self.accesses.push(StaticAccess::Write(sym));
+        }
+    }
+}
+
+/// Bodies of functions are not evaluated in the static block.
+impl Visit<RFunction> for StaticBlockAnalyzer<'_> {
+    fn visit(&mut self, _: &RFunction) {}
+}
+
+impl Visit<RArrowExpr> for StaticBlockAnalyzer<'_> {
+    fn visit(&mut self, _: &RArrowExpr) {}
+}
+
+impl Visit<RClass> for StaticBlockAnalyzer<'_> {
+    fn visit(&mut self, _: &RClass) {}
+}
+
+/// Collects names of properties definitely assigned via `this.foo = ...` in a
+/// constructor, like the control flow analysis of `tsc`.
+///
+/// Assignments in branches are counted only if all branches assign, and ones
+/// in loops other than `do-while` are not counted.
+struct ConstructorAnalyzer {
+    /// Properties assigned at the current point. [None] if it's unreachable.
+    assigned: Option<FxHashSet<JsWord>>,
+    /// Properties assigned at all `return` statements so far.
+    returned: Option<FxHashSet<JsWord>>,
+}
+
+impl ConstructorAnalyzer {
+    /// Returns [None] if the constructor never completes.
+    fn analyze(body: &RBlockStmt) -> Option<FxHashSet<JsWord>> {
+        let mut v = ConstructorAnalyzer {
+            assigned: Some(Default::default()),
+            returned: None,
+        };
+        v.block(body);
+
+        merge(v.assigned, v.returned)
+    }
+
+    fn block(&mut self, b: &RBlockStmt) {
+        for s in &b.stmts {
+            self.stmt(s);
+        }
+    }
+
+    /// Analyzes `op`, but drops assignments because it may not be executed.
+    fn maybe(&mut self, op: impl FnOnce(&mut Self)) {
+        let assigned = self.assigned.clone();
+        op(self);
+        self.assigned = assigned;
+    }
+
+    fn stmt(&mut self, s: &RStmt) {
+        match s {
+            RStmt::Block(b) => self.block(b),
+
+            RStmt::Expr(s) => s.expr.visit_with(self),
+
+            RStmt::Decl(RDecl::Var(v)) => v.visit_with(self),
+
+            RStmt::Return(s) => {
+                s.arg.visit_with(self);
+                self.returned = merge(self.returned.take(), self.assigned.take());
+            }
+
+            RStmt::Throw(s) => {
+                s.arg.visit_with(self);
+                self.assigned = None;
+            }
+
+            RStmt::Break(..) | RStmt::Continue(..) => {
+                self.assigned = None;
+            }
+
+            RStmt::If(s) => {
+                s.test.visit_with(self);
+
+                let before = self.assigned.clone();
+                self.stmt(&s.cons);
+                let cons = mem::replace(&mut self.assigned, before);
+                if let Some(alt) = &s.alt {
+                    self.stmt(alt);
+                }
+                self.assigned = merge(cons, self.assigned.take());
+            }
+
+            RStmt::Switch(s) => {
+                s.discriminant.visit_with(self);
+                self.maybe(|v| {
+                    for case in &s.cases {
+                        case.test.visit_with(v);
+                        for s in &case.cons {
+                            v.stmt(s);
+                        }
+                    }
+                });
+            }
+
+            RStmt::Try(s) => {
+                let before = self.assigned.clone();
+                self.block(&s.block);
+                if let Some(handler) = &s.handler {
+                    // Any statement in the `try` block may throw.
+                    let block = mem::replace(&mut self.assigned, before);
+                    self.block(&handler.body);
+                    self.assigned = merge(block, self.assigned.take());
+                }
+                if let Some(finalizer) = &s.finalizer {
+                    self.block(finalizer);
+                }
+            }
+
+            RStmt::While(s) => {
+                s.test.visit_with(self);
+                self.maybe(|v| v.stmt(&s.body));
+            }
+
+            RStmt::DoWhile(s) => {
+                let mut finder = LoopJumpFinder { found: false };
+                s.body.visit_with(&mut finder);
+
+                if finder.found {
+                    self.maybe(|v| v.stmt(&s.body));
+                } else {
+                    self.stmt(&s.body);
+                }
+                s.test.visit_with(self);
+            }
+
+            RStmt::For(s) => {
+                s.init.visit_with(self);
+                s.test.visit_with(self);
+                self.maybe(|v| {
+                    v.stmt(&s.body);
+                    s.update.visit_with(v);
+                });
+            }
+
+            RStmt::ForIn(RForInStmt { right, body, .. }) | RStmt::ForOf(RForOfStmt { right, body, .. }) => {
+                right.visit_with(self);
+                self.maybe(|v| v.stmt(body));
+            }
+
+            // `break` with the label may skip assignments.
+            RStmt::Labeled(s) => self.maybe(|v| v.stmt(&s.body)),
+
+            RStmt::With(s) => {
+                s.obj.visit_with(self);
+                self.maybe(|v| v.stmt(&s.body));
+            }
+
+            RStmt::Decl(..) | RStmt::Empty(..) | RStmt::Debugger(..) => {}
+        }
+    }
+}
+
+/// Returns properties assigned in both of `a` and `b`. [None] means
+/// unreachable.
+fn merge(a: Option<FxHashSet<JsWord>>, b: Option<FxHashSet<JsWord>>) -> Option<FxHashSet<JsWord>> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.intersection(&b).cloned().collect()),
+        (Some(v), None) | (None, Some(v)) => Some(v),
+        (None, None) => None,
+    }
+}
+
+impl Visit<RAssignExpr> for ConstructorAnalyzer {
+    fn visit(&mut self, e: &RAssignExpr) {
+        e.visit_children_with(self);
+
+        if e.op != AssignOp::Assign {
+            return;
+        }
+
+        if let RPatOrExpr::Expr(box RExpr::Member(left)) | RPatOrExpr::Pat(box RPat::Expr(box RExpr::Member(left))) = &e.left {
+            if let (RExpr::This(..), RMemberProp::Ident(i)) = (&*left.obj, &left.prop) {
+                let assigned = match &mut self.assigned {
+                    Some(assigned) => assigned,
+                    None => return,
+                };
+                if assigned.contains(&i.sym) {
+                    return;
+                }
+
+                // `this.foo = this.foo` reads the property before it's assigned.
+                let mut reader = ThisPropReader { sym: &i.sym, found: false };
+                e.right.visit_with(&mut reader);
+                if reader.found {
+                    return;
+                }
+
+                assigned.insert(i.sym.clone());
+            }
+        }
+    }
+}
+
+impl Visit<RBinExpr> for ConstructorAnalyzer {
+    fn visit(&mut self, e: &RBinExpr) {
+        e.left.visit_with(self);
+
+        if matches!(e.op, BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing) {
+            self.maybe(|v| e.right.visit_with(v));
+        } else {
+            e.right.visit_with(self);
+        }
+    }
+}
+
+impl Visit<RCondExpr> for ConstructorAnalyzer {
+    fn visit(&mut self, e: &RCondExpr) {
+        e.test.visit_with(self);
+
+        let before = self.assigned.clone();
+        e.cons.visit_with(self);
+        let cons = mem::replace(&mut self.assigned, before);
+        e.alt.visit_with(self);
+        self.assigned = merge(cons, self.assigned.take());
+    }
+}
+
+/// Assignments in nested functions are not considered as initialization.
+impl Visit<RFunction> for ConstructorAnalyzer {
+    fn visit(&mut self, _: &RFunction) {}
+}
+
+impl Visit<RArrowExpr> for ConstructorAnalyzer {
+    fn visit(&mut self, _: &RArrowExpr) {}
+}
+
+impl Visit<RClass> for ConstructorAnalyzer {
+    fn visit(&mut self, _: &RClass) {}
+}
+
+/// Finds `this.foo` evaluated immediately.
+struct ThisPropReader<'a> {
+    sym: &'a JsWord,
+    found: bool,
+}
+
+impl Visit<RMemberExpr> for ThisPropReader<'_> {
+    fn visit(&mut self, e: &RMemberExpr) {
+        e.visit_children_with(self);
+
+        if let (RExpr::This(..), RMemberProp::Ident(i)) = (&*e.obj, &e.prop) {
+            if i.sym == *self.sym {
+                self.found = true;
+            }
+        }
+    }
+}
+
+impl Visit<RFunction> for ThisPropReader<'_> {
+    fn visit(&mut self, _: &RFunction) {}
+}
+
+impl Visit<RArrowExpr> for ThisPropReader<'_> {
+    fn visit(&mut self, _: &RArrowExpr) {}
+}
+
+impl Visit<RClass> for ThisPropReader<'_> {
+    fn visit(&mut self, _: &RClass) {}
+}
+
+/// Finds `break` and `continue` in the body of a loop.
+struct LoopJumpFinder {
+    found: bool,
+}
+
+impl Visit<RBreakStmt> for LoopJumpFinder {
+    fn visit(&mut self, _: &RBreakStmt) {
+        self.found = true;
+    }
+}
+
+impl Visit<RContinueStmt> for LoopJumpFinder {
+    fn visit(&mut self, _: &RContinueStmt) {
+        self.found = true;
+    }
+}
+
+impl Visit<RFunction> for LoopJumpFinder {
+    fn visit(&mut self, _: &RFunction) {}
+}
+
+impl Visit<RArrowExpr> for LoopJumpFinder {
+    fn visit(&mut self, _: &RArrowExpr) {}
+}
@@ -0,0 +1,168 @@
+use std::borrow::Cow;
+
+use fxhash::FxHashSet;
+use stc_ts_types::{Key, TypeElement};
+use stc_utils::cache::Freeze;
+use swc_common::{Span, SyntaxContext};
+
+use crate::{
+    analyzer::{scope::VarKind, Analyzer},
+    completion::{Completion, CompletionKind, Completions},
+    ty::Type,
+    VResult,
+};
+
+impl Analyzer<'_, '_> {
+    /// Returns the completion request if it's for the identifier at `span`.
+    fn completions_at(&self, span: Span) -> Option<Completions> {
+        if self.is_builtin || span.is_dummy() {
+            return None;
+        }
+        let completions = self.completions.as_ref()?;
+
+        let file = self.cm.lookup_source_file(span.lo);
+        if file.name != *completions.file() || (span.lo - file.start_pos).0 as usize != completions.offset() {
+            return None;
+        }
+
+        Some(completions.clone())
+    }
+
+    /// Collects members of `obj` if completions are requested for the property
+    /// at `span`.
+    pub(crate) fn complete_members(&mut self, span: Span, obj: &Type) {
+        let completions = match self.completions_at(span) {
+            Some(v) => v,
+            None => return,
+        };
+
+        // Errors are ignored, as the property is being written.
+        let items = self
+            .members_for_completion(span.with_ctxt(SyntaxContext::empty()), obj)
+            .unwrap_or_default();
+        completions.set(items);
+    }
+
+    fn members_for_completion(&mut self, span: Span, obj: &Type) -> VResult<Vec<Completion>> {
+        let obj = self.normalize(Some(span), Cow::Borrowed(obj), Default::default())?;
+
+        if let Type::Union(u) = obj.normalize() {
+            // Only common members can be accessed.
+            let mut common: Option<Vec<Completion>> = None;
+            for ty in &u.types {
+                let items = self.members_for_completion(span, ty)?;
+
+                common = Some(match common {
+                    Some(prev) => prev
+                        .into_iter()
+                        .filter(|prev| items.iter().any(|item| item.name == prev.name))
+                        .collect(),
+                    None => items,
+                });
+            }
+
+            return Ok(common.unwrap_or_default());
+        }
+
+        let lit = match self.convert_type_to_type_lit(span, obj)? {
+            Some(lit) => lit,
+            None => return Ok(vec![]),
+        };
+
+        let mut done = FxHashSet::default();
+        let mut items = vec![];
+        for member in &lit.members {
+            let (key, kind, ty) = match member {
+                TypeElement::Property(p) => (&p.key, CompletionKind::Property, p.type_ann.as_deref()),
+                TypeElement::Method(m) => (&m.key, CompletionKind::Method, None),
+                _ => continue,
+            };
+
+            let name = match key {
+                Key::Normal { sym, .. } => sym.clone(),
+                _ => continue,
+            };
+
+            // Overloads and merged declarations.
+            if !done.insert(name.clone()) {
+                continue;
+            }
+
+            items.push(Completion {
+                name,
+                kind,
+                ty: ty.cloned().map(Freeze::freezed),
+            });
+        }
+
+        Ok(items)
+    }
+
+    /// Collects variables and types in scope if completions are requested for
+    /// the identifier at `span`.
+    pub(crate) fn complete_scope(&mut self, span: Span) {
+        let completions = match self.completions_at(span) {
+            Some(v) => v,
+            None => return,
+        };
+
+        let mut done = FxHashSet::default();
+        let mut items = vec![];
+
+        let mut scope = Some(&self.scope);
+        while let Some(s) = scope {
+            for (id, var) in &s.vars {
+                if var.copied || !done.insert(id.sym().clone()) {
+                    continue;
+                }
+
+                let kind = match var.kind {
+                    VarKind::Fn => CompletionKind::Function,
+                    VarKind::Class => CompletionKind::Class,
+                    VarKind::Enum => CompletionKind::Enum,
+                    _ => CompletionKind::Var,
+                };
+
+                items.push(Completion {
+                    name: id.sym().clone(),
+                    kind,
+                    ty: var.ty.clone().map(Freeze::freezed),
+                });
+            }
+
+            for id in s.types.keys().chain(s.type_params.keys()) {
+                if done.insert(id.sym().clone()) {
+                    items.push(Completion {
+                        name: id.sym().clone(),
+                        kind: CompletionKind::Type,
+                        ty: None,
+                    });
+                }
+            }
+
+            scope = s.parent();
+        }
+
+        for name in self.env.global_var_names() {
+            if done.insert(name.clone()) {
+                items.push(Completion {
+                    name,
+                    kind: CompletionKind::Var,
+                    ty: None,
+                });
+            }
+        }
+
+        for name in self.env.global_type_names() {
+            if done.insert(name.clone()) {
+                items.push(Completion {
+                    name,
+                    kind: CompletionKind::Type,
+                    ty: None,
+                });
+            }
+        }
+
+        completions.set(items);
+    }
+}
@@ -374,6 +374,7 @@ impl Analyzer<'_, '_> {
         let false_facts = facts_from_test.false_facts;
 
         let mut cons_ends_with_unreachable = false;
+        let mut cons_ends_with_jump = false;
 
         let ends_with_ret = stmt.cons.ends_with_ret();
 
@@ -382,12 +383,14 @@ impl Analyzer<'_, '_> {
             stmt.cons.visit_with(child);
 
             cons_ends_with_unreachable = child.ctx.in_unreachable;
+            cons_ends_with_jump = child.ctx.after_jump;
 
             Ok(())
         })
         .report(&mut self.storage);
 
         let mut alt_ends_with_unreachable = None;
+        let mut alt_ends_with_jump = false;
 
         if let Some(alt) = &stmt.alt {
             self.cur_facts = prev_facts.clone();
@@ -395,6 +398,7 @@ impl Analyzer<'_, '_> {
                 alt.visit_with(child);
 
                 alt_ends_with_unreachable = Some(child.ctx.in_unreachable);
+                alt_ends_with_jump = child.ctx.after_jump;
 
                 Ok(())
             })
@@ -403,6 +407,10 @@ impl Analyzer<'_, '_> {
 
         self.cur_facts = prev_facts;
 
+        if (cons_ends_with_unreachable || cons_ends_with_jump) && (alt_ends_with_unreachable == Some(true) || alt_ends_with_jump) {
+            self.ctx.after_jump = true;
+        }
+
         if ends_with_ret {
             self.cur_facts.true_facts += false_facts;
             return Ok(());
@@ -614,11 +622,15 @@ impl Analyzer<'_, '_> {
             let mut facts_for_body = base_true_facts.clone();
             facts_for_body += true_facts_created_by_case;
 
-            self.with_child(ScopeKind::Flow, facts_for_body, |child| {
+            let ends_with_jump = self.with_child(ScopeKind::Flow, facts_for_body, |child| {
                 cons.visit_with(child);
-                Ok(())
+                Ok(child.ctx.after_jump || child.ctx.in_unreachable)
             })?;
 
+            if !last && !cons.is_empty() && !ends_with_jump && self.rule().no_fallthrough_cases_in_switch {
+                self.storage.report(ErrorKind::FallthroughCaseInSwitch { span: case.span }.into());
+            }
+
             if ends_with_ret || last {
                 false_facts += false_facts_created_by_case.clone();
                 base_true_facts += false_facts_created_by_case;
@@ -494,6 +494,14 @@ impl Analyzer<'_, '_> {
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, e: &RTsExprWithTypeArgs) -> VResult<TsExpr> {
+        let mut obj = &*e.expr;
+        while let RExpr::Member(m) = obj {
+            obj = &*m.obj;
+        }
+        if let RExpr::Ident(i) = obj {
+            self.mark_var_as_used(&i.into(), i.span);
+        }
+
         Ok(TsExpr {
             span: e.span,
             expr: e.expr.clone(),
@@ -700,6 +708,8 @@ impl Analyzer<'_, '_> {
         self.record(t);
 
         let span = t.span;
+        self.mark_entity_name_as_used(&t.type_name);
+
         let type_args = try_opt!(t.type_params.validate_with(self)).map(Box::new).freezed();
         let mut contains_infer = false;
 
@@ -822,7 +832,10 @@ impl Analyzer<'_, '_> {
         let span = t.span();
 
         Ok(match t {
-            RTsTypeQueryExpr::TsEntityName(t) => t.clone().into(),
+            RTsTypeQueryExpr::TsEntityName(t) => {
+                self.mark_entity_name_as_used(t);
+                t.clone().into()
+            }
             RTsTypeQueryExpr::Import(i) => i.validate_with(self)?.into(),
         })
     }
@@ -68,12 +68,20 @@ impl Analyzer<'_, '_> {
                     a.storage.export_type(span, a.ctx.module_id, e.id.clone().into());
                     a.storage
                         .export_var(span, a.ctx.module_id, e.id.clone().into(), e.id.clone().into());
+
+                    if let Some(symbol_table) = a.symbol_table() {
+                        symbol_table.add_export(a.ctx.module_id, e.id.sym.clone(), e.id.clone().into());
+                    }
                 }
                 RDecl::TsModule(module) => match &module.id {
                     RTsModuleName::Ident(id) => {
                         module.visit_with(a);
 
                         a.storage.export_type(span, a.ctx.module_id, id.clone().into());
+
+                        if let Some(symbol_table) = a.symbol_table() {
+                            symbol_table.add_export(a.ctx.module_id, id.sym.clone(), id.clone().into());
+                        }
                     }
                     RTsModuleName::Str(..) => {
                         let module: Option<Type> = module.validate_with(a)?;
@@ -195,6 +203,11 @@ impl Analyzer<'_, '_> {
             self.report_errors_for_duplicated_exports_of_var(span, name.sym().clone());
         }
 
+        if let Some(symbol_table) = self.symbol_table() {
+            let orig_name = orig_name.clone().unwrap_or_else(|| name.clone());
+            symbol_table.add_export(self.ctx.module_id, name.sym().clone(), orig_name);
+        }
+
         self.storage
             .export_var(span, self.ctx.module_id, name.clone(), orig_name.unwrap_or(name));
     }
@@ -211,6 +224,10 @@ impl Analyzer<'_, '_> {
     fn export_type(&mut self, span: Span, name: Id, orig_name: Option<Id>) {
         let orig_name = orig_name.unwrap_or_else(|| name.clone());
 
+        if let Some(symbol_table) = self.symbol_table() {
+            symbol_table.add_export(self.ctx.module_id, name.sym().clone(), orig_name.clone());
+        }
+
         let types = match self.find_type(&orig_name) {
             Ok(v) => v,
             Err(err) => {
@@ -346,6 +363,10 @@ impl Analyzer<'_, '_> {
         let (dep, data) = self.get_imported_items(span, &node.src.value);
 
         if ctxt != dep {
+            if let Some(symbol_table) = self.symbol_table() {
+                symbol_table.add_star_export(ctxt, dep);
+            }
+
             match data.normalize() {
                 Type::Module(data) => {
                     for (id, ty) in data.exports.vars.iter() {
@@ -396,6 +417,13 @@ impl Analyzer<'_, '_> {
                         Some(src) => {
                             let (dep, data) = self.get_imported_items(node.span, &src.value);
 
+                            if let (Some(symbol_table), RModuleExportName::Ident(orig)) = (self.symbol_table(), &named.orig) {
+                                if base != dep {
+                                    let exported = named.exported.as_ref().map(Id::from).unwrap_or_else(|| Id::from(orig));
+                                    symbol_table.add_reexport(base, exported.sym().clone(), dep, orig.sym.clone());
+                                }
+                            }
+
                             self.reexport(
                                 span,
                                 base,
@@ -423,6 +451,10 @@ impl Analyzer<'_, '_> {
 
 impl Analyzer<'_, '_> {
     fn export_named(&mut self, span: Span, ctxt: ModuleId, orig: Id, id: Id) {
+        if let Some(symbol_table) = self.symbol_table() {
+            symbol_table.add_export(ctxt, id.sym().clone(), orig.clone());
+        }
+
         if self.storage.get_local_var(ctxt, orig.clone()).is_some() {
             self.report_errors_for_duplicated_exports_of_var(span, id.sym().clone());
 
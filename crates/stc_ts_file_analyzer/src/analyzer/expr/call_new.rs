@@ -205,6 +205,44 @@ impl Analyzer<'_, '_> {
     }
 }
 
+impl Analyzer<'_, '_> {
+    /// Calls `callee` with arguments of `arg_types`, which are created by the
+    /// analyzer. This is used for calls which are not written by the user,
+    /// like decorators.
+    pub(crate) fn call_with_synthesized_args(&mut self, span: Span, callee: Type, arg_types: Vec<Type>) -> VResult<Type> {
+        let args = arg_types
+            .iter()
+            .map(|_| RExprOrSpread {
+                spread: None,
+                expr: box RExpr::Invalid(RInvalid { span }),
+            })
+            .collect::<Vec<_>>();
+        let mut arg_types = arg_types
+            .into_iter()
+            .map(|ty| TypeOrSpread {
+                span,
+                spread: None,
+                ty: box ty,
+            })
+            .collect::<Vec<_>>();
+        arg_types.make_clone_cheap();
+
+        let spread_arg_types = self.spread_args(&arg_types).context("tried to handle spreads in arguments")?;
+
+        self.get_best_return_type(
+            span,
+            ReevalMode::NoReeval,
+            callee,
+            ExtractKind::Call,
+            None,
+            &args,
+            &arg_types,
+            &spread_arg_types,
+            None,
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum ExtractKind {
     New,
@@ -216,7 +254,7 @@ impl Analyzer<'_, '_> {
     ///
     /// This method check arguments
     #[cfg_attr(debug_assertions, tracing::instrument(skip_all))]
-    fn extract_call_new_expr_member(
+    pub(super) fn extract_call_new_expr_member(
         &mut self,
         span: Span,
         expr: ReevalMode,
@@ -1783,7 +1821,7 @@ impl Analyzer<'_, '_> {
         )
     }
 
-    fn extract_callee_candidates(&mut self, span: Span, kind: ExtractKind, callee: &Type) -> VResult<Vec<CallCandidate>> {
+    pub(super) fn extract_callee_candidates(&mut self, span: Span, kind: ExtractKind, callee: &Type) -> VResult<Vec<CallCandidate>> {
         let span = span.with_ctxt(SyntaxContext::empty());
 
         let callee = self
@@ -2168,6 +2206,7 @@ impl Analyzer<'_, '_> {
                 span,
                 min: min_param,
                 max: max_param,
+                actual: args.len(),
             }
             .into())
         }
@@ -3504,7 +3543,7 @@ fn test_arg_check_result_order() {
 }
 
 /// TODO(kdy1): Use cow
-struct CallCandidate {
+pub(super) struct CallCandidate {
     pub type_params: Option<Vec<TypeParam>>,
     pub params: Vec<FnParam>,
     pub ret_ty: Type,
@@ -1 +1,692 @@
+//! JSX elements and fragments.
+//!
+//! Like `tsc`, attributes and children of an element are converted to an
+//! object literal, which is then checked against the props type of the
+//! element. For value-based elements, the props type comes from the call or
+//! construct signatures of the tag.
 
+use std::borrow::Cow;
+
+use fxhash::FxHashMap;
+use rnode::NodeId;
+use stc_ts_ast_rnode::{
+    RArrayLit, RBool, RExpr, RExprOrSpread, RIdent, RJSXAttrName, RJSXAttrOrSpread, RJSXAttrValue, RJSXElement, RJSXElementChild,
+    RJSXElementName, RJSXExpr, RJSXFragment, RJSXObject, RKeyValueProp, RLit, RMemberExpr, RMemberProp, RObjectLit, RProp, RPropName,
+    RPropOrSpread, RStr, RThisExpr, RTsLit,
+};
+use stc_ts_errors::ErrorKind;
+use stc_ts_file_analyzer_macros::validator;
+use stc_ts_types::{Id, IdCtx, Interface, Key, KeywordType, LitType, Module, Namespace, Type, TypeElement, TypeLit};
+use stc_utils::cache::Freeze;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::TsKeywordTypeKind;
+
+use super::call_new::{CallCandidate, ExtractKind, ReevalMode};
+use crate::{
+    analyzer::{expr::TypeOfMode, util::ResultExt, Analyzer, ScopeKind},
+    validator::ValidateWith,
+    VResult,
+};
+
+#[validator]
+impl Analyzer<'_, '_> {
+    fn validate(&mut self, e: &RJSXElement) -> VResult<Type> {
+        let span = e.span;
+        let name_span = e.opening.name.span();
+        let ns = self.find_jsx_namespace(span);
+
+        if is_intrinsic_tag(&e.opening.name) {
+            self.check_jsx_factory(name_span, false);
+
+            let name = tag_name(&e.opening.name);
+            self.validate_intrinsic_jsx_element(e, ns.as_ref(), name);
+
+            return Ok(self.jsx_element_type(span, ns.as_ref()));
+        }
+
+        self.check_jsx_factory(name_span, false);
+
+        let callee = tag_to_expr(&e.opening.name);
+        let tag_ty = callee.validate_with_default(self)?;
+
+        if let Type::Lit(LitType { lit: RTsLit::Str(s), .. }) = tag_ty.normalize() {
+            // `const Tag = 'div'; <Tag />`
+            let name = s.value.clone();
+            self.validate_intrinsic_jsx_element(e, ns.as_ref(), name);
+
+            return Ok(self.jsx_element_type(span, ns.as_ref()));
+        }
+
+        if tag_ty.is_any() || tag_ty.is_unknown() {
+            self.validate_jsx_attrs_without_props(e);
+
+            return Ok(self.jsx_element_type(span, ns.as_ref()));
+        }
+
+        // Construct signatures take precedence, like `tsc`.
+        let (kind, candidates) = {
+            let candidates = self.extract_callee_candidates(name_span, ExtractKind::New, &tag_ty)?;
+            if candidates.is_empty() {
+                (
+                    ExtractKind::Call,
+                    self.extract_callee_candidates(name_span, ExtractKind::Call, &tag_ty)?,
+                )
+            } else {
+                (ExtractKind::New, candidates)
+            }
+        };
+
+        if candidates.is_empty() {
+            self.storage.report(
+                ErrorKind::JsxElementTypeHasNoSignatures {
+                    span: name_span,
+                    name: tag_name(&e.opening.name),
+                }
+                .into(),
+            );
+            self.validate_jsx_attrs_without_props(e);
+
+            return Ok(self.jsx_element_type(span, ns.as_ref()));
+        }
+
+        let ret_ty = if candidates.len() == 1 && candidates[0].type_params.is_none() && e.opening.type_args.is_none() {
+            let candidate = &candidates[0];
+            let props = self.jsx_props_of_candidate(name_span, ns.as_ref(), kind, &tag_ty, candidate)?;
+
+            let children_name = self.jsx_children_attr_name(name_span, ns.as_ref())?;
+            let (attrs, extra) = jsx_attrs_to_object(e, &children_name, &[]);
+            self.validate_extra_jsx_attrs(&extra);
+
+            let attrs_ty = attrs.validate_with_args(self, Some(&props))?;
+            self.assign(name_span, &mut Default::default(), &props, &attrs_ty)
+                .report(&mut self.storage);
+
+            candidate.ret_ty.clone()
+        } else {
+            // Generic components and overloads are resolved like a call with the
+            // attributes as the only argument.
+            let children_name = self.jsx_children_attr_name(name_span, ns.as_ref())?;
+            let reserved = self.jsx_reserved_attr_names(name_span, ns.as_ref(), kind)?;
+            let (attrs, extra) = jsx_attrs_to_object(e, &children_name, &reserved);
+            self.validate_extra_jsx_attrs(&extra);
+
+            let args = [RExprOrSpread {
+                spread: None,
+                expr: box RExpr::Object(attrs),
+            }];
+
+            self.with_child(ScopeKind::Call, Default::default(), |analyzer: &mut Analyzer| {
+                analyzer.extract_call_new_expr_member(
+                    span,
+                    ReevalMode::NoReeval,
+                    &callee,
+                    kind,
+                    &args,
+                    e.opening.type_args.as_deref(),
+                    Default::default(),
+                )
+            })?
+        };
+
+        if let Some(ns) = &ns {
+            self.check_jsx_component(name_span, tag_name(&e.opening.name), ns, kind, &tag_ty, &ret_ty);
+        }
+
+        Ok(self.jsx_element_type(span, ns.as_ref()))
+    }
+}
+
+#[validator]
+impl Analyzer<'_, '_> {
+    fn validate(&mut self, e: &RJSXFragment) -> VResult<Type> {
+        let span = e.span;
+        let ns = self.find_jsx_namespace(span);
+
+        self.check_jsx_factory(e.opening.span, true);
+
+        for child in &e.children {
+            if let Some(child) = child_to_expr(child) {
+                child.expr.validate_with_default(self).report(&mut self.storage);
+            }
+        }
+
+        Ok(self.jsx_element_type(span, ns.as_ref()))
+    }
+}
+
+impl Analyzer<'_, '_> {
+    /// Finds the `JSX` namespace.
+    ///
+    /// The namespace is searched from
+    ///
+    ///  - the runtime module, like `react/jsx-runtime`, if the automatic
+    ///    runtime is used.
+    ///  - the root of the factory, like `React.JSX`.
+    ///  - the global scope.
+    fn find_jsx_namespace(&mut self, span: Span) -> Option<Type> {
+        if self.is_builtin {
+            return None;
+        }
+
+        if let Some(src) = self.env.jsx().runtime_module() {
+            if let Some(ns) = self.find_jsx_namespace_in_runtime(span, &src) {
+                return Some(ns);
+            }
+        }
+
+        let ctxt = self.loader.top_level_ctxt(self.ctx.module_id);
+
+        let factory = Id::new(self.env.jsx().factory_root(false), ctxt);
+        if let Some(ty) = self.find_var_type(&factory, TypeOfMode::RValue).map(Cow::into_owned) {
+            if let Some(ns) = namespace_member(span, &ty, "JSX") {
+                return Some(ns);
+            }
+        }
+
+        let types = self
+            .find_type(&Id::new("JSX".into(), ctxt))
+            .ok()
+            .flatten()?
+            .map(Cow::into_owned)
+            .collect::<Vec<_>>();
+        if types.is_empty() {
+            return None;
+        }
+
+        Some(Type::new_intersection(span, types).freezed())
+    }
+
+    fn find_jsx_namespace_in_runtime(&mut self, span: Span, src: &JsWord) -> Option<Type> {
+        let ctxt = self.ctx.module_id;
+        let base = self.storage.path(ctxt);
+        let dep = self.loader.module_id(&base, src)?;
+
+        if self.loader.is_in_same_circular_group(ctxt, dep) {
+            return None;
+        }
+
+        let module = match self.imports.get(&(ctxt, dep)) {
+            Some(module) => module.clone(),
+            None => {
+                let module = self.loader.load_non_circular_dep(ctxt, dep).report(&mut self.storage)?;
+                self.imports.insert((ctxt, dep), module.clone());
+                module
+            }
+        };
+
+        namespace_member(span, &module, "JSX")
+    }
+
+    /// Reports an error if the factory is required but not in scope.
+    fn check_jsx_factory(&mut self, span: Span, fragment: bool) {
+        if self.is_builtin || !self.env.jsx().requires_factory() {
+            return;
+        }
+
+        let ctxt = self.loader.top_level_ctxt(self.ctx.module_id);
+        let id = Id::new(self.env.jsx().factory_root(fragment), ctxt);
+        self.mark_var_as_used(&id, span);
+
+        if self.find_var_type(&id, TypeOfMode::RValue).is_some() || self.env.get_global_var(span, id.sym()).is_ok() {
+            return;
+        }
+
+        self.storage.report(ErrorKind::NoSuchVar { span, name: id }.into());
+    }
+
+    /// Type of a JSX expression, which is `JSX.Element`.
+    fn jsx_element_type(&mut self, span: Span, ns: Option<&Type>) -> Type {
+        match ns.and_then(|ns| namespace_member(span, ns, "Element")) {
+            Some(mut ty) => {
+                ty.reposition(span);
+                ty.freezed()
+            }
+            None => Type::any(span, Default::default()),
+        }
+    }
+
+    fn validate_intrinsic_jsx_element(&mut self, e: &RJSXElement, ns: Option<&Type>, name: JsWord) {
+        let name_span = e.opening.name.span();
+
+        let props = match ns.and_then(|ns| namespace_member(name_span, ns, "IntrinsicElements")) {
+            Some(elements) => self
+                .access_property(
+                    name_span,
+                    &elements,
+                    &Key::Normal {
+                        span: name_span,
+                        sym: name,
+                    },
+                    TypeOfMode::RValue,
+                    IdCtx::Var,
+                    Default::default(),
+                )
+                .report(&mut self.storage),
+            None => {
+                if self.rule().no_implicit_any {
+                    self.storage.report(ErrorKind::NoJsxIntrinsicElements { span: name_span }.into());
+                }
+                None
+            }
+        };
+
+        let props = match props {
+            Some(props) => props.freezed(),
+            None => {
+                self.validate_jsx_attrs_without_props(e);
+                return;
+            }
+        };
+
+        let (attrs, extra) = jsx_attrs_to_object(e, &"children".into(), &[]);
+        self.validate_extra_jsx_attrs(&extra);
+
+        if let Some(attrs_ty) = attrs.validate_with_args(self, Some(&props)).report(&mut self.storage) {
+            self.assign(name_span, &mut Default::default(), &props, &attrs_ty)
+                .report(&mut self.storage);
+        }
+    }
+
+    /// Validates attributes and children of an element with an unknown props
+    /// type.
+    fn validate_jsx_attrs_without_props(&mut self, e: &RJSXElement) {
+        let (attrs, extra) = jsx_attrs_to_object(e, &"children".into(), &[]);
+        self.validate_extra_jsx_attrs(&extra);
+
+        attrs.validate_with_args(self, None).report(&mut self.storage);
+    }
+
+    /// Validates attributes which are not a part of props.
+    fn validate_extra_jsx_attrs(&mut self, extra: &[RExpr]) {
+        for e in extra {
+            e.validate_with_default(self).report(&mut self.storage);
+        }
+    }
+
+    /// Calculates the props type of a component with a single non-generic
+    /// signature.
+    fn jsx_props_of_candidate(
+        &mut self,
+        span: Span,
+        ns: Option<&Type>,
+        kind: ExtractKind,
+        tag_ty: &Type,
+        candidate: &CallCandidate,
+    ) -> VResult<Type> {
+        let mut props = None;
+
+        if kind == ExtractKind::New {
+            // `JSX.ElementAttributesProperty` specifies the property of the instance type
+            // used as props, and the instance type itself is used if it's empty.
+            if let Some(attrs_prop) = ns.and_then(|ns| namespace_member(span, ns, "ElementAttributesProperty")) {
+                props = Some(match self.jsx_property_names(span, &attrs_prop)?.into_iter().next() {
+                    Some(sym) => self.access_property(
+                        span,
+                        &candidate.ret_ty,
+                        &Key::Normal { span, sym },
+                        TypeOfMode::RValue,
+                        IdCtx::Var,
+                        Default::default(),
+                    )?,
+                    None => candidate.ret_ty.clone(),
+                });
+            }
+        }
+
+        let mut props = match props {
+            Some(props) => props,
+            None => match candidate.params.first() {
+                Some(param) => *param.ty.clone(),
+                None => Type::TypeLit(TypeLit {
+                    span,
+                    members: vec![],
+                    metadata: Default::default(),
+                }),
+            },
+        };
+
+        if let Some(ns) = ns {
+            if let Some(managed) = namespace_member(span, ns, "LibraryManagedAttributes") {
+                props = self.instantiate_jsx_type(span, managed, vec![tag_ty.clone(), props])?;
+            }
+
+            let mut types = vec![props];
+            if let Some(attrs) = namespace_member(span, ns, "IntrinsicAttributes") {
+                types.push(attrs);
+            }
+            if kind == ExtractKind::New {
+                if let Some(attrs) = namespace_member(span, ns, "IntrinsicClassAttributes") {
+                    types.push(self.instantiate_jsx_type(span, attrs, vec![candidate.ret_ty.clone()])?);
+                }
+            }
+            props = Type::new_intersection(span, types);
+        }
+
+        Ok(props.freezed())
+    }
+
+    /// Names of attributes provided by `JSX.IntrinsicAttributes` and
+    /// `JSX.IntrinsicClassAttributes`, like `key` and `ref`.
+    fn jsx_reserved_attr_names(&mut self, span: Span, ns: Option<&Type>, kind: ExtractKind) -> VResult<Vec<JsWord>> {
+        let mut names = vec![];
+
+        if let Some(ns) = ns {
+            if let Some(attrs) = namespace_member(span, ns, "IntrinsicAttributes") {
+                names.extend(self.jsx_property_names(span, &attrs)?);
+            }
+            if kind == ExtractKind::New {
+                if let Some(attrs) = namespace_member(span, ns, "IntrinsicClassAttributes") {
+                    names.extend(self.jsx_property_names(span, &attrs)?);
+                }
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// Name of the property used for children, which is specified by
+    /// `JSX.ElementChildrenAttribute`.
+    fn jsx_children_attr_name(&mut self, span: Span, ns: Option<&Type>) -> VResult<JsWord> {
+        if let Some(attr) = ns.and_then(|ns| namespace_member(span, ns, "ElementChildrenAttribute")) {
+            if let Some(name) = self.jsx_property_names(span, &attr)?.into_iter().next() {
+                return Ok(name);
+            }
+        }
+
+        Ok("children".into())
+    }
+
+    fn jsx_property_names(&mut self, span: Span, ty: &Type) -> VResult<Vec<JsWord>> {
+        let lit = self.convert_type_to_type_lit(span, Cow::Borrowed(ty))?;
+
+        Ok(lit
+            .map(|lit| {
+                lit.members
+                    .iter()
+                    .filter_map(|member| match member {
+                        TypeElement::Property(p) => match &p.key {
+                            Key::Normal { sym, .. } => Some(sym.clone()),
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Instantiates a generic type alias or interface of the `JSX` namespace.
+    fn instantiate_jsx_type(&mut self, span: Span, ty: Type, args: Vec<Type>) -> VResult<Type> {
+        let (type_params, ty) = match ty.normalize() {
+            Type::Alias(alias) => (alias.type_params.clone(), *alias.ty.clone()),
+            Type::Interface(i) => (
+                i.type_params.clone(),
+                Type::Interface(Interface {
+                    type_params: None,
+                    ..i.clone()
+                }),
+            ),
+            _ => return Ok(ty),
+        };
+
+        let type_params = match type_params {
+            Some(type_params) => type_params.params,
+            None => return Ok(ty),
+        };
+
+        let mut params = FxHashMap::default();
+        for (param, arg) in type_params.into_iter().zip(args) {
+            params.insert(param.name, arg.freezed());
+        }
+
+        let mut ty = self.expand_type_params(&params, ty, Default::default())?;
+        ty.reposition(span);
+
+        Ok(ty)
+    }
+
+    /// Checks if the tag can be used as a component, and reports `TS2786` if
+    /// it cannot.
+    ///
+    /// If `JSX.ElementType` exists, the type of the tag should be assignable
+    /// to it. Otherwise, the return type of a function component should be
+    /// assignable to `JSX.Element | null`, and instances of a class component
+    /// should be assignable to `JSX.ElementClass`.
+    fn check_jsx_component(&mut self, span: Span, name: JsWord, ns: &Type, kind: ExtractKind, tag_ty: &Type, ret_ty: &Type) {
+        let res = match namespace_member(span, ns, "ElementType") {
+            Some(element_type) => self.assign(span, &mut Default::default(), &element_type, tag_ty),
+            None => {
+                let expected = match kind {
+                    ExtractKind::New => namespace_member(span, ns, "ElementClass"),
+                    ExtractKind::Call => namespace_member(span, ns, "Element").map(|element| {
+                        Type::new_union(
+                            span,
+                            vec![
+                                element,
+                                Type::Keyword(KeywordType {
+                                    span,
+                                    kind: TsKeywordTypeKind::TsNullKeyword,
+                                    metadata: Default::default(),
+                                }),
+                            ],
+                        )
+                    }),
+                };
+
+                match expected {
+                    Some(expected) => self.assign(span, &mut Default::default(), &expected, ret_ty),
+                    None => Ok(()),
+                }
+            }
+        };
+
+        if let Err(err) = res {
+            self.storage.report(
+                ErrorKind::InvalidJsxComponent {
+                    span,
+                    name,
+                    cause: box err,
+                }
+                .into(),
+            );
+        }
+    }
+}
+
+/// Finds a type named `name` exported from a namespace or a module.
+fn namespace_member(span: Span, ns: &Type, name: &str) -> Option<Type> {
+    match ns.normalize() {
+        Type::Namespace(Namespace { exports, .. }) | Type::Module(Module { exports, .. }) => {
+            let types = exports
+                .types
+                .get(name)
+                .or_else(|| exports.private_types.iter().find(|(id, _)| &**id.sym() == name).map(|(_, v)| v))?;
+
+            Some(Type::new_intersection(span, types.iter().cloned()).freezed())
+        }
+        Type::Intersection(i) => i.types.iter().find_map(|ty| namespace_member(span, ty, name)),
+        _ => None,
+    }
+}
+
+/// `<div />`, `<my-element />` and `<svg:rect />` are intrinsic elements.
+pub(crate) fn is_intrinsic_tag(name: &RJSXElementName) -> bool {
+    match name {
+        RJSXElementName::Ident(i) => i.sym.starts_with(|c: char| c.is_ascii_lowercase()) || i.sym.contains('-'),
+        RJSXElementName::JSXMemberExpr(..) => false,
+        RJSXElementName::JSXNamespacedName(..) => true,
+    }
+}
+
+fn tag_name(name: &RJSXElementName) -> JsWord {
+    fn object_name(obj: &RJSXObject) -> String {
+        match obj {
+            RJSXObject::Ident(i) => i.sym.to_string(),
+            RJSXObject::JSXMemberExpr(e) => format!("{}.{}", object_name(&e.obj), e.prop.sym),
+        }
+    }
+
+    match name {
+        RJSXElementName::Ident(i) => i.sym.clone(),
+        RJSXElementName::JSXMemberExpr(e) => format!("{}.{}", object_name(&e.obj), e.prop.sym).into(),
+        RJSXElementName::JSXNamespacedName(n) => format!("{}:{}", n.ns.sym, n.name.sym).into(),
+    }
+}
+
+/// Converts the tag of a value-based element to an expression.
+fn tag_to_expr(name: &RJSXElementName) -> RExpr {
+    fn object_to_expr(obj: &RJSXObject) -> RExpr {
+        match obj {
+            RJSXObject::Ident(i) if i.sym == js_word!("this") => RExpr::This(RThisExpr {
+                node_id: NodeId::invalid(),
+                span: i.span,
+            }),
+            RJSXObject::Ident(i) => RExpr::Ident(i.clone()),
+            RJSXObject::JSXMemberExpr(e) => RExpr::Member(RMemberExpr {
+                node_id: NodeId::invalid(),
+                span: e.span(),
+                obj: box object_to_expr(&e.obj),
+                prop: RMemberProp::Ident(e.prop.clone()),
+            }),
+        }
+    }
+
+    match name {
+        RJSXElementName::Ident(i) => object_to_expr(&RJSXObject::Ident(i.clone())),
+        RJSXElementName::JSXMemberExpr(e) => RExpr::Member(RMemberExpr {
+            node_id: NodeId::invalid(),
+            span: e.span(),
+            obj: box object_to_expr(&e.obj),
+            prop: RMemberProp::Ident(e.prop.clone()),
+        }),
+        RJSXElementName::JSXNamespacedName(..) => unreachable!("namespaced names are intrinsic"),
+    }
+}
+
+/// Converts attributes and children of `e` to an object literal.
+///
+/// Values of attributes in `reserved` and attributes with a hyphenated name
+/// are not a part of props, so they are returned separately.
+fn jsx_attrs_to_object(e: &RJSXElement, children_name: &JsWord, reserved: &[JsWord]) -> (RObjectLit, Vec<RExpr>) {
+    let mut props = vec![];
+    let mut extra = vec![];
+
+    for attr in &e.opening.attrs {
+        let attr = match attr {
+            RJSXAttrOrSpread::JSXAttr(attr) => attr,
+            RJSXAttrOrSpread::SpreadElement(spread) => {
+                props.push(RPropOrSpread::Spread(spread.clone()));
+                continue;
+            }
+        };
+
+        let (key, name_span) = match &attr.name {
+            RJSXAttrName::Ident(i) => (RPropName::Ident(i.clone()), i.span),
+            RJSXAttrName::JSXNamespacedName(n) => (
+                RPropName::Str(RStr {
+                    span: n.span(),
+                    value: format!("{}:{}", n.ns.sym, n.name.sym).into(),
+                    raw: None,
+                }),
+                n.span(),
+            ),
+        };
+
+        let value = match &attr.value {
+            // `<input disabled />`
+            None => RExpr::Lit(RLit::Bool(RBool {
+                span: name_span,
+                value: true,
+            })),
+            Some(RJSXAttrValue::Lit(lit)) => RExpr::Lit(lit.clone()),
+            Some(RJSXAttrValue::JSXExprContainer(c)) => match &c.expr {
+                RJSXExpr::Expr(e) => *e.clone(),
+                RJSXExpr::JSXEmptyExpr(..) => continue,
+            },
+            Some(RJSXAttrValue::JSXElement(e)) => RExpr::JSXElement(e.clone()),
+            Some(RJSXAttrValue::JSXFragment(f)) => RExpr::JSXFragment(f.clone()),
+        };
+
+        let is_extra = match &key {
+            RPropName::Ident(i) => i.sym.contains('-') || reserved.contains(&i.sym),
+            _ => false,
+        };
+        if is_extra {
+            extra.push(value);
+            continue;
+        }
+
+        props.push(RPropOrSpread::Prop(box RProp::KeyValue(RKeyValueProp {
+            node_id: NodeId::invalid(),
+            key,
+            value: box value,
+        })));
+    }
+
+    let children = e.children.iter().filter_map(child_to_expr).collect::<Vec<_>>();
+    if !children.is_empty() {
+        let span = children[0].span().with_hi(children[children.len() - 1].span().hi);
+
+        let value = if children.len() == 1 && children[0].spread.is_none() {
+            children.into_iter().next().unwrap().expr
+        } else {
+            box RExpr::Array(RArrayLit {
+                node_id: NodeId::invalid(),
+                span,
+                elems: children.into_iter().map(Some).collect(),
+            })
+        };
+
+        props.push(RPropOrSpread::Prop(box RProp::KeyValue(RKeyValueProp {
+            node_id: NodeId::invalid(),
+            key: RPropName::Ident(RIdent::new(children_name.clone(), span)),
+            value,
+        })));
+    }
+
+    (
+        RObjectLit {
+            node_id: NodeId::invalid(),
+            span: e.opening.span,
+            props,
+        },
+        extra,
+    )
+}
+
+/// Returns [None] for children which are ignored, like whitespaces
+/// containing a line break.
+fn child_to_expr(child: &RJSXElementChild) -> Option<RExprOrSpread> {
+    let expr = match child {
+        RJSXElementChild::JSXText(text) => {
+            let value = text.value.trim();
+            if value.is_empty() && text.value.contains('\n') {
+                return None;
+            }
+
+            RExpr::Lit(RLit::Str(RStr {
+                span: text.span,
+                value: value.into(),
+                raw: None,
+            }))
+        }
+        RJSXElementChild::JSXExprContainer(c) => match &c.expr {
+            RJSXExpr::Expr(e) => *e.clone(),
+            RJSXExpr::JSXEmptyExpr(..) => return None,
+        },
+        RJSXElementChild::JSXSpreadChild(c) => {
+            return Some(RExprOrSpread {
+                spread: Some(c.span),
+                expr: c.expr.clone(),
+            })
+        }
+        RJSXElementChild::JSXElement(e) => RExpr::JSXElement(e.clone()),
+        RJSXElementChild::JSXFragment(f) => RExpr::JSXFragment(f.clone()),
+    };
+
+    Some(RExprOrSpread {
+        spread: None,
+        expr: box expr,
+    })
+}
@@ -24,7 +24,7 @@ use stc_ts_types::{
     QueryTypeMetadata, StaticThis, ThisType, TplType, TplTypeMetadata,
 };
 use stc_utils::{cache::Freeze, debug_ctx, ext::TypeVecExt, stack};
-use swc_atoms::js_word;
+use swc_atoms::{js_word, JsWord};
 use swc_common::{Span, Spanned, SyntaxContext, TypeEq, DUMMY_SP};
 use swc_ecma_ast::{op, EsVersion, TruePlusMinus, TsKeywordTypeKind, TsTypeOperatorOp, VarDeclKind};
 use tracing::{debug, info, warn, Level};
@@ -60,7 +60,7 @@ mod call_new;
 mod const_assertion;
 mod constraint_reducer;
 mod function;
-mod jsx;
+pub(crate) mod jsx;
 mod meta_prop;
 mod misc;
 mod object;
@@ -171,7 +171,9 @@ impl Analyzer<'_, '_> {
                             let mut ty = ty.into_owned();
                             let name = Name::from(Id::word(js_word!("this")));
 
-                            if !self.is_builtin {
+                            // Type facts are not applied to the class being declared, as normalization
+                            // converts it to the instance type.
+                            if !self.is_builtin && !matches!(ty, Type::StaticThis(..)) {
                                 ty = self.apply_type_facts(&name, ty);
 
                                 ty.assert_valid();
@@ -201,6 +203,8 @@ impl Analyzer<'_, '_> {
                             metadata: Default::default(),
                         }));
                     }
+                    self.complete_scope(i.span);
+
                     let ty = self.type_of_var(i, mode, type_args)?;
                     if self.ctx.should_store_truthy_for_access && mode == TypeOfMode::RValue {
                         // `i` is truthy
@@ -316,6 +320,10 @@ impl Analyzer<'_, '_> {
 
                 RExpr::TsInstantiation(expr) => expr.validate_with_args(self, (mode, None, type_ann)),
 
+                RExpr::JSXElement(e) => e.validate_with(self),
+
+                RExpr::JSXFragment(e) => e.validate_with(self),
+
                 _ => unimplemented!("typeof ({:?})", e),
             }
         })()?;
@@ -357,6 +365,7 @@ impl Analyzer<'_, '_> {
         if !span.is_dummy() & !matches!(e, RExpr::Lit(..)) {
             self.dump_type(span, &ty);
         }
+        self.record_type(span, &ty);
 
         Ok(ty)
     }
@@ -811,6 +820,28 @@ impl Analyzer<'_, '_> {
         false
     }
 
+    /// Returns the name of the global interface which provides properties of
+    /// callable (or constructable) types.
+    ///
+    /// If `strictBindCallApply` is enabled, `bind`, `call` and `apply` are
+    /// typed using `CallableFunction` or `NewableFunction`.
+    fn fn_interface_name(&self, prop: &Key, is_constructor: bool) -> JsWord {
+        let is_bind_call_apply = match prop {
+            Key::Normal { sym, .. } => matches!(&**sym, "bind" | "call" | "apply"),
+            _ => false,
+        };
+
+        if !is_bind_call_apply || !self.rule().strict_bind_call_apply {
+            return js_word!("Function");
+        }
+
+        if is_constructor {
+            "NewableFunction".into()
+        } else {
+            "CallableFunction".into()
+        }
+    }
+
     #[cfg_attr(debug_assertions, tracing::instrument(skip_all))]
     fn access_property_of_type_elements(
         &mut self,
@@ -832,7 +863,11 @@ impl Analyzer<'_, '_> {
                             }
 
                             if let Some(ref type_ann) = p.type_ann {
-                                if p.optional {
+                                // `undefined` can't be assigned to optional properties with
+                                // `exactOptionalPropertyTypes`.
+                                let exact = type_mode == TypeOfMode::LValue && self.rule().exact_optional_property_types;
+
+                                if p.optional && !exact {
                                     let mut types = vec![Type::undefined(span, Default::default()), *type_ann.clone()];
                                     types.dedup_type();
                                     matching_elements.push(Type::union(types));
@@ -926,7 +961,7 @@ impl Analyzer<'_, '_> {
             // Handle funciton-like interfaces
             // Example of code handled by this block is `Error.call`
 
-            let obj = self.env.get_global_type(span, &js_word!("Function"))?;
+            let obj = self.env.get_global_type(span, &self.fn_interface_name(prop, false))?;
 
             if let Ok(v) = self.access_property(span, &obj, prop, type_mode, IdCtx::Var, opts) {
                 return Ok(Some(v));
@@ -968,16 +1003,17 @@ impl Analyzer<'_, '_> {
 
                 if indexed {
                     if let Some(type_ann) = type_ann {
-                        return Ok(Some(*type_ann.clone()));
+                        return Ok(Some(self.apply_unchecked_indexed_access(span, type_mode, *type_ann.clone())));
                     }
 
                     return Ok(Some(Type::any(span, Default::default())));
                 }
 
                 if (**index_ty).type_eq(&*prop_ty) {
-                    return Ok(Some(
-                        type_ann.clone().map(|v| *v).unwrap_or_else(|| Type::any(span, Default::default())),
-                    ));
+                    return Ok(Some(match type_ann {
+                        Some(type_ann) => self.apply_unchecked_indexed_access(span, type_mode, *type_ann.clone()),
+                        None => Type::any(span, Default::default()),
+                    }));
                 }
 
                 if let Type::EnumVariant(..) = prop_ty.normalize() {
@@ -1385,18 +1421,27 @@ impl Analyzer<'_, '_> {
                                 ClassMember::Constructor(_) => {}
 
                                 ClassMember::Method(member @ Method { is_static: false, .. }) if member.key.type_eq(prop) => {
-                                    return Ok(Type::Function(ty::Function {
+                                    let member_span = member.span;
+                                    let ty = Type::Function(ty::Function {
                                         span: member.span,
                                         type_params: member.type_params.clone(),
                                         params: member.params.clone(),
                                         ret_ty: member.ret_ty.clone(),
                                         metadata: Default::default(),
-                                    }));
+                                    });
+                                    if type_mode != TypeOfMode::LValue {
+                                        self.mark_member_as_used(span, member_span);
+                                    }
+                                    return Ok(ty);
                                 }
 
                                 ClassMember::Property(member @ ClassProperty { is_static: false, .. }) => {
                                     if member.key.type_eq(prop) {
+                                        let member_span = member.span;
                                         let ty = *member.value.clone().unwrap_or_else(|| box Type::any(span, Default::default()));
+                                        if type_mode != TypeOfMode::LValue {
+                                            self.mark_member_as_used(span, member_span);
+                                        }
                                         let ty = match self.expand_top_ref(span, Cow::Borrowed(&ty), Default::default()) {
                                             Ok(new_ty) => {
                                                 if new_ty.is_any() {
@@ -1494,19 +1539,25 @@ impl Analyzer<'_, '_> {
                         match member {
                             stc_ts_types::ClassMember::Method(member @ Method { is_static: true, .. }) => {
                                 if member.key.type_eq(prop) {
-                                    return Ok(Type::Function(ty::Function {
+                                    let member_span = member.span;
+                                    let ty = Type::Function(ty::Function {
                                         span: member.span,
                                         type_params: member.type_params.clone(),
                                         params: member.params.clone(),
                                         ret_ty: member.ret_ty.clone(),
                                         metadata: Default::default(),
-                                    }));
+                                    });
+                                    if type_mode != TypeOfMode::LValue {
+                                        self.mark_member_as_used(*span, member_span);
+                                    }
+                                    return Ok(ty);
                                 }
                             }
 
                             stc_ts_types::ClassMember::Property(property @ ClassProperty { is_static: true, .. }) => {
                                 if property.key.type_eq(prop) {
-                                    return Ok(*property.value.clone().unwrap_or_else(|| {
+                                    let member_span = property.span;
+                                    let ty = *property.value.clone().unwrap_or_else(|| {
                                         box Type::any(
                                             *span,
                                             KeywordTypeMetadata {
@@ -1514,7 +1565,11 @@ impl Analyzer<'_, '_> {
                                                 ..Default::default()
                                             },
                                         )
-                                    }));
+                                    });
+                                    if type_mode != TypeOfMode::LValue {
+                                        self.mark_member_as_used(*span, member_span);
+                                    }
+                                    return Ok(ty);
                                 }
                             }
 
@@ -1657,7 +1712,12 @@ impl Analyzer<'_, '_> {
                             RTsEnumMemberId::Str(s) => s.value == *sym,
                         });
                         if !has_such_member {
-                            return Err(ErrorKind::NoSuchEnumVariant { span, name: sym.clone() }.into());
+                            return Err(ErrorKind::NoSuchEnumVariant {
+                                span,
+                                enum_name: e.id.sym.clone(),
+                                name: sym.clone(),
+                            }
+                            .into());
                         }
 
                         // Computed values are not permitted in an enum with string valued members.
@@ -1800,6 +1860,9 @@ impl Analyzer<'_, '_> {
 
                             //
                             if self.key_matches(span, &class_prop.key, prop, false) {
+                                if type_mode != TypeOfMode::LValue {
+                                    self.mark_member_as_used(span, class_prop.span);
+                                }
                                 return Ok(match class_prop.value {
                                     Some(ref ty) => *ty.clone(),
                                     None => Type::any(span, Default::default()),
@@ -1814,6 +1877,9 @@ impl Analyzer<'_, '_> {
                             }
 
                             if self.key_matches(span, &mtd.key, prop, false) {
+                                if type_mode != TypeOfMode::LValue {
+                                    self.mark_member_as_used(span, mtd.span);
+                                }
                                 if mtd.is_abstract {
                                     self.storage.report(ErrorKind::CannotAccessAbstractMember { span }.into());
                                     return Ok(Type::any(span, Default::default()));
@@ -1854,11 +1920,10 @@ impl Analyzer<'_, '_> {
                                     || self.assign(span, &mut Default::default(), index_ty, &prop_ty).is_ok();
 
                                 if indexed {
-                                    return Ok(index
-                                        .type_ann
-                                        .clone()
-                                        .map(|v| *v)
-                                        .unwrap_or_else(|| Type::any(span, Default::default())));
+                                    return Ok(match &index.type_ann {
+                                        Some(type_ann) => self.apply_unchecked_indexed_access(span, type_mode, *type_ann.clone()),
+                                        None => Type::any(span, Default::default()),
+                                    });
                                 }
                             }
                         }
@@ -2088,13 +2153,13 @@ impl Analyzer<'_, '_> {
                         })
                         | Type::Lit(LitType {
                             lit: RTsLit::Number(..), ..
-                        }) => return Ok(*elem_type),
+                        }) => return Ok(self.apply_unchecked_indexed_access(span, type_mode, *elem_type)),
 
                         _ => {}
                     }
                 }
                 if let Key::Num(n) = prop {
-                    return Ok(*elem_type.clone());
+                    return Ok(self.apply_unchecked_indexed_access(span, type_mode, *elem_type.clone()));
                 }
 
                 let array_ty = self.env.get_global_type(span, &js_word!("Array"))?;
@@ -2184,7 +2249,7 @@ impl Analyzer<'_, '_> {
                         span,
                         &Type::Ref(Ref {
                             span: span.with_ctxt(Default::default()),
-                            type_name: RTsEntityName::Ident(RIdent::new(js_word!("Function"), DUMMY_SP)),
+                            type_name: RTsEntityName::Ident(RIdent::new(self.fn_interface_name(prop, true), DUMMY_SP)),
                             type_args: None,
                             metadata: Default::default(),
                         }),
@@ -2220,7 +2285,7 @@ impl Analyzer<'_, '_> {
                         span,
                         &Type::Ref(Ref {
                             span: span.with_ctxt(Default::default()),
-                            type_name: RTsEntityName::Ident(RIdent::new(js_word!("Function"), DUMMY_SP)),
+                            type_name: RTsEntityName::Ident(RIdent::new(self.fn_interface_name(prop, true), DUMMY_SP)),
                             type_args: None,
                             metadata: Default::default(),
                         }),
@@ -2238,7 +2303,7 @@ impl Analyzer<'_, '_> {
                 }
 
                 if members.iter().any(|e| e.is_call()) {
-                    let obj = self.env.get_global_type(span, &js_word!("Function"))?;
+                    let obj = self.env.get_global_type(span, &self.fn_interface_name(prop, false))?;
                     if let Ok(v) = self.access_property(span, &obj, prop, type_mode, IdCtx::Var, opts) {
                         return Ok(v);
                     }
@@ -2480,6 +2545,9 @@ impl Analyzer<'_, '_> {
                             }
                             // TODO(kdy1): normalized string / ident
                             if self.key_matches(span, &p.key, prop, false) {
+                                if type_mode != TypeOfMode::LValue {
+                                    self.mark_member_as_used(span, p.span);
+                                }
                                 if let Some(ref ty) = p.value {
                                     return Ok(*ty.clone());
                                 }
@@ -2494,6 +2562,9 @@ impl Analyzer<'_, '_> {
                             }
 
                             if self.key_matches(span, &m.key, prop, false) {
+                                if type_mode != TypeOfMode::LValue {
+                                    self.mark_member_as_used(span, m.span);
+                                }
                                 return Ok(Type::Function(ty::Function {
                                     span,
                                     type_params: m.type_params.clone(),
@@ -2518,7 +2589,7 @@ impl Analyzer<'_, '_> {
                     span,
                     &Type::Ref(Ref {
                         span: span.with_ctxt(Default::default()),
-                        type_name: RTsEntityName::Ident(RIdent::new(js_word!("Function"), DUMMY_SP)),
+                        type_name: RTsEntityName::Ident(RIdent::new(self.fn_interface_name(prop, true), DUMMY_SP)),
                         type_args: None,
                         metadata: Default::default(),
                     }),
@@ -2577,6 +2648,7 @@ impl Analyzer<'_, '_> {
                 return Err(ErrorKind::NoSuchPropertyInModule {
                     span,
                     name: box name.clone(),
+                    prop: box prop.clone(),
                 }
                 .into());
             }
@@ -2821,7 +2893,7 @@ impl Analyzer<'_, '_> {
 
             Type::Function(f) if type_mode == TypeOfMode::RValue => {
                 // Use builtin type `Function`
-                let interface = self.env.get_global_type(f.span, &js_word!("Function"))?;
+                let interface = self.env.get_global_type(f.span, &self.fn_interface_name(prop, false))?;
                 return self.access_property(span, &interface, prop, type_mode, id_ctx, opts);
             }
 
@@ -2858,7 +2930,7 @@ impl Analyzer<'_, '_> {
                     span,
                     &Type::Ref(Ref {
                         span: span.with_ctxt(Default::default()),
-                        type_name: RTsEntityName::Ident(RIdent::new(js_word!("Function"), DUMMY_SP)),
+                        type_name: RTsEntityName::Ident(RIdent::new(self.fn_interface_name(prop, false), DUMMY_SP)),
                         type_args: None,
                         metadata: Default::default(),
                     }),
@@ -3012,6 +3084,10 @@ impl Analyzer<'_, '_> {
         let id: Id = i.into();
         let name: Name = i.into();
 
+        if type_mode == TypeOfMode::RValue {
+            self.mark_var_as_used(&id, span);
+        }
+
         if self.scope.is_declaring_fn(&id) {
             // We will expand this type query to proper type while calculating returns types
             // of a function.
@@ -3727,6 +3803,10 @@ impl Analyzer<'_, '_> {
         };
         obj_ty.make_clone_cheap();
 
+        if let RMemberProp::Ident(i) = prop {
+            self.complete_members(i.span, &obj_ty);
+        }
+
         self.storage.report_all(errors);
 
         let mut prop = self
@@ -29,7 +29,7 @@ impl Analyzer<'_, '_> {
         let dep_id = match dep_id {
             Some(v) => v,
             None => {
-                self.storage.report(ErrorKind::ModuleNotFound { span }.into());
+                self.storage.report(ErrorKind::ModuleNotFound { span, src: dst.clone() }.into());
 
                 return (ctxt, Type::any(span, Default::default()));
             }
@@ -37,7 +37,7 @@ impl Analyzer<'_, '_> {
         let data = match self.imports.get(&(ctxt, dep_id)).cloned() {
             Some(v) => v,
             None => {
-                self.storage.report(ErrorKind::ModuleNotFound { span }.into());
+                self.storage.report(ErrorKind::ModuleNotFound { span, src: dst.clone() }.into());
 
                 return (ctxt, Type::any(span, Default::default()));
             }
@@ -89,7 +89,13 @@ impl Analyzer<'_, '_> {
             let dep_id = match dep_id {
                 Some(v) => v,
                 None => {
-                    self.storage.report(ErrorKind::ModuleNotFound { span }.into());
+                    self.storage.report(
+                        ErrorKind::ModuleNotFound {
+                            span,
+                            src: import.src.clone(),
+                        }
+                        .into(),
+                    );
                     continue;
                 }
             };
@@ -127,11 +133,15 @@ impl Analyzer<'_, '_> {
 }
 
 impl Analyzer<'_, '_> {
-    fn handle_import(&mut self, span: Span, ctxt: ModuleId, target: ModuleId, orig: Id, id: Id) {
+    fn handle_import(&mut self, span: Span, ctxt: ModuleId, target: ModuleId, src: &JsWord, orig: Id, id: Id) {
         let mut found_entry = false;
 
         // Check for entry only if import was successful.
         if ctxt != target {
+            if let Some(symbol_table) = self.symbol_table() {
+                symbol_table.add_import(id.clone(), target, orig.sym().clone());
+            }
+
             if let Some(data) = self.imports.get(&(ctxt, target)) {
                 match data.normalize() {
                     Type::Module(data) => {
@@ -177,7 +187,15 @@ impl Analyzer<'_, '_> {
             if ctxt != target {
                 // If import was successful but the entry is not found, the error should point
                 // the specifier.
-                self.storage.report(ErrorKind::ImportFailed { span, orig, id }.into());
+                self.storage.report(
+                    ErrorKind::ImportFailed {
+                        span,
+                        src: src.clone(),
+                        orig,
+                        id,
+                    }
+                    .into(),
+                );
             }
         }
     }
@@ -197,15 +215,29 @@ impl Analyzer<'_, '_> {
                     //
                     match &named.imported {
                         Some(imported) => {
-                            self.handle_import(named.span, base, dep, Id::from(imported), Id::from(&named.local));
+                            self.handle_import(named.span, base, dep, &node.src.value, Id::from(imported), Id::from(&named.local));
                         }
                         None => {
-                            self.handle_import(named.span, base, dep, Id::from(&named.local), Id::from(&named.local));
+                            self.handle_import(
+                                named.span,
+                                base,
+                                dep,
+                                &node.src.value,
+                                Id::from(&named.local),
+                                Id::from(&named.local),
+                            );
                         }
                     }
                 }
                 RImportSpecifier::Default(default) => {
-                    self.handle_import(default.span, base, dep, Id::word(js_word!("default")), Id::from(&default.local));
+                    self.handle_import(
+                        default.span,
+                        base,
+                        dep,
+                        &node.src.value,
+                        Id::word(js_word!("default")),
+                        Id::from(&default.local),
+                    );
                 }
                 RImportSpecifier::Namespace(ns) => {
                     if base == dep {
@@ -21,7 +21,7 @@ use stc_ts_types::{Id, IdCtx, ModuleId, ModuleTypeData, Namespace};
 use stc_ts_utils::StcComments;
 use stc_utils::{cache::Freeze, panic_ctx, AHashMap, AHashSet};
 use swc_atoms::{js_word, JsWord};
-use swc_common::{FileName, SourceMap, Span, Spanned, DUMMY_SP, GLOBALS};
+use swc_common::{FileName, SourceMap, Span, Spanned, SyntaxContext, DUMMY_SP, GLOBALS};
 use swc_ecma_ast::*;
 
 use self::{
@@ -29,13 +29,17 @@ use self::{
     pat::PatMode,
     props::ComputedPropMode,
     scope::{Scope, VarKind},
+    unused::Usages,
     util::ResultExt,
 };
 pub(crate) use self::{scope::ScopeKind, types::NormalizeTypeOpts};
 use crate::{
+    completion::Completions,
     loader::{Load, ModuleInfo},
+    symbol_table::SymbolTable,
     ty,
     ty::Type,
+    type_table::TypeTable,
     validator,
     validator::ValidateWith,
     VResult,
@@ -52,6 +56,7 @@ macro_rules! try_opt {
 
 mod assign;
 mod class;
+mod completion;
 mod control_flow;
 mod convert;
 mod decl_merging;
@@ -70,6 +75,7 @@ mod stmt;
 #[cfg(test)]
 mod tests;
 mod types;
+mod unused;
 mod util;
 mod visit_mut;
 
@@ -195,6 +201,15 @@ pub(crate) struct Ctx {
     /// Should be modified directly instead of using `with_ctx`.
     in_unreachable: bool,
 
+    /// `true` if the end of the current statement list cannot be reached
+    /// because of `return`, `throw`, `break` or `continue`.
+    ///
+    /// Unlike `in_unreachable`, this does not make following statements
+    /// report unreachable code.
+    ///
+    /// Should be modified directly instead of using `with_ctx`.
+    after_jump: bool,
+
     /// `true` for top-level type annotations.
     is_not_topmost_type: bool,
 
@@ -254,6 +269,14 @@ pub struct Analyzer<'scope, 'b> {
 
     debugger: Option<Debugger>,
 
+    /// Types are recorded only if this is [Some].
+    type_table: Option<TypeTable>,
+
+    /// Symbols are recorded only if this is [Some].
+    symbol_table: Option<SymbolTable>,
+
+    completions: Option<Completions>,
+
     data: AnalyzerData,
 }
 #[derive(Debug, Default)]
@@ -294,6 +317,9 @@ struct AnalyzerData {
     cache: TypeCache,
 
     checked_for_async_iterator: bool,
+
+    /// Used by `noUnusedLocals` and `noUnusedParameters`.
+    usages: Usages,
 }
 
 #[derive(Debug, Default)]
@@ -362,6 +388,7 @@ impl Analyzer<'_, '_> {
             {
                 node.visit_children_with(&mut new);
             }
+            new.report_unused_vars(&node.body, false);
 
             let errors = new.storage.take_errors();
             let data = new.storage.take_info(self.ctx.module_id);
@@ -412,10 +439,31 @@ impl<'scope, 'b> Analyzer<'scope, 'b> {
             Scope::root(),
             false,
             debugger,
+            None,
+            None,
+            None,
             Default::default(),
         )
     }
 
+    /// Records types of expressions and declared identifiers to `type_table`.
+    pub fn with_type_table(mut self, type_table: Option<TypeTable>) -> Self {
+        self.type_table = type_table;
+        self
+    }
+
+    /// Records declarations, references and exports to `symbol_table`.
+    pub fn with_symbol_table(mut self, symbol_table: Option<SymbolTable>) -> Self {
+        self.symbol_table = symbol_table;
+        self
+    }
+
+    /// Collects completions requested by `completions`.
+    pub fn with_completions(mut self, completions: Option<Completions>) -> Self {
+        self.completions = completions;
+        self
+    }
+
     pub(crate) fn for_builtin(env: StableEnv, storage: &'b mut Builtin) -> Self {
         Self::new_inner(
             Env::new(env, Default::default(), EsVersion::latest(), ModuleConfig::None, Default::default()),
@@ -427,6 +475,9 @@ impl<'scope, 'b> Analyzer<'scope, 'b> {
             Scope::root(),
             true,
             None,
+            None,
+            None,
+            None,
             Default::default(),
         )
     }
@@ -443,6 +494,9 @@ impl<'scope, 'b> Analyzer<'scope, 'b> {
             scope,
             self.is_builtin,
             self.debugger.clone(),
+            self.type_table.clone(),
+            self.symbol_table.clone(),
+            self.completions.clone(),
             data,
         )
     }
@@ -457,6 +511,9 @@ impl<'scope, 'b> Analyzer<'scope, 'b> {
         scope: Scope<'scope>,
         is_builtin: bool,
         debugger: Option<Debugger>,
+        type_table: Option<TypeTable>,
+        symbol_table: Option<SymbolTable>,
+        completions: Option<Completions>,
         data: AnalyzerData,
     ) -> Self {
         let is_dts = storage.is_dts();
@@ -526,6 +583,7 @@ impl<'scope, 'b> Analyzer<'scope, 'b> {
                 allow_new_target: false,
                 disallow_suggesting_property_on_no_var: false,
                 in_unreachable: false,
+                after_jump: false,
                 is_not_topmost_type: false,
                 is_fn_param: false,
                 in_module: false,
@@ -537,6 +595,9 @@ impl<'scope, 'b> Analyzer<'scope, 'b> {
             mapped_type_param_name: vec![],
             imports_by_id: Default::default(),
             debugger,
+            type_table,
+            symbol_table,
+            completions,
             data,
         }
     }
@@ -731,7 +792,11 @@ impl Load for NoopLoader {
         unreachable!()
     }
 
-    fn declare_module(&self, name: &JsWord, module: Type) {
+    fn top_level_ctxt(&self, module: ModuleId) -> SyntaxContext {
+        SyntaxContext::empty()
+    }
+
+    fn declare_module(&self, base: ModuleId, name: &JsWord, module: Type) {
         unreachable!()
     }
 }
@@ -748,6 +813,10 @@ impl Analyzer<'_, '_> {
         // TODO: Pass spans.
         self.load_normal_imports(vec![], &items);
 
+        if let Some(symbol_table) = self.symbol_table() {
+            symbol_table.collect(modules);
+        }
+
         self.fill_known_type_names(&modules);
 
         self.validate_stmts_with_hoisting(&items);
@@ -816,7 +885,15 @@ impl Analyzer<'_, '_> {
             if self.is_builtin {
                 m.body.visit_children_with(self);
             } else {
+                if let Some(symbol_table) = self.symbol_table() {
+                    symbol_table.collect(&m.body);
+                }
+
                 self.validate_stmts_and_collect(&items_ref);
+
+                // Files without imports or exports are scripts.
+                let is_module = m.body.iter().any(|item| matches!(item, RModuleItem::ModuleDecl(..)));
+                self.report_unused_vars(&m.body, is_module);
             }
 
             Ok(())
@@ -860,13 +937,16 @@ impl Analyzer<'_, '_> {
         };
         self.with_ctx(ctx).with(|analyzer: &mut Analyzer| {
             let ty = match node.module_ref {
-                RTsModuleRef::TsEntityName(ref e) => analyzer
-                    .type_of_ts_entity_name(node.span, &e.clone().into(), None)
-                    .unwrap_or_else(|err| {
-                        analyzer.storage.report(err);
-                        Type::any(node.span, Default::default())
-                    })
-                    .freezed(),
+                RTsModuleRef::TsEntityName(ref e) => {
+                    analyzer.mark_entity_name_as_used(e);
+                    analyzer
+                        .type_of_ts_entity_name(node.span, &e.clone().into(), None)
+                        .unwrap_or_else(|err| {
+                            analyzer.storage.report(err);
+                            Type::any(node.span, Default::default())
+                        })
+                        .freezed()
+                }
                 RTsModuleRef::TsExternalModuleRef(ref e) => {
                     let (dep, data) = analyzer.get_imported_items(e.span, &e.expr.value);
 
@@ -1045,7 +1125,7 @@ impl Analyzer<'_, '_> {
                         }
                     }
 
-                    self.loader.declare_module(&s.value, ty.clone());
+                    self.loader.declare_module(self.ctx.module_id, &s.value, ty.clone());
                 }
             }
         }
@@ -72,7 +72,7 @@ pub(crate) struct Scope<'a> {
     pub declaring_type_params: FxHashSet<Id>,
 
     pub(super) vars: FxHashMap<Id, VarInfo>,
-    types: FxHashMap<Id, Type>,
+    pub(super) types: FxHashMap<Id, Type>,
     pub(super) facts: CondFacts,
 
     pub(super) declaring_fn: Option<Id>,
@@ -2,7 +2,8 @@ use std::borrow::Cow;
 
 use rnode::{VisitMut, VisitMutWith};
 use stc_ts_type_ops::this::contains_this;
-use stc_ts_types::{ClassMember, ClassProperty, Id, Key, Method, Type};
+use stc_ts_types::{ClassMember, ClassProperty, Id, Key, Method, StaticThis, Type};
+use swc_common::Span;
 
 use crate::analyzer::Analyzer;
 
@@ -17,10 +18,13 @@ impl Analyzer<'_, '_> {
         }
 
         if self.scope.is_this_ref_to_class() {
+            let in_static = matches!(self.scope.this().as_deref(), Some(Type::StaticThis(..)));
+
             for (_, m) in self.scope.class_members() {
                 match m {
-                    ClassMember::Method(Method { key, is_static: false, .. })
-                    | ClassMember::Property(ClassProperty { key, is_static: false, .. }) => {
+                    ClassMember::Method(Method { key, is_static, .. }) | ClassMember::Property(ClassProperty { key, is_static, .. })
+                        if *is_static == in_static =>
+                    {
                         if let Key::Normal { sym, .. } = key {
                             if *p.sym() == *sym {
                                 return true;
@@ -35,6 +39,15 @@ impl Analyzer<'_, '_> {
         false
     }
 
+    /// Binds `this` to the class being declared, like `this` in static
+    /// blocks.
+    pub(crate) fn bind_static_this(&mut self, span: Span) {
+        self.scope.this = Some(Type::StaticThis(StaticThis {
+            span,
+            metadata: Default::default(),
+        }));
+    }
+
     /// Expand `this` contained in `ty`.
     pub(crate) fn expand_this_in_type(&mut self, ty: &mut Type) {
         let this_ty = self.scope.this();
@@ -5,7 +5,7 @@ use rnode::{FoldWith, NodeId};
 use stc_ts_ast_rnode::{RBindingIdent, RExpr, RIdent, RNumber, RObjectPatProp, RPat, RStr, RTsEntityName, RTsLit};
 use stc_ts_errors::{ctx, debug::dump_type_as_string, DebugExt, ErrorKind};
 use stc_ts_type_ops::{widen::Widen, Fix};
-use stc_ts_types::{Array, Key, KeywordType, LitType, Ref, Tuple, Type, TypeLit, TypeParamInstantiation, Union};
+use stc_ts_types::{Array, Id, Key, KeywordType, LitType, Ref, Tuple, Type, TypeLit, TypeParamInstantiation, Union};
 use stc_ts_utils::{run, PatExt};
 use stc_utils::{cache::Freeze, TryOpt};
 use swc_common::{Span, Spanned, SyntaxContext, DUMMY_SP};
@@ -132,6 +132,8 @@ impl Analyzer<'_, '_> {
                     if let Some(m) = &mut self.mutations {
                         m.for_pats.entry(i.node_id).or_default().ty = Some(ty.clone());
                     }
+
+                    self.record_type(i.id.span, ty);
                 }
 
                 self.declare_var(
@@ -880,3 +882,34 @@ impl Analyzer<'_, '_> {
         .context("tried to ensure iterator")
     }
 }
+
+/// Usages for `noUnusedLocals` and `noUnusedParameters`.
+impl Analyzer<'_, '_> {
+    pub(crate) fn is_usage_tracked(&self) -> bool {
+        let rule = self.rule();
+
+        !self.is_builtin && !self.ctx.is_dts && (rule.no_unused_locals || rule.no_unused_parameters)
+    }
+
+    /// Records a read of a variable, or a reference to a type.
+    pub(crate) fn mark_var_as_used(&mut self, id: &Id, span: Span) {
+        if self.is_usage_tracked() {
+            self.data.usages.add_var(id, span);
+        }
+    }
+
+    /// Records a reference to the leftmost identifier of `n`.
+    pub(crate) fn mark_entity_name_as_used(&mut self, n: &RTsEntityName) {
+        match n {
+            RTsEntityName::Ident(i) => self.mark_var_as_used(&i.into(), i.span),
+            RTsEntityName::TsQualifiedName(n) => self.mark_entity_name_as_used(&n.left),
+        }
+    }
+
+    /// Records a read of the class member declared at `member_span`.
+    pub(crate) fn mark_member_as_used(&mut self, span: Span, member_span: Span) {
+        if self.is_usage_tracked() {
+            self.data.usages.add_member(span, member_span);
+        }
+    }
+}
@@ -411,7 +411,11 @@ impl Analyzer<'_, '_> {
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, node: &RDoWhileStmt) {
+        // `break` and `continue` in the body do not make the end of the loop
+        // unreachable.
+        let after_jump = self.ctx.after_jump;
         node.body.visit_with(self);
+        self.ctx.after_jump = after_jump;
 
         self.validate_loop_body_with_scope(Some(&node.test), &node.body)
             .report(&mut self.storage);
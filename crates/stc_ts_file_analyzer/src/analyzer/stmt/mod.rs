@@ -1,10 +1,14 @@
 use std::time::Instant;
 
-use rnode::VisitWith;
-use stc_ts_ast_rnode::{RBlockStmt, RBool, RForStmt, RModuleItem, RStmt, RTsExprWithTypeArgs, RTsLit, RWithStmt};
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{
+    RBlockStmt, RBool, RBreakStmt, RContinueStmt, RForStmt, RLabeledStmt, RModuleItem, RStmt, RThrowStmt, RTsExprWithTypeArgs, RTsLit,
+    RWithStmt,
+};
 use stc_ts_errors::ErrorKind;
 use stc_ts_types::{LitType, Type};
 use stc_utils::stack;
+use swc_atoms::JsWord;
 use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_utils::Value::Known;
 use tracing::{instrument, span, trace, warn, Level};
@@ -109,7 +113,12 @@ impl Analyzer<'_, '_> {
         self.check_for_inifinite_loop(test.as_ref().unwrap_or(&always_true), &node.body);
 
         node.update.visit_with(self);
+
+        // `break` and `continue` in the body do not make the end of the loop
+        // unreachable.
+        let after_jump = self.ctx.after_jump;
         node.body.validate_with(self)?;
+        self.ctx.after_jump = after_jump;
 
         Ok(())
     }
@@ -130,15 +139,80 @@ impl Analyzer<'_, '_> {
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, s: &RBlockStmt) {
-        self.with_child(ScopeKind::Block, Default::default(), |analyzer| {
+        let ends_with_jump = self.with_child(ScopeKind::Block, Default::default(), |analyzer| {
             s.stmts.visit_with(analyzer);
-            Ok(())
+            Ok(analyzer.ctx.after_jump || analyzer.ctx.in_unreachable)
         })?;
 
+        self.ctx.after_jump |= ends_with_jump;
+
+        Ok(())
+    }
+}
+
+#[validator]
+impl Analyzer<'_, '_> {
+    fn validate(&mut self, s: &RThrowStmt) {
+        s.arg.visit_with(self);
+
+        self.ctx.after_jump = true;
+
+        Ok(())
+    }
+}
+
+#[validator]
+impl Analyzer<'_, '_> {
+    fn validate(&mut self, _: &RBreakStmt) {
+        self.ctx.after_jump = true;
+
         Ok(())
     }
 }
 
+#[validator]
+impl Analyzer<'_, '_> {
+    fn validate(&mut self, _: &RContinueStmt) {
+        self.ctx.after_jump = true;
+
+        Ok(())
+    }
+}
+
+/// The body of a labeled statement can complete normally if it contains a
+/// `break` with the label.
+#[validator]
+impl Analyzer<'_, '_> {
+    fn validate(&mut self, s: &RLabeledStmt) {
+        let after_jump = self.ctx.after_jump;
+        s.body.visit_with(self);
+
+        let mut v = LabeledBreakFinder {
+            label: &s.label.sym,
+            found: false,
+        };
+        s.body.visit_with(&mut v);
+        if v.found {
+            self.ctx.after_jump = after_jump;
+        }
+
+        Ok(())
+    }
+}
+
+struct LabeledBreakFinder<'a> {
+    label: &'a JsWord,
+    found: bool,
+}
+
+impl Visit<RBreakStmt> for LabeledBreakFinder<'_> {
+    fn visit(&mut self, s: &RBreakStmt) {
+        if matches!(&s.label, Some(label) if label.sym == *self.label) {
+            self.found = true;
+        }
+    }
+}
+
 impl Analyzer<'_, '_> {
     /// Validate that parent interfaces are all resolved.
     #[instrument(skip(self, parents))]
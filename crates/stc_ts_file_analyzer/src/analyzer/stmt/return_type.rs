@@ -22,7 +22,7 @@ use crate::{
     analyzer::{
         assign::AssignOpts,
         expr::{GetIteratorOpts, TypeOfMode},
-        scope::ExpandOpts,
+        scope::{ExpandOpts, ScopeKind},
         util::ResultExt,
         Analyzer, Ctx,
     },
@@ -79,20 +79,30 @@ impl Analyzer<'_, '_> {
 
         // let mut old_ret_tys = self.scope.return_types.take();
 
+        // `true` if the end of the body is reachable while some code paths return.
+        let mut has_implicit_return = false;
+        let mut returns_void_or_any = false;
+
         let mut ret_ty = (|| -> VResult<_> {
             let mut values: ReturnValues = {
                 let ctx = Ctx {
                     preserve_ref: true,
                     cannot_fallback_to_iterable_iterator,
+                    after_jump: false,
                     ..self.ctx
                 };
                 self.with_ctx(ctx).with(|analyzer: &mut Analyzer| {
                     analyzer.validate_stmts_and_collect(&stmts.iter().collect::<Vec<_>>());
 
+                    has_implicit_return = !analyzer.ctx.in_unreachable && !analyzer.ctx.after_jump;
+
                     take(&mut analyzer.scope.return_values)
                 })
             };
 
+            has_implicit_return &= !values.return_types.is_empty();
+            returns_void_or_any = values.return_types.iter().any(is_void_or_any);
+
             {
                 //  Expand return types if no element references a type parameter
                 let can_expand = values.return_types.iter().all(|ty| {
@@ -283,6 +293,11 @@ impl Analyzer<'_, '_> {
             return Ok(ret_ty);
         }
 
+        if has_implicit_return && !is_generator && self.rule().no_implicit_returns && self.scope.kind() != ScopeKind::Constructor {
+            self.report_implicit_return(span, is_async, returns_void_or_any)
+                .report(&mut self.storage);
+        }
+
         if let Some(declared) = self.scope.declared_return_type().cloned() {
             if !is_async && !is_generator {
                 // Noop
@@ -307,6 +322,60 @@ impl Analyzer<'_, '_> {
 
         Ok(ret_ty)
     }
+
+    /// Reports `TS7030` for a function whose end is reachable while other code
+    /// paths return a value.
+    ///
+    /// `returns_void_or_any` is used only if the return type is not declared.
+    fn report_implicit_return(&mut self, span: Span, is_async: bool, returns_void_or_any: bool) -> VResult<()> {
+        let declared = match self.unwrapped_declared_return_type(is_async)? {
+            Some(declared) => declared,
+            None => {
+                if !returns_void_or_any {
+                    self.storage.report(ErrorKind::NotAllCodePathsReturnValue { span }.into());
+                }
+                return Ok(());
+            }
+        };
+        let span = declared.span();
+
+        if is_void_or_any(&declared)
+            || declared.is_kwd(TsKeywordTypeKind::TsUndefinedKeyword)
+            || declared.is_kwd(TsKeywordTypeKind::TsNeverKeyword)
+        {
+            return Ok(());
+        }
+
+        if self.rule().strict_null_checks
+            && self
+                .assign(span, &mut Default::default(), &declared, &Type::undefined(span, Default::default()))
+                .is_err()
+        {
+            // This is `TS2366`, not `TS7030`.
+            return Ok(());
+        }
+
+        self.storage.report(ErrorKind::NotAllCodePathsReturnValue { span }.into());
+
+        Ok(())
+    }
+
+    /// Returns the declared return type, unwrapped if `is_async`.
+    fn unwrapped_declared_return_type(&mut self, is_async: bool) -> VResult<Option<Type>> {
+        let declared = match self.scope.declared_return_type().cloned() {
+            Some(declared) => declared,
+            None => return Ok(None),
+        };
+        let span = declared.span();
+
+        let declared = if is_async {
+            self.get_awaited_type(span, Cow::Owned(declared))?
+        } else {
+            Cow::Owned(declared)
+        };
+
+        Ok(Some(self.normalize(Some(span), declared, Default::default())?.into_owned()))
+    }
 }
 
 #[validator]
@@ -315,6 +384,8 @@ impl Analyzer<'_, '_> {
         debug_assert!(!self.is_builtin, "builtin: return statement is not supported");
         debug_assert_ne!(node.span, DUMMY_SP, "return statement should have valid span");
 
+        self.ctx.after_jump = true;
+
         let mut ty = if let Some(res) = {
             let ctx = Ctx {
                 in_return_arg: true,
@@ -336,6 +407,18 @@ impl Analyzer<'_, '_> {
         debug_assert_ne!(ty.span(), DUMMY_SP, "{:?}", ty);
         ty.make_clone_cheap();
 
+        // With `strictNullChecks`, `return;` is checked by assigning `undefined`
+        // instead.
+        if node.arg.is_none() && !self.ctx.in_generator && self.rule().no_implicit_returns && !self.rule().strict_null_checks {
+            let is_async = self.ctx.in_async;
+            if let Some(declared) = self.unwrapped_declared_return_type(is_async).report(&mut self.storage).flatten() {
+                if !is_void_or_any(&declared) {
+                    self.storage
+                        .report(ErrorKind::NotAllCodePathsReturnValue { span: node.span }.into());
+                }
+            }
+        }
+
         if let Some(declared) = self.scope.declared_return_type().cloned() {
             match (self.ctx.in_async, self.ctx.in_generator) {
                 // AsyncGenerator
@@ -532,6 +615,10 @@ impl Visit<RReturnStmt> for LoopBreakerFinder {
     }
 }
 
+fn is_void_or_any(ty: &Type) -> bool {
+    ty.contains_void() || ty.is_any() || ty.is_unknown()
+}
+
 fn should_preserve_ref(ty: &Type) -> bool {
     match ty {
         Type::IndexedAccessType(..) => true,
@@ -1,8 +1,15 @@
 use rnode::VisitWith;
-use stc_ts_ast_rnode::RCatchClause;
+use stc_ts_ast_rnode::{RCatchClause, RTryStmt};
+use stc_ts_types::Type;
+use stc_ts_utils::PatExt;
+use swc_common::Spanned;
 
 use crate::{
-    analyzer::{pat::PatMode, scope::ScopeKind, Analyzer, Ctx},
+    analyzer::{
+        pat::PatMode,
+        scope::{ScopeKind, VarKind},
+        Analyzer, Ctx,
+    },
     validator,
     validator::ValidateWith,
 };
@@ -14,9 +21,16 @@ impl Analyzer<'_, '_> {
             pat_mode: PatMode::Decl,
             ..self.ctx
         };
-        self.with_ctx(ctx)
+        let ends_with_jump = self
+            .with_ctx(ctx)
             .with_child(ScopeKind::Block, Default::default(), |child: &mut Analyzer| {
                 match &s.param {
+                    // With `useUnknownInCatchVariables`, the type of a catch variable without
+                    // type annotation is `unknown` instead of `any`.
+                    Some(pat) if child.rule().use_unknown_in_catch_variables && pat.get_ty().is_none() => {
+                        let ty = Type::unknown(pat.span(), Default::default());
+                        child.declare_vars_with_ty(VarKind::Param, pat, Some(ty), None, None)?;
+                    }
                     Some(pat) => {
                         pat.validate_with(child)?;
                     }
@@ -25,7 +39,35 @@ impl Analyzer<'_, '_> {
 
                 s.body.visit_with(child);
 
-                Ok(())
-            })
+                Ok(child.ctx.after_jump || child.ctx.in_unreachable)
+            })?;
+
+        self.ctx.after_jump |= ends_with_jump;
+
+        Ok(())
+    }
+}
+
+#[validator]
+impl Analyzer<'_, '_> {
+    fn validate(&mut self, s: &RTryStmt) {
+        let after_jump = self.ctx.after_jump;
+
+        s.block.visit_with(self);
+        let mut ends_with_jump = self.ctx.after_jump;
+
+        // The end of `try` statement is reachable if the end of the `catch` clause is
+        // reachable.
+        if let Some(handler) = &s.handler {
+            self.ctx.after_jump = after_jump;
+            handler.visit_with(self);
+            ends_with_jump &= self.ctx.after_jump;
+        }
+
+        self.ctx.after_jump = after_jump;
+        s.finalizer.visit_with(self);
+        self.ctx.after_jump |= ends_with_jump;
+
+        Ok(())
     }
 }
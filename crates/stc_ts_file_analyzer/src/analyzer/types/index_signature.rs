@@ -2,10 +2,13 @@ use std::borrow::Cow;
 
 use stc_ts_errors::{debug::dump_type_as_string, DebugExt};
 use stc_ts_types::{ClassDef, ClassMember, IndexSignature, Type};
-use stc_utils::ext::ValueExt;
+use stc_utils::ext::{TypeVecExt, ValueExt};
 use swc_common::Span;
 
-use crate::{analyzer::Analyzer, VResult};
+use crate::{
+    analyzer::{expr::TypeOfMode, Analyzer},
+    VResult,
+};
 
 impl Analyzer<'_, '_> {
     /// Get [IndexSignature] from `ty`, if there's one.
@@ -36,4 +39,16 @@ impl Analyzer<'_, '_> {
 
         Ok(None)
     }
+
+    /// Adds `undefined` to `ty`, which is the type of an element read via an
+    /// index signature, if `noUncheckedIndexedAccess` is enabled.
+    pub(crate) fn apply_unchecked_indexed_access(&self, span: Span, type_mode: TypeOfMode, ty: Type) -> Type {
+        if type_mode != TypeOfMode::RValue || !self.rule().no_unchecked_indexed_access {
+            return ty;
+        }
+
+        let mut types = vec![ty, Type::undefined(span, Default::default())];
+        types.dedup_type();
+        Type::union(types)
+    }
 }
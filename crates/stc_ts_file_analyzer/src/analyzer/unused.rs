@@ -0,0 +1,810 @@
+//! `noUnusedLocals` and `noUnusedParameters`.
+//!
+//! Usages are recorded by the analyzer while it resolves names (see
+//! `scope/vars.rs`), and declarations are collected from the AST after a
+//! module or a namespace is validated. The analyzer may validate a function
+//! multiple times, but a usage is a usage regardless of the validation which
+//! recorded it.
+
+use std::mem;
+
+use fxhash::{FxHashMap, FxHashSet};
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{
+    RArrowExpr, RBlockStmt, RClass, RClassDecl, RClassMember, RClassMethod, RClassProp, RConstructor, RDecl, RDefaultDecl, RExportDecl,
+    RExportDefaultDecl, RExportNamedSpecifier, RExportSpecifier, RFnDecl, RForInStmt, RForOfStmt, RFunction, RIdent, RImportDecl,
+    RImportSpecifier, RModuleExportName, RNamedExport, RObjectPatProp, RParamOrTsParamProp, RPat, RPrivateMethod, RPrivateProp, RPropName,
+    RSetterProp, RTsEnumDecl, RTsImportEqualsDecl, RTsInterfaceDecl, RTsModuleDecl, RTsModuleName, RTsParamProp, RTsParamPropParam,
+    RTsTypeAliasDecl, RVarDecl,
+};
+use stc_ts_errors::ErrorKind;
+use stc_ts_types::Id;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::{Accessibility, VarDeclKind};
+
+use crate::analyzer::Analyzer;
+
+impl Analyzer<'_, '_> {
+    /// Reports unused declarations, if `noUnusedLocals` or
+    /// `noUnusedParameters` is enabled.
+    ///
+    /// Top-level declarations of a script are global, so they are reported
+    /// only if `is_module` is `true`.
+    pub(crate) fn report_unused_vars<N>(&mut self, node: &N, is_module: bool)
+    where
+        N: VisitWith<UsageTracker>,
+    {
+        if !self.is_usage_tracked() {
+            return;
+        }
+        let rule = self.rule();
+
+        let mut tracker = UsageTracker {
+            is_local: is_module,
+            in_fn: is_module,
+            ..Default::default()
+        };
+        node.visit_with(&mut tracker);
+
+        for (err, is_param) in tracker.into_errors(&self.data.usages) {
+            let enabled = if is_param {
+                rule.no_unused_parameters
+            } else {
+                rule.no_unused_locals
+            };
+
+            if enabled {
+                self.storage.report(err.into());
+            }
+        }
+    }
+}
+
+/// Usages recorded while the analyzer resolves names.
+#[derive(Debug, Default)]
+pub(crate) struct Usages {
+    /// Spans of references to variables and types.
+    vars: FxHashMap<Id, FxHashSet<Span>>,
+    /// `(lo, hi)` of class members which are read.
+    members: FxHashSet<(BytePos, BytePos)>,
+}
+
+impl Usages {
+    pub(crate) fn add_var(&mut self, id: &Id, span: Span) {
+        self.vars.entry(id.clone()).or_default().insert(span);
+    }
+
+    /// `member_span` is the span of the declaration of the member.
+    pub(crate) fn add_member(&mut self, span: Span, member_span: Span) {
+        // References from the body of a member are not usages, like `tsc`.
+        if contains(member_span, span) {
+            return;
+        }
+
+        self.members.insert((member_span.lo, member_span.hi));
+    }
+
+    fn is_var_used(&self, decl: &Decl) -> bool {
+        match self.vars.get(&decl.id) {
+            Some(spans) => spans.iter().any(|&span| !decl.body.map_or(false, |body| contains(body, span))),
+            None => false,
+        }
+    }
+
+    fn is_member_used(&self, member_span: Span) -> bool {
+        self.members.contains(&(member_span.lo, member_span.hi))
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.lo <= inner.lo && inner.hi <= outer.hi
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeclKind {
+    Value,
+    /// Classes, interfaces, type aliases and enums.
+    Type,
+}
+
+#[derive(Debug)]
+struct Decl {
+    id: Id,
+    span: Span,
+    kind: DeclKind,
+    is_param: bool,
+    group: Option<usize>,
+    /// References from the body of a declaration are not usages, like `tsc`.
+    body: Option<Span>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupKind {
+    /// An import declaration.
+    Imports,
+    /// A variable declaration, which may have multiple declarators.
+    Vars,
+    /// An object or array pattern.
+    Destructuring,
+}
+
+/// Declarations reported together if all of them are unused.
+#[derive(Debug)]
+struct DeclGroup {
+    span: Span,
+    kind: GroupKind,
+    /// Number of declarations in the group, including ones which are not
+    /// tracked.
+    len: usize,
+    is_param: bool,
+    /// A pattern of a declarator is reported as a declarator if it declares
+    /// only one variable.
+    parent: Option<usize>,
+}
+
+/// A private member of a class.
+#[derive(Debug)]
+struct PrivateMember {
+    name: JsWord,
+    is_private_name: bool,
+    /// Span of the key, which is reported.
+    span: Span,
+    /// Span of the whole member, which is the key of [Usages::members].
+    member_span: Span,
+    is_param_prop: bool,
+}
+
+/// Collects declarations, for `noUnusedLocals` and `noUnusedParameters`.
+///
+/// Identifiers are resolved, so a usage recorded with the same [Id] as a
+/// declaration is a usage of it.
+#[derive(Debug, Default)]
+pub(crate) struct UsageTracker {
+    decls: Vec<Decl>,
+    groups: Vec<DeclGroup>,
+    exported: FxHashSet<Id>,
+
+    /// Private members, grouped by class.
+    classes: Vec<Vec<PrivateMember>>,
+
+    in_declare: bool,
+    in_for_in_of_head: bool,
+    /// `false` for top-level declarations of scripts.
+    is_local: bool,
+    /// Same as `is_local`, but for `var`s.
+    in_fn: bool,
+}
+
+impl UsageTracker {
+    fn add_group(&mut self, span: Span, kind: GroupKind, len: usize, is_param: bool, parent: Option<usize>) -> usize {
+        let parent = parent.filter(|&parent| self.groups[parent].kind == GroupKind::Vars);
+
+        self.groups.push(DeclGroup {
+            span,
+            kind,
+            len,
+            is_param,
+            parent,
+        });
+        self.groups.len() - 1
+    }
+
+    fn declare(&mut self, i: &RIdent, kind: DeclKind, is_param: bool, group: Option<usize>) {
+        self.decls.push(Decl {
+            id: i.into(),
+            span: i.span,
+            kind,
+            is_param,
+            group,
+            body: None,
+        });
+    }
+
+    fn declare_with_body(&mut self, i: &RIdent, kind: DeclKind, body: Span) {
+        self.decls.push(Decl {
+            id: i.into(),
+            span: i.span,
+            kind,
+            is_param: false,
+            group: None,
+            body: Some(body),
+        });
+    }
+
+    fn declare_param(&mut self, pat: &RPat) {
+        if let Some(i) = binding_ident(pat) {
+            // `this` is not a parameter, and `_` is used for intentionally unused
+            // parameters.
+            if i.sym.starts_with('_') || i.sym == js_word!("this") {
+                return;
+            }
+        }
+
+        self.declare_pat(pat, true, None);
+    }
+
+    fn declare_pat(&mut self, pat: &RPat, is_param: bool, group: Option<usize>) {
+        match pat {
+            RPat::Ident(i) => self.declare(&i.id, DeclKind::Value, is_param, group),
+            RPat::Assign(p) => self.declare_pat(&p.left, is_param, group),
+            RPat::Rest(p) => self.declare_pat(&p.arg, is_param, group),
+            RPat::Array(p) => {
+                let group = self.add_group(p.span, GroupKind::Destructuring, p.elems.len(), is_param, group);
+
+                for elem in p.elems.iter().flatten() {
+                    if matches!(binding_ident(elem), Some(i) if i.sym.starts_with('_')) {
+                        continue;
+                    }
+
+                    self.declare_pat(elem, is_param, Some(group));
+                }
+            }
+            RPat::Object(p) => {
+                let group = self.add_group(p.span, GroupKind::Destructuring, p.props.len(), is_param, group);
+                // In `{ a, ...rest }`, `a` is used as it removes a property from `rest`.
+                let has_rest = matches!(p.props.last(), Some(RObjectPatProp::Rest(..)));
+
+                for prop in &p.props {
+                    match prop {
+                        RObjectPatProp::KeyValue(prop) => {
+                            if has_rest || matches!(binding_ident(&prop.value), Some(i) if i.sym.starts_with('_')) {
+                                continue;
+                            }
+
+                            self.declare_pat(&prop.value, is_param, Some(group));
+                        }
+                        RObjectPatProp::Assign(prop) => {
+                            if !has_rest {
+                                self.declare(&prop.key, DeclKind::Value, is_param, Some(group));
+                            }
+                        }
+                        RObjectPatProp::Rest(prop) => self.declare_pat(&prop.arg, is_param, Some(group)),
+                    }
+                }
+            }
+            RPat::Invalid(..) | RPat::Expr(..) => {}
+        }
+    }
+
+    /// Declarations in a function, a block, a class or a namespace are local
+    /// even if they are in a script.
+    fn in_local_scope<F>(&mut self, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let (is_local, in_fn) = (self.is_local, self.in_fn);
+        self.is_local = true;
+        self.in_fn = true;
+
+        op(self);
+
+        self.is_local = is_local;
+        self.in_fn = in_fn;
+    }
+
+    fn into_errors(self, usages: &Usages) -> Vec<(ErrorKind, bool)> {
+        let mut errors = vec![];
+
+        for members in &self.classes {
+            // Accessors of a property share a name.
+            let used = members
+                .iter()
+                .filter(|m| usages.is_member_used(m.member_span))
+                .map(|m| (&m.name, m.is_private_name))
+                .collect::<FxHashSet<_>>();
+
+            let mut reported = FxHashSet::default();
+            for m in members {
+                if used.contains(&(&m.name, m.is_private_name)) || !reported.insert((&m.name, m.is_private_name)) {
+                    continue;
+                }
+
+                let err = if m.is_param_prop {
+                    ErrorKind::UnusedProperty {
+                        span: m.span,
+                        name: m.name.clone(),
+                    }
+                } else if m.is_private_name {
+                    ErrorKind::UnusedLocal {
+                        span: m.span,
+                        name: format!("#{}", m.name).into(),
+                    }
+                } else {
+                    ErrorKind::UnusedLocal {
+                        span: m.span,
+                        name: m.name.clone(),
+                    }
+                };
+                errors.push((err, false));
+            }
+        }
+
+        let mut reported = FxHashSet::default();
+        let mut unused_in_groups = vec![vec![]; self.groups.len()];
+
+        for decl in &self.decls {
+            if self.exported.contains(&decl.id) || usages.is_var_used(decl) || !reported.insert(decl.id.clone()) {
+                continue;
+            }
+
+            match decl.group {
+                Some(group) => unused_in_groups[group].push((decl.id.sym().clone(), decl.span)),
+                None => {
+                    let name = decl.id.sym().clone();
+                    let err = match decl.kind {
+                        DeclKind::Value => ErrorKind::UnusedLocal { span: decl.span, name },
+                        DeclKind::Type => ErrorKind::UnusedType { span: decl.span, name },
+                    };
+                    errors.push((err, decl.is_param));
+                }
+            }
+        }
+
+        // Patterns are reported before declarators, because a pattern declaring only
+        // one variable is treated as a declarator.
+        for idx in 0..self.groups.len() {
+            let group = &self.groups[idx];
+            if group.kind != GroupKind::Destructuring || unused_in_groups[idx].is_empty() {
+                continue;
+            }
+            let unused = mem::take(&mut unused_in_groups[idx]);
+
+            if unused.len() == group.len {
+                if unused.len() == 1 {
+                    if let Some(parent) = group.parent {
+                        let span = group.span;
+                        unused_in_groups[parent].extend(unused.into_iter().map(|(name, _)| (name, span)));
+                        continue;
+                    }
+
+                    let (name, _) = unused.into_iter().next().unwrap();
+                    errors.push((ErrorKind::UnusedLocal { span: group.span, name }, group.is_param));
+                } else {
+                    errors.push((ErrorKind::AllDestructuredElementsUnused { span: group.span }, group.is_param));
+                }
+                continue;
+            }
+
+            for (name, span) in unused {
+                errors.push((ErrorKind::UnusedLocal { span, name }, group.is_param));
+            }
+        }
+
+        for (group, unused) in self.groups.iter().zip(unused_in_groups) {
+            if group.kind == GroupKind::Destructuring || unused.is_empty() {
+                continue;
+            }
+
+            if unused.len() == group.len {
+                let err = match (group.kind, unused.len()) {
+                    (GroupKind::Imports, 1) => ErrorKind::UnusedLocal {
+                        span: group.span,
+                        name: unused[0].0.clone(),
+                    },
+                    (_, 1) => ErrorKind::UnusedLocal {
+                        span: unused[0].1,
+                        name: unused[0].0.clone(),
+                    },
+                    (GroupKind::Imports, _) => ErrorKind::AllImportsUnused { span: group.span },
+                    _ => ErrorKind::AllVariablesUnused { span: group.span },
+                };
+                errors.push((err, false));
+                continue;
+            }
+
+            for (name, span) in unused {
+                errors.push((ErrorKind::UnusedLocal { span, name }, false));
+            }
+        }
+
+        errors
+    }
+}
+
+/// Returns the identifier if `pat` declares only one variable.
+fn binding_ident(pat: &RPat) -> Option<&RIdent> {
+    match pat {
+        RPat::Ident(i) => Some(&i.id),
+        RPat::Assign(p) => binding_ident(&p.left),
+        RPat::Rest(p) => binding_ident(&p.arg),
+        _ => None,
+    }
+}
+
+fn binding_idents<'a>(pat: &'a RPat, ids: &mut Vec<&'a RIdent>) {
+    match pat {
+        RPat::Ident(i) => ids.push(&i.id),
+        RPat::Assign(p) => binding_idents(&p.left, ids),
+        RPat::Rest(p) => binding_idents(&p.arg, ids),
+        RPat::Array(p) => {
+            for elem in p.elems.iter().flatten() {
+                binding_idents(elem, ids);
+            }
+        }
+        RPat::Object(p) => {
+            for prop in &p.props {
+                match prop {
+                    RObjectPatProp::KeyValue(prop) => binding_idents(&prop.value, ids),
+                    RObjectPatProp::Assign(prop) => ids.push(&prop.key),
+                    RObjectPatProp::Rest(prop) => binding_idents(&prop.arg, ids),
+                }
+            }
+        }
+        RPat::Invalid(..) | RPat::Expr(..) => {}
+    }
+}
+
+impl Visit<RImportDecl> for UsageTracker {
+    fn visit(&mut self, d: &RImportDecl) {
+        if self.in_declare || d.specifiers.is_empty() {
+            return;
+        }
+
+        let group = self.add_group(d.span, GroupKind::Imports, d.specifiers.len(), false, None);
+
+        for specifier in &d.specifiers {
+            let local = match specifier {
+                RImportSpecifier::Named(s) => &s.local,
+                RImportSpecifier::Default(s) => &s.local,
+                RImportSpecifier::Namespace(s) => &s.local,
+            };
+
+            if local.sym.starts_with('_') {
+                continue;
+            }
+
+            self.decls.push(Decl {
+                id: local.into(),
+                span: specifier.span(),
+                kind: DeclKind::Value,
+                is_param: false,
+                group: Some(group),
+                body: None,
+            });
+        }
+    }
+}
+
+impl Visit<RTsImportEqualsDecl> for UsageTracker {
+    fn visit(&mut self, d: &RTsImportEqualsDecl) {
+        if d.is_export {
+            self.exported.insert((&d.id).into());
+        } else if !self.in_declare && !d.id.sym.starts_with('_') {
+            self.declare(&d.id, DeclKind::Value, false, None);
+        }
+    }
+}
+
+impl Visit<RNamedExport> for UsageTracker {
+    fn visit(&mut self, e: &RNamedExport) {
+        if e.src.is_some() {
+            return;
+        }
+
+        for specifier in &e.specifiers {
+            if let RExportSpecifier::Named(RExportNamedSpecifier {
+                orig: RModuleExportName::Ident(i),
+                ..
+            }) = specifier
+            {
+                self.exported.insert(i.into());
+            }
+        }
+    }
+}
+
+impl Visit<RExportDecl> for UsageTracker {
+    fn visit(&mut self, e: &RExportDecl) {
+        match &e.decl {
+            RDecl::Class(d) => {
+                self.exported.insert((&d.ident).into());
+            }
+            RDecl::Fn(d) => {
+                self.exported.insert((&d.ident).into());
+            }
+            RDecl::Var(d) => {
+                let mut ids = vec![];
+                for decl in &d.decls {
+                    binding_idents(&decl.name, &mut ids);
+                }
+                self.exported.extend(ids.into_iter().map(Id::from));
+            }
+            RDecl::TsInterface(d) => {
+                self.exported.insert((&d.id).into());
+            }
+            RDecl::TsTypeAlias(d) => {
+                self.exported.insert((&d.id).into());
+            }
+            RDecl::TsEnum(d) => {
+                self.exported.insert((&d.id).into());
+            }
+            RDecl::TsModule(d) => {
+                if let RTsModuleName::Ident(i) = &d.id {
+                    self.exported.insert(i.into());
+                }
+            }
+        }
+
+        e.decl.visit_with(self);
+    }
+}
+
+impl Visit<RExportDefaultDecl> for UsageTracker {
+    fn visit(&mut self, e: &RExportDefaultDecl) {
+        if let RDefaultDecl::TsInterfaceDecl(d) = &e.decl {
+            self.exported.insert((&d.id).into());
+        }
+
+        e.decl.visit_with(self);
+    }
+}
+
+impl Visit<RVarDecl> for UsageTracker {
+    fn visit(&mut self, d: &RVarDecl) {
+        let in_declare = self.in_declare;
+        self.in_declare |= d.declare;
+
+        let is_local = if d.kind == VarDeclKind::Var { self.in_fn } else { self.is_local };
+
+        if is_local && !self.in_declare {
+            let group = self.add_group(d.span, GroupKind::Vars, d.decls.len(), false, None);
+
+            for decl in &d.decls {
+                // `_` is allowed for variables of `for-in` and `for-of`.
+                if self.in_for_in_of_head && matches!(binding_ident(&decl.name), Some(i) if i.sym.starts_with('_')) {
+                    continue;
+                }
+
+                self.declare_pat(&decl.name, false, Some(group));
+            }
+        }
+
+        self.in_for_in_of_head = false;
+        d.visit_children_with(self);
+
+        self.in_declare = in_declare;
+    }
+}
+
+impl Visit<RForInStmt> for UsageTracker {
+    fn visit(&mut self, s: &RForInStmt) {
+        self.in_for_in_of_head = true;
+        s.left.visit_with(self);
+        self.in_for_in_of_head = false;
+
+        s.right.visit_with(self);
+        s.body.visit_with(self);
+    }
+}
+
+impl Visit<RForOfStmt> for UsageTracker {
+    fn visit(&mut self, s: &RForOfStmt) {
+        self.in_for_in_of_head = true;
+        s.left.visit_with(self);
+        self.in_for_in_of_head = false;
+
+        s.right.visit_with(self);
+        s.body.visit_with(self);
+    }
+}
+
+impl Visit<RFnDecl> for UsageTracker {
+    fn visit(&mut self, d: &RFnDecl) {
+        let in_declare = self.in_declare;
+        self.in_declare |= d.declare;
+
+        if self.is_local && !self.in_declare {
+            self.declare_with_body(&d.ident, DeclKind::Value, d.function.span);
+        }
+
+        d.function.visit_with(self);
+
+        self.in_declare = in_declare;
+    }
+}
+
+impl Visit<RClassDecl> for UsageTracker {
+    fn visit(&mut self, d: &RClassDecl) {
+        let in_declare = self.in_declare;
+        self.in_declare |= d.declare;
+
+        if self.is_local && !self.in_declare {
+            self.declare_with_body(&d.ident, DeclKind::Type, d.class.span);
+        }
+
+        d.class.visit_with(self);
+
+        self.in_declare = in_declare;
+    }
+}
+
+impl Visit<RTsInterfaceDecl> for UsageTracker {
+    fn visit(&mut self, d: &RTsInterfaceDecl) {
+        if self.is_local && !self.in_declare {
+            self.declare_with_body(&d.id, DeclKind::Type, d.span);
+        }
+
+        d.visit_children_with(self);
+    }
+}
+
+impl Visit<RTsTypeAliasDecl> for UsageTracker {
+    fn visit(&mut self, d: &RTsTypeAliasDecl) {
+        if self.is_local && !self.in_declare {
+            self.declare_with_body(&d.id, DeclKind::Type, d.span);
+        }
+
+        d.visit_children_with(self);
+    }
+}
+
+impl Visit<RTsEnumDecl> for UsageTracker {
+    fn visit(&mut self, d: &RTsEnumDecl) {
+        let in_declare = self.in_declare;
+        self.in_declare |= d.declare;
+
+        if self.is_local && !self.in_declare {
+            self.declare_with_body(&d.id, DeclKind::Type, d.span);
+        }
+
+        d.visit_children_with(self);
+
+        self.in_declare = in_declare;
+    }
+}
+
+impl Visit<RTsModuleDecl> for UsageTracker {
+    fn visit(&mut self, d: &RTsModuleDecl) {
+        let id = match &d.id {
+            RTsModuleName::Ident(i) if !d.declare && !d.global => i,
+            _ => {
+                // Ambient modules and global augmentations.
+                let in_declare = self.in_declare;
+                self.in_declare = true;
+                d.body.visit_with(self);
+                self.in_declare = in_declare;
+                return;
+            }
+        };
+
+        if self.is_local && !self.in_declare {
+            self.declare_with_body(id, DeclKind::Value, d.span);
+        }
+
+        self.in_local_scope(|tracker| d.body.visit_with(tracker));
+    }
+}
+
+impl Visit<RBlockStmt> for UsageTracker {
+    fn visit(&mut self, s: &RBlockStmt) {
+        let is_local = self.is_local;
+        self.is_local = true;
+        s.visit_children_with(self);
+        self.is_local = is_local;
+    }
+}
+
+impl Visit<RFunction> for UsageTracker {
+    fn visit(&mut self, f: &RFunction) {
+        // Parameters of overloads and ambient functions are not checked.
+        if f.body.is_some() && !self.in_declare {
+            for param in &f.params {
+                self.declare_param(&param.pat);
+            }
+        }
+
+        self.in_local_scope(|tracker| f.visit_children_with(tracker));
+    }
+}
+
+impl Visit<RArrowExpr> for UsageTracker {
+    fn visit(&mut self, f: &RArrowExpr) {
+        if !self.in_declare {
+            for param in &f.params {
+                self.declare_param(param);
+            }
+        }
+
+        self.in_local_scope(|tracker| f.visit_children_with(tracker));
+    }
+}
+
+impl Visit<RConstructor> for UsageTracker {
+    fn visit(&mut self, c: &RConstructor) {
+        if c.body.is_some() && !self.in_declare {
+            for param in &c.params {
+                // Parameter properties are class members.
+                if let RParamOrTsParamProp::Param(param) = param {
+                    self.declare_param(&param.pat);
+                }
+            }
+        }
+
+        self.in_local_scope(|tracker| c.visit_children_with(tracker));
+    }
+}
+
+impl Visit<RSetterProp> for UsageTracker {
+    fn visit(&mut self, p: &RSetterProp) {
+        if p.body.is_some() && !self.in_declare {
+            self.declare_param(&p.param);
+        }
+
+        self.in_local_scope(|tracker| p.visit_children_with(tracker));
+    }
+}
+
+impl Visit<RClass> for UsageTracker {
+    fn visit(&mut self, c: &RClass) {
+        let mut members = vec![];
+
+        if !self.in_declare {
+            for member in &c.body {
+                match member {
+                    RClassMember::ClassProp(RClassProp {
+                        span,
+                        key,
+                        accessibility: Some(Accessibility::Private),
+                        ..
+                    })
+                    | RClassMember::Method(RClassMethod {
+                        span,
+                        key,
+                        accessibility: Some(Accessibility::Private),
+                        ..
+                    }) => {
+                        let name = match key {
+                            RPropName::Ident(i) => i.sym.clone(),
+                            RPropName::Str(s) => s.value.clone(),
+                            _ => continue,
+                        };
+                        members.push(PrivateMember {
+                            name,
+                            is_private_name: false,
+                            span: key.span(),
+                            member_span: *span,
+                            is_param_prop: false,
+                        });
+                    }
+                    RClassMember::PrivateProp(RPrivateProp { span, key, .. })
+                    | RClassMember::PrivateMethod(RPrivateMethod { span, key, .. }) => {
+                        members.push(PrivateMember {
+                            name: key.id.sym.clone(),
+                            is_private_name: true,
+                            span: key.span,
+                            member_span: *span,
+                            is_param_prop: false,
+                        });
+                    }
+                    RClassMember::Constructor(ctor) if ctor.body.is_some() => {
+                        for param in &ctor.params {
+                            if let RParamOrTsParamProp::TsParamProp(RTsParamProp {
+                                span,
+                                accessibility: Some(Accessibility::Private),
+                                param: RTsParamPropParam::Ident(i),
+                                ..
+                            }) = param
+                            {
+                                members.push(PrivateMember {
+                                    name: i.id.sym.clone(),
+                                    is_private_name: false,
+                                    span: i.id.span,
+                                    member_span: *span,
+                                    is_param_prop: true,
+                                });
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if !members.is_empty() {
+            self.classes.push(members);
+        }
+
+        self.in_local_scope(|tracker| c.visit_children_with(tracker));
+    }
+}
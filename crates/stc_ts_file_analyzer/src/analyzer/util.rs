@@ -16,6 +16,7 @@ use ty::TypeExt;
 
 use crate::{
     analyzer::{generic::is_literals, scope::ExpandOpts, Analyzer, Ctx},
+    symbol_table::SymbolTable,
     ty,
     ty::Type,
     VResult,
@@ -41,6 +42,27 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// Records the type of a node for editor features.
+    pub(crate) fn record_type(&self, span: Span, ty: &Type) {
+        if self.is_builtin || self.ctx.reevaluating() {
+            return;
+        }
+
+        if let Some(type_table) = &self.type_table {
+            type_table.insert(span, ty.clone());
+        }
+    }
+
+    /// Returns the symbol table if symbols of the current module should be
+    /// recorded.
+    pub(crate) fn symbol_table(&self) -> Option<&SymbolTable> {
+        if self.is_builtin {
+            return None;
+        }
+
+        self.symbol_table.as_ref()
+    }
+
     /// `span` and `callee` is used only for error reporting.
     #[cfg_attr(debug_assertions, tracing::instrument(skip_all))]
     fn make_instance_from_type_elements(&mut self, span: Span, callee: &Type, elements: &[TypeElement]) -> VResult<Type> {
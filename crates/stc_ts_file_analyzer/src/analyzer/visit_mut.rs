@@ -2,13 +2,13 @@
 
 use rnode::Visit;
 use stc_ts_ast_rnode::{
-    RArrowExpr, RBlockStmt, RCatchClause, RClass, RClassDecl, RClassExpr, RClassMember, RClassMethod, RComputedPropName, RConstructor,
-    RDoWhileStmt, RExportAll, RExportDecl, RExportDefaultDecl, RExportDefaultExpr, RExportNamedSpecifier, RExpr, RFnDecl, RFnExpr,
-    RForInStmt, RForOfStmt, RForStmt, RFunction, RIfStmt, RImportDecl, RModule, RModuleItem, RNamedExport, RObjectLit, RParam,
-    RParamOrTsParamProp, RPrivateMethod, RPrivateProp, RPropName, RReturnStmt, RSeqExpr, RStmt, RSwitchStmt, RTpl, RTsEnumDecl,
-    RTsExportAssignment, RTsFnParam, RTsFnType, RTsImportEqualsDecl, RTsInterfaceBody, RTsInterfaceDecl, RTsModuleBlock, RTsModuleDecl,
-    RTsNamespaceDecl, RTsParamProp, RTsTplLitType, RTsType, RTsTypeAliasDecl, RTsTypeElement, RVarDecl, RVarDeclarator, RWhileStmt,
-    RWithStmt, RYieldExpr,
+    RArrowExpr, RBlockStmt, RBreakStmt, RCatchClause, RClass, RClassDecl, RClassExpr, RClassMember, RClassMethod, RComputedPropName,
+    RConstructor, RContinueStmt, RDoWhileStmt, RExportAll, RExportDecl, RExportDefaultDecl, RExportDefaultExpr, RExportNamedSpecifier,
+    RExpr, RFnDecl, RFnExpr, RForInStmt, RForOfStmt, RForStmt, RFunction, RIfStmt, RImportDecl, RLabeledStmt, RModule, RModuleItem,
+    RNamedExport, RObjectLit, RParam, RParamOrTsParamProp, RPrivateMethod, RPrivateProp, RPropName, RReturnStmt, RSeqExpr, RStmt,
+    RSwitchStmt, RThrowStmt, RTpl, RTryStmt, RTsEnumDecl, RTsExportAssignment, RTsFnParam, RTsFnType, RTsImportEqualsDecl,
+    RTsInterfaceBody, RTsInterfaceDecl, RTsModuleBlock, RTsModuleDecl, RTsNamespaceDecl, RTsParamProp, RTsTplLitType, RTsType,
+    RTsTypeAliasDecl, RTsTypeElement, RVarDecl, RVarDeclarator, RWhileStmt, RWithStmt, RYieldExpr,
 };
 
 use crate::{analyzer::Analyzer, validator::ValidateWith};
@@ -68,6 +68,11 @@ forward!(visit_mut_stmt, RStmt);
 forward!(visit_mut_switch_stmt, RSwitchStmt);
 forward!(visit_mut_with_stmt, RWithStmt);
 forward!(visit_mut_return_stmt, RReturnStmt);
+forward!(visit_throw_stmt, RThrowStmt);
+forward!(visit_break_stmt, RBreakStmt);
+forward!(visit_continue_stmt, RContinueStmt);
+forward!(visit_labeled_stmt, RLabeledStmt);
+forward!(visit_try_stmt, RTryStmt);
 forward!(visit_mut_yield_expr, RYieldExpr);
 forward!(visit_mut_export_default_expr, RExportDefaultExpr);
 forward!(visit_mut_ts_export_assignment, RTsExportAssignment);
@@ -0,0 +1,72 @@
+//! Completions requested by editors.
+
+use std::sync::{Arc, Mutex};
+
+use stc_ts_types::Type;
+use swc_atoms::JsWord;
+use swc_common::FileName;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionKind {
+    Property,
+    Method,
+    Var,
+    Function,
+    Class,
+    Enum,
+    /// Interfaces, type aliases and type parameters.
+    Type,
+}
+
+#[derive(Debug, Clone)]
+pub struct Completion {
+    pub name: JsWord,
+    pub kind: CompletionKind,
+    pub ty: Option<Type>,
+}
+
+/// A request for completions of the identifier which starts at `offset` in
+/// `file`.
+///
+/// If the identifier is the property of a member expression, members of the
+/// object are collected. Otherwise, variables and types in scope are
+/// collected.
+#[derive(Debug, Clone)]
+pub struct Completions {
+    file: FileName,
+    offset: usize,
+    items: Arc<Mutex<Option<Vec<Completion>>>>,
+}
+
+impl Completions {
+    pub fn new(file: FileName, offset: usize) -> Self {
+        Self {
+            file,
+            offset,
+            items: Default::default(),
+        }
+    }
+
+    pub(crate) fn file(&self) -> &FileName {
+        &self.file
+    }
+
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Only the first result is stored, as a node may be validated multiple
+    /// times.
+    pub(crate) fn set(&self, items: Vec<Completion>) {
+        let mut lock = self.items.lock().unwrap();
+        if lock.is_none() {
+            *lock = Some(items);
+        }
+    }
+
+    /// Returns [None] if the identifier is not validated, e.g. because of
+    /// a syntax error.
+    pub fn take(&self) -> Option<Vec<Completion>> {
+        self.items.lock().unwrap().take()
+    }
+}
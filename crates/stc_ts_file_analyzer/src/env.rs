@@ -39,6 +39,12 @@ pub trait BuiltInGen: Sized {
             format!("{:x}", result)
         };
 
+        // Snapshots of default libraries are embedded in the binary.
+        if let Some(data) = stc_ts_builtin_types::snapshot(libs) {
+            return rmp_serde::decode::from_slice(data)
+                .unwrap_or_else(|err| panic!("failed to deserialize builtin snapshot of {:?}: {:?}", libs, err));
+        }
+
         let cache_path = Path::new(".stc").join(".builtin-cache").join(&format!("{}.rmp", key));
 
         if cache_path.is_file() {
@@ -49,6 +55,21 @@ pub trait BuiltInGen: Sized {
             return builtin;
         }
 
+        let builtin = Self::analyze_ts_libs(env, libs);
+
+        let json_data = rmp_serde::encode::to_vec(&builtin).unwrap_or_else(|err| panic!("failed to serialize builtin cache: {:?}", err));
+
+        std::fs::create_dir_all(cache_path.parent().unwrap())
+            .unwrap_or_else(|err| panic!("failed to create directory for builtin cache at {:?}: {:?}", cache_path, err));
+
+        std::fs::write(&cache_path, &json_data)
+            .unwrap_or_else(|err| panic!("failed to write builtin cache at {:?}: {:?}", cache_path, err));
+
+        builtin
+    }
+
+    /// Analyzes `libs` without using snapshots or caches.
+    fn analyze_ts_libs(env: &StableEnv, libs: &[Lib]) -> BuiltIn {
         let _stack = stack::start(300);
 
         let mut node_id_gen = NodeIdGenerator::default();
@@ -66,17 +87,7 @@ pub trait BuiltInGen: Sized {
             .cloned()
             .map(|orig| RModuleItem::from_orig(&mut node_id_gen, orig));
 
-        let builtin = Self::from_module_items(env, iter);
-
-        let json_data = rmp_serde::encode::to_vec(&builtin).unwrap_or_else(|err| panic!("failed to serialize builtin cache: {:?}", err));
-
-        std::fs::create_dir_all(cache_path.parent().unwrap())
-            .unwrap_or_else(|err| panic!("failed to create directory for builtin cache at {:?}: {:?}", cache_path, err));
-
-        std::fs::write(&cache_path, &json_data)
-            .unwrap_or_else(|err| panic!("failed to write builtin cache at {:?}: {:?}", cache_path, err));
-
-        builtin
+        Self::from_module_items(env, iter)
     }
 
     fn from_modules(env: &StableEnv, modules: Vec<RModule>) -> BuiltIn {
@@ -269,6 +280,24 @@ impl BuiltInGen for BuiltIn {
     }
 }
 
+/// Analyzes the default libraries of `lib` (an entry of
+/// [stc_ts_builtin_types::SNAPSHOT_LIBS]) and returns the name and the data of
+/// the snapshot.
+pub fn create_builtin_snapshot(lib: &str) -> (String, Vec<u8>) {
+    let mut libs = Lib::load(lib);
+    libs.sort();
+    libs.dedup();
+
+    // Marks in types should match ones of `Env::simple`.
+    let env = StableEnv::default();
+    let builtin = swc_common::GLOBALS.set(env.swc_globals(), || BuiltIn::analyze_ts_libs(&env, &libs));
+
+    let data =
+        rmp_serde::encode::to_vec(&builtin).unwrap_or_else(|err| panic!("failed to serialize builtin snapshot of {}: {:?}", lib, err));
+
+    (stc_ts_builtin_types::snapshot_name(&libs), data)
+}
+
 pub trait EnvFactory {
     #[allow(clippy::new_ret_no_self)]
     fn new(env: StableEnv, rule: Rule, target: EsVersion, module: ModuleConfig, builtin: Arc<BuiltIn>) -> Env;
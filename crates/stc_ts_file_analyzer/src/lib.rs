@@ -26,12 +26,15 @@ use swc_atoms::JsWord;
 use swc_common::Span;
 
 pub mod analyzer;
+pub mod completion;
 pub mod env;
 pub mod loader;
+pub mod symbol_table;
 #[cfg(test)]
 mod tests;
 pub mod ty;
 mod type_facts;
+pub mod type_table;
 pub mod util;
 pub mod validator;
 
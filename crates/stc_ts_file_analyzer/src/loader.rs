@@ -3,7 +3,7 @@ use std::sync::Arc;
 use auto_impl::auto_impl;
 use stc_ts_types::{ModuleId, ModuleTypeData, Type};
 use swc_atoms::JsWord;
-use swc_common::FileName;
+use swc_common::{FileName, SyntaxContext};
 
 use crate::VResult;
 
@@ -41,6 +41,15 @@ pub trait Load: 'static + Send + Sync {
     /// Returned value must be [Type::Arc] of [Type::Module]
     fn load_non_circular_dep(&self, base: ModuleId, dep: ModuleId) -> VResult<Type>;
 
+    /// Syntax context of top-level items of `module`.
+    ///
+    /// This is used to find items referenced without an identifier, like the
+    /// `JSX` namespace.
+    fn top_level_ctxt(&self, module: ModuleId) -> SyntaxContext;
+
     /// `module` should be [Type::Arc] of [Type::Module].
-    fn declare_module(&self, name: &JsWord, module: Type);
+    ///
+    /// If `name` resolves to a file from `base`, `module` is an augmentation
+    /// and its exports should be merged into the exports of the file.
+    fn declare_module(&self, base: ModuleId, name: &JsWord, module: Type);
 }
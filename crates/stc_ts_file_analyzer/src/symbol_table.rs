@@ -0,0 +1,507 @@
+//! Declarations and references of symbols, for editor features like
+//! go-to-definition.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use fxhash::FxBuildHasher;
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{
+    RArrowExpr, RAssignPatProp, RBindingIdent, RCatchClause, RClassDecl, RClassExpr, RConstructor, RExportSpecifier, RExpr, RFnDecl,
+    RFnExpr, RFunction, RIdent, RImportDecl, RImportSpecifier, RJSXElementName, RJSXObject, RModuleExportName, RNamedExport,
+    RObjectPatProp, RParamOrTsParamProp, RPat, RProp, RSetterProp, RTsEntityName, RTsEnumDecl, RTsImportEqualsDecl, RTsInterfaceDecl,
+    RTsModuleDecl, RTsModuleName, RTsParamPropParam, RTsTypeAliasDecl, RVarDecl,
+};
+use stc_ts_types::{Id, ModuleId};
+use swc_atoms::JsWord;
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::VarDeclKind;
+
+use crate::util::span_map::SpanMap;
+
+/// Imports and reexports are followed at most this many times, to handle
+/// cyclic reexports.
+const MAX_LINKS: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Var,
+    Const,
+    Param,
+    Function,
+    Class,
+    Interface,
+    TypeAlias,
+    Enum,
+    Namespace,
+    Import,
+}
+
+/// A declaration of a symbol.
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub id: Id,
+    pub kind: SymbolKind,
+    /// Span of the name.
+    pub span: Span,
+    /// Span of the whole declaration.
+    pub full_span: Span,
+    /// Name of the declaration containing this declaration.
+    pub container: Option<JsWord>,
+}
+
+/// The origin of an export.
+#[derive(Debug, Clone)]
+enum ExportOrigin {
+    Local(Id),
+    /// Reexported from another module.
+    Module(ModuleId, JsWord),
+}
+
+/// Declarations and references of all analyzed modules.
+///
+/// Identifiers are resolved, so [Id]s are unique across modules. Imports are
+/// linked to the exports of the dependency, so navigation works across
+/// modules.
+///
+/// This is recorded only if it's passed to the analyzer, as it's not required
+/// for type checking. Cloning is cheap and the clones share the table.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTable {
+    decls: Arc<DashMap<Id, Vec<Symbol>, FxBuildHasher>>,
+    refs: Arc<SpanMap<Id>>,
+    /// Imported bindings, and the exports they refer to.
+    imports: Arc<DashMap<Id, (ModuleId, JsWord), FxBuildHasher>>,
+    exports: Arc<DashMap<(ModuleId, JsWord), ExportOrigin, FxBuildHasher>>,
+    /// Modules reexported with `export * from 'foo'`.
+    star_exports: Arc<DashMap<ModuleId, Vec<ModuleId>, FxBuildHasher>>,
+}
+
+impl SymbolTable {
+    /// Records declarations and references in `node`.
+    pub(crate) fn collect<N>(&self, node: &N)
+    where
+        N: for<'a> VisitWith<SymbolCollector<'a>>,
+    {
+        node.visit_with(&mut SymbolCollector {
+            table: self,
+            containers: vec![],
+        });
+    }
+
+    pub(crate) fn add_import(&self, id: Id, module: ModuleId, name: JsWord) {
+        self.imports.insert(id, (module, name));
+    }
+
+    pub(crate) fn add_export(&self, module: ModuleId, name: JsWord, orig: Id) {
+        self.exports.insert((module, name), ExportOrigin::Local(orig));
+    }
+
+    pub(crate) fn add_reexport(&self, module: ModuleId, name: JsWord, from: ModuleId, orig: JsWord) {
+        self.exports.insert((module, name), ExportOrigin::Module(from, orig));
+    }
+
+    pub(crate) fn add_star_export(&self, module: ModuleId, from: ModuleId) {
+        self.star_exports.entry(module).or_default().push(from);
+    }
+
+    fn add_ref(&self, span: Span, id: Id) {
+        if span.is_dummy() {
+            return;
+        }
+
+        self.refs.insert(span, id);
+    }
+
+    /// Removes declarations, references and exports of `module`, whose file
+    /// is in `lo..hi`, so they can be recorded again.
+    pub fn forget(&self, module: ModuleId, lo: BytePos, hi: BytePos) {
+        let in_file = |span: Span| lo <= span.lo && span.hi <= hi;
+
+        self.refs.remove_range(lo, hi);
+        self.decls.retain(|_, decls| {
+            decls.retain(|decl| !in_file(decl.span));
+            !decls.is_empty()
+        });
+        self.exports.retain(|(m, _), _| *m != module);
+        self.star_exports.remove(&module);
+    }
+
+    /// Returns the innermost reference at `pos`, including declarations.
+    pub fn ref_at(&self, pos: BytePos) -> Option<(Span, Id)> {
+        self.refs.innermost(pos, true)
+    }
+
+    /// Follows imports, and returns the [Id] of the original declaration.
+    pub fn resolve(&self, id: &Id) -> Id {
+        let mut id = id.clone();
+
+        for _ in 0..MAX_LINKS {
+            let (module, name) = match self.imports.get(&id) {
+                Some(import) => import.clone(),
+                None => break,
+            };
+
+            match self.find_export(module, &name, 0) {
+                Some(orig) => id = orig,
+                None => break,
+            }
+        }
+
+        id
+    }
+
+    fn find_export(&self, module: ModuleId, name: &JsWord, depth: usize) -> Option<Id> {
+        if depth >= MAX_LINKS {
+            return None;
+        }
+
+        let origin = self.exports.get(&(module, name.clone())).map(|origin| origin.clone());
+        match origin {
+            Some(ExportOrigin::Local(id)) => Some(id),
+            Some(ExportOrigin::Module(from, orig)) => self.find_export(from, &orig, depth + 1),
+            None => {
+                let deps = self.star_exports.get(&module).map(|deps| deps.clone()).unwrap_or_default();
+
+                deps.into_iter().find_map(|dep| self.find_export(dep, name, depth + 1))
+            }
+        }
+    }
+
+    /// Returns the declarations of `id`, following imports.
+    ///
+    /// Falls back to the declaration of the import itself if the imported
+    /// module is not analyzed.
+    pub fn definitions(&self, id: &Id) -> Vec<Symbol> {
+        let orig = self.resolve(id);
+
+        match self.decls.get(&orig) {
+            Some(decls) => decls.clone(),
+            None => self.decls.get(id).map(|decls| decls.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// Returns spans of all references to the symbol `id` refers to, including
+    /// imports of it.
+    pub fn references(&self, id: &Id) -> Vec<Span> {
+        let orig = self.resolve(id);
+
+        self.refs.spans(|id| *id == orig || self.resolve(id) == orig)
+    }
+
+    /// Returns all declarations.
+    pub fn symbols(&self) -> Vec<Symbol> {
+        let mut symbols = self.decls.iter().flat_map(|decls| decls.value().clone()).collect::<Vec<_>>();
+        symbols.sort_by_key(|symbol| symbol.span.lo);
+
+        symbols
+    }
+}
+
+pub(crate) struct SymbolCollector<'a> {
+    table: &'a SymbolTable,
+    /// Names of declarations being visited.
+    containers: Vec<JsWord>,
+}
+
+impl SymbolCollector<'_> {
+    fn declare(&mut self, i: &RIdent, kind: SymbolKind, full_span: Span) {
+        let id = Id::from(i);
+        self.table.add_ref(i.span, id.clone());
+
+        self.table.decls.entry(id.clone()).or_default().push(Symbol {
+            id,
+            kind,
+            span: i.span,
+            full_span,
+            container: self.containers.last().cloned(),
+        });
+    }
+
+    fn declare_pat(&mut self, pat: &RPat, kind: SymbolKind, full_span: Span) {
+        match pat {
+            RPat::Ident(i) => self.declare(&i.id, kind, full_span),
+            RPat::Assign(p) => self.declare_pat(&p.left, kind, full_span),
+            RPat::Rest(p) => self.declare_pat(&p.arg, kind, full_span),
+            RPat::Array(p) => {
+                for elem in p.elems.iter().flatten() {
+                    self.declare_pat(elem, kind, full_span);
+                }
+            }
+            RPat::Object(p) => {
+                for prop in &p.props {
+                    match prop {
+                        RObjectPatProp::KeyValue(prop) => self.declare_pat(&prop.value, kind, full_span),
+                        RObjectPatProp::Assign(prop) => self.declare(&prop.key, kind, full_span),
+                        RObjectPatProp::Rest(prop) => self.declare_pat(&prop.arg, kind, full_span),
+                    }
+                }
+            }
+            RPat::Invalid(..) | RPat::Expr(..) => {}
+        }
+    }
+
+    fn with_container<F>(&mut self, name: &JsWord, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.containers.push(name.clone());
+        op(self);
+        self.containers.pop();
+    }
+}
+
+impl Visit<RVarDecl> for SymbolCollector<'_> {
+    fn visit(&mut self, d: &RVarDecl) {
+        let kind = if d.kind == VarDeclKind::Const {
+            SymbolKind::Const
+        } else {
+            SymbolKind::Var
+        };
+
+        for decl in &d.decls {
+            self.declare_pat(&decl.name, kind, decl.span);
+        }
+
+        d.visit_children_with(self);
+    }
+}
+
+impl Visit<RFnDecl> for SymbolCollector<'_> {
+    fn visit(&mut self, d: &RFnDecl) {
+        self.declare(&d.ident, SymbolKind::Function, d.function.span);
+
+        self.with_container(&d.ident.sym, |v| d.function.visit_with(v));
+    }
+}
+
+impl Visit<RFnExpr> for SymbolCollector<'_> {
+    fn visit(&mut self, f: &RFnExpr) {
+        match &f.ident {
+            Some(i) => {
+                self.declare(i, SymbolKind::Function, f.function.span);
+
+                self.with_container(&i.sym, |v| f.function.visit_with(v));
+            }
+            None => f.function.visit_with(self),
+        }
+    }
+}
+
+impl Visit<RFunction> for SymbolCollector<'_> {
+    fn visit(&mut self, f: &RFunction) {
+        for param in &f.params {
+            self.declare_pat(&param.pat, SymbolKind::Param, param.span);
+        }
+
+        f.visit_children_with(self);
+    }
+}
+
+impl Visit<RArrowExpr> for SymbolCollector<'_> {
+    fn visit(&mut self, f: &RArrowExpr) {
+        for param in &f.params {
+            self.declare_pat(param, SymbolKind::Param, param.span());
+        }
+
+        f.visit_children_with(self);
+    }
+}
+
+impl Visit<RConstructor> for SymbolCollector<'_> {
+    fn visit(&mut self, c: &RConstructor) {
+        for param in &c.params {
+            match param {
+                RParamOrTsParamProp::Param(param) => self.declare_pat(&param.pat, SymbolKind::Param, param.span),
+                RParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+                    RTsParamPropParam::Ident(i) => self.declare(&i.id, SymbolKind::Param, prop.span),
+                    RTsParamPropParam::Assign(p) => self.declare_pat(&p.left, SymbolKind::Param, prop.span),
+                },
+            }
+        }
+
+        c.visit_children_with(self);
+    }
+}
+
+impl Visit<RSetterProp> for SymbolCollector<'_> {
+    fn visit(&mut self, p: &RSetterProp) {
+        self.declare_pat(&p.param, SymbolKind::Param, p.param.span());
+
+        p.visit_children_with(self);
+    }
+}
+
+impl Visit<RCatchClause> for SymbolCollector<'_> {
+    fn visit(&mut self, c: &RCatchClause) {
+        if let Some(param) = &c.param {
+            self.declare_pat(param, SymbolKind::Var, param.span());
+        }
+
+        c.visit_children_with(self);
+    }
+}
+
+impl Visit<RClassDecl> for SymbolCollector<'_> {
+    fn visit(&mut self, d: &RClassDecl) {
+        self.declare(&d.ident, SymbolKind::Class, d.class.span);
+
+        self.with_container(&d.ident.sym, |v| d.class.visit_with(v));
+    }
+}
+
+impl Visit<RClassExpr> for SymbolCollector<'_> {
+    fn visit(&mut self, c: &RClassExpr) {
+        match &c.ident {
+            Some(i) => {
+                self.declare(i, SymbolKind::Class, c.class.span);
+
+                self.with_container(&i.sym, |v| c.class.visit_with(v));
+            }
+            None => c.class.visit_with(self),
+        }
+    }
+}
+
+impl Visit<RTsInterfaceDecl> for SymbolCollector<'_> {
+    fn visit(&mut self, d: &RTsInterfaceDecl) {
+        self.declare(&d.id, SymbolKind::Interface, d.span);
+
+        self.with_container(&d.id.sym, |v| d.visit_children_with(v));
+    }
+}
+
+impl Visit<RTsTypeAliasDecl> for SymbolCollector<'_> {
+    fn visit(&mut self, d: &RTsTypeAliasDecl) {
+        self.declare(&d.id, SymbolKind::TypeAlias, d.span);
+
+        self.with_container(&d.id.sym, |v| d.visit_children_with(v));
+    }
+}
+
+impl Visit<RTsEnumDecl> for SymbolCollector<'_> {
+    fn visit(&mut self, d: &RTsEnumDecl) {
+        self.declare(&d.id, SymbolKind::Enum, d.span);
+
+        self.with_container(&d.id.sym, |v| d.visit_children_with(v));
+    }
+}
+
+impl Visit<RTsModuleDecl> for SymbolCollector<'_> {
+    fn visit(&mut self, d: &RTsModuleDecl) {
+        match &d.id {
+            RTsModuleName::Ident(i) if !d.global => {
+                self.declare(i, SymbolKind::Namespace, d.span);
+
+                self.with_container(&i.sym, |v| d.body.visit_with(v));
+            }
+            _ => d.body.visit_with(self),
+        }
+    }
+}
+
+impl Visit<RImportDecl> for SymbolCollector<'_> {
+    fn visit(&mut self, d: &RImportDecl) {
+        for specifier in &d.specifiers {
+            let local = match specifier {
+                RImportSpecifier::Named(s) => &s.local,
+                RImportSpecifier::Default(s) => &s.local,
+                RImportSpecifier::Namespace(s) => &s.local,
+            };
+
+            self.declare(local, SymbolKind::Import, specifier.span());
+        }
+    }
+}
+
+impl Visit<RTsImportEqualsDecl> for SymbolCollector<'_> {
+    fn visit(&mut self, d: &RTsImportEqualsDecl) {
+        self.declare(&d.id, SymbolKind::Import, d.span);
+
+        d.module_ref.visit_with(self);
+    }
+}
+
+impl Visit<RNamedExport> for SymbolCollector<'_> {
+    fn visit(&mut self, e: &RNamedExport) {
+        // Specifiers of reexports are not local bindings.
+        if e.src.is_some() {
+            return;
+        }
+
+        for specifier in &e.specifiers {
+            if let RExportSpecifier::Named(named) = specifier {
+                if let RModuleExportName::Ident(i) = &named.orig {
+                    self.table.add_ref(i.span, i.into());
+                }
+            }
+        }
+    }
+}
+
+impl Visit<RBindingIdent> for SymbolCollector<'_> {
+    fn visit(&mut self, i: &RBindingIdent) {
+        self.table.add_ref(i.id.span, (&i.id).into());
+
+        i.type_ann.visit_with(self);
+    }
+}
+
+impl Visit<RAssignPatProp> for SymbolCollector<'_> {
+    fn visit(&mut self, p: &RAssignPatProp) {
+        self.table.add_ref(p.key.span, (&p.key).into());
+
+        p.value.visit_with(self);
+    }
+}
+
+impl Visit<RExpr> for SymbolCollector<'_> {
+    fn visit(&mut self, e: &RExpr) {
+        match e {
+            RExpr::Ident(i) => self.table.add_ref(i.span, i.into()),
+            _ => e.visit_children_with(self),
+        }
+    }
+}
+
+impl Visit<RProp> for SymbolCollector<'_> {
+    fn visit(&mut self, p: &RProp) {
+        match p {
+            RProp::Shorthand(i) => self.table.add_ref(i.span, i.into()),
+            _ => p.visit_children_with(self),
+        }
+    }
+}
+
+impl Visit<RTsEntityName> for SymbolCollector<'_> {
+    fn visit(&mut self, n: &RTsEntityName) {
+        match n {
+            RTsEntityName::Ident(i) => self.table.add_ref(i.span, i.into()),
+            RTsEntityName::TsQualifiedName(n) => n.left.visit_with(self),
+        }
+    }
+}
+
+impl Visit<RJSXElementName> for SymbolCollector<'_> {
+    fn visit(&mut self, n: &RJSXElementName) {
+        match n {
+            // Intrinsic elements like `<div />` are not references.
+            RJSXElementName::Ident(i) => {
+                if !i.sym.starts_with(|c: char| c.is_ascii_lowercase()) {
+                    self.table.add_ref(i.span, i.into());
+                }
+            }
+            RJSXElementName::JSXMemberExpr(e) => e.obj.visit_with(self),
+            RJSXElementName::JSXNamespacedName(..) => {}
+        }
+    }
+}
+
+impl Visit<RJSXObject> for SymbolCollector<'_> {
+    fn visit(&mut self, o: &RJSXObject) {
+        match o {
+            RJSXObject::Ident(i) => self.table.add_ref(i.span, i.into()),
+            RJSXObject::JSXMemberExpr(e) => e.obj.visit_with(self),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Types of nodes computed by the analyzer, for editor features like hover.
+
+use std::sync::Arc;
+
+use stc_ts_types::Type;
+use stc_utils::cache::Freeze;
+use swc_common::{BytePos, Span};
+
+use crate::util::span_map::SpanMap;
+
+/// A side table from spans of expressions and declared identifiers to their
+/// types.
+///
+/// This is recorded only if it's passed to the analyzer, as it's not required
+/// for type checking. Cloning is cheap and the clones share the table.
+#[derive(Debug, Clone, Default)]
+pub struct TypeTable {
+    types: Arc<SpanMap<Type>>,
+}
+
+impl TypeTable {
+    pub(crate) fn insert(&self, span: Span, mut ty: Type) {
+        if span.is_dummy() {
+            return;
+        }
+
+        ty.make_clone_cheap();
+        self.types.insert(span, ty);
+    }
+
+    /// Removes types of nodes in `lo..hi`, which is the range of a file to be
+    /// analyzed again.
+    pub fn forget(&self, lo: BytePos, hi: BytePos) {
+        self.types.remove_range(lo, hi);
+    }
+
+    /// Returns the type of the innermost node containing `pos`.
+    pub fn type_at(&self, pos: BytePos) -> Option<(Span, Type)> {
+        self.types.innermost(pos, false)
+    }
+}
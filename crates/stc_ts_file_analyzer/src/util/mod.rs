@@ -9,6 +9,7 @@ use crate::ty::{Intersection, Type, Union};
 
 pub(crate) mod dashmap;
 pub(crate) mod graph;
+pub(crate) mod span_map;
 pub(crate) mod type_ext;
 
 pub(crate) trait ModuleItemOrStmt {
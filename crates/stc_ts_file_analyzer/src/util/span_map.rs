@@ -0,0 +1,112 @@
+//! A side table keyed by spans, which supports lookups by position.
+
+use std::{cmp::Reverse, sync::RwLock};
+
+use swc_common::{BytePos, Span};
+
+#[cfg(test)]
+mod tests;
+
+/// Values keyed by spans of nodes.
+///
+/// Entries are appended while analyzing, and sorted by the first lookup after
+/// that, so a lookup by position is a binary search.
+#[derive(Debug)]
+pub(crate) struct SpanMap<V> {
+    entries: RwLock<Entries<V>>,
+}
+
+#[derive(Debug)]
+struct Entries<V> {
+    /// If `sorted` is true, this is sorted by `lo` and then by `hi` in
+    /// descending order, so an outer span comes before inner spans.
+    items: Vec<((BytePos, BytePos), V)>,
+    sorted: bool,
+}
+
+impl<V> Default for SpanMap<V> {
+    fn default() -> Self {
+        Self {
+            entries: RwLock::new(Entries {
+                items: Default::default(),
+                sorted: true,
+            }),
+        }
+    }
+}
+
+impl<V> SpanMap<V>
+where
+    V: Clone,
+{
+    /// A value replaces the old value of the same span.
+    pub fn insert(&self, span: Span, value: V) {
+        let mut entries = self.entries.write().unwrap();
+        entries.items.push(((span.lo, span.hi), value));
+        entries.sorted = false;
+    }
+
+    /// Returns the innermost span containing `pos`, and its value.
+    ///
+    /// If `inclusive` is true, a span ending at `pos` contains `pos`.
+    pub fn innermost(&self, pos: BytePos, inclusive: bool) -> Option<(Span, V)> {
+        self.sort();
+
+        let entries = self.entries.read().unwrap();
+        let end = entries.items.partition_point(|&((lo, _), _)| lo <= pos);
+
+        // Spans of nodes are nested or disjoint, so the last span containing `pos` is
+        // the innermost one.
+        entries.items[..end]
+            .iter()
+            .rev()
+            .find(|&&((_, hi), _)| pos < hi || (inclusive && pos == hi))
+            .map(|((lo, hi), value)| (Span::new(*lo, *hi, Default::default()), value.clone()))
+    }
+
+    /// Returns spans of values matching `pred`, sorted by their start.
+    pub fn spans(&self, mut pred: impl FnMut(&V) -> bool) -> Vec<Span> {
+        self.sort();
+
+        let entries = self.entries.read().unwrap();
+        entries
+            .items
+            .iter()
+            .filter(|(_, value)| pred(value))
+            .map(|&((lo, hi), _)| Span::new(lo, hi, Default::default()))
+            .collect()
+    }
+
+    /// Removes entries in `lo..hi`.
+    pub fn remove_range(&self, lo: BytePos, hi: BytePos) {
+        let mut entries = self.entries.write().unwrap();
+        entries.items.retain(|&((l, h), _)| !(lo <= l && h <= hi));
+    }
+
+    fn sort(&self) {
+        if self.entries.read().unwrap().sorted {
+            return;
+        }
+
+        let mut entries = self.entries.write().unwrap();
+        if entries.sorted {
+            return;
+        }
+
+        // The sort is stable, so values of the same span are in the order of
+        // insertion.
+        let mut items = std::mem::take(&mut entries.items);
+        items.sort_by_key(|&((lo, hi), _)| (lo, Reverse(hi)));
+
+        let mut deduped: Vec<((BytePos, BytePos), V)> = Vec::with_capacity(items.len());
+        for item in items {
+            match deduped.last_mut() {
+                Some(last) if last.0 == item.0 => *last = item,
+                _ => deduped.push(item),
+            }
+        }
+
+        entries.items = deduped;
+        entries.sorted = true;
+    }
+}
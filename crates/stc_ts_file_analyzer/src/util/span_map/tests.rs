@@ -0,0 +1,54 @@
+use swc_common::{BytePos, Span};
+
+use super::SpanMap;
+
+fn span(lo: u32, hi: u32) -> Span {
+    Span::new(BytePos(lo), BytePos(hi), Default::default())
+}
+
+fn map() -> SpanMap<&'static str> {
+    let map = SpanMap::default();
+    map.insert(span(20, 30), "sibling");
+    map.insert(span(1, 100), "outer");
+    map.insert(span(5, 15), "inner");
+    map.insert(span(5, 10), "innermost");
+    map
+}
+
+#[test]
+fn innermost() {
+    let map = map();
+
+    assert_eq!(map.innermost(BytePos(7), false), Some((span(5, 10), "innermost")));
+    assert_eq!(map.innermost(BytePos(12), false), Some((span(5, 15), "inner")));
+    assert_eq!(map.innermost(BytePos(25), false), Some((span(20, 30), "sibling")));
+    assert_eq!(map.innermost(BytePos(50), false), Some((span(1, 100), "outer")));
+    assert_eq!(map.innermost(BytePos(100), false), None);
+}
+
+#[test]
+fn inclusive_end() {
+    let map = map();
+
+    assert_eq!(map.innermost(BytePos(10), false), Some((span(5, 15), "inner")));
+    assert_eq!(map.innermost(BytePos(10), true), Some((span(5, 10), "innermost")));
+    assert_eq!(map.innermost(BytePos(100), true), Some((span(1, 100), "outer")));
+}
+
+#[test]
+fn last_insertion_wins() {
+    let map = map();
+    map.insert(span(5, 10), "replaced");
+
+    assert_eq!(map.innermost(BytePos(7), false), Some((span(5, 10), "replaced")));
+    assert_eq!(map.spans(|_| true).len(), 4);
+}
+
+#[test]
+fn remove_range() {
+    let map = map();
+    map.remove_range(BytePos(5), BytePos(15));
+
+    assert_eq!(map.innermost(BytePos(7), false), Some((span(1, 100), "outer")));
+    assert_eq!(map.spans(|_| true), vec![span(1, 100), span(20, 30)]);
+}
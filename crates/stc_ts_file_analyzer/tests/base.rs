@@ -12,7 +12,7 @@ use rnode::{NodeIdGenerator, RNode, VisitWith};
 use stc_testing::logger;
 use stc_ts_ast_rnode::RModule;
 use stc_ts_builtin_types::Lib;
-use stc_ts_env::{Env, ModuleConfig, Rule};
+use stc_ts_env::{Env, JsxConfig, ModuleConfig, Rule};
 use stc_ts_errors::{debug::debugger::Debugger, ErrorKind};
 use stc_ts_file_analyzer::{
     analyzer::{Analyzer, NoopLoader},
@@ -37,7 +37,11 @@ struct StcError {
     code: usize,
 }
 
-fn get_env() -> Env {
+/// Creates an [Env] for a fixture.
+///
+/// Options can be changed using `// @name: value` comments, like the test
+/// suite of `tsc`.
+fn get_env(src: &str) -> Env {
     let mut libs = vec![];
     let ls = &["es2017.full", "es2016.full", "es2015.full"];
     for s in ls {
@@ -46,15 +50,65 @@ fn get_env() -> Env {
     libs.sort();
     libs.dedup();
 
-    Env::simple(
-        Rule {
-            strict_function_types: true,
-            ..Default::default()
-        },
-        EsVersion::latest(),
-        ModuleConfig::None,
-        &libs,
-    )
+    let mut rule = Rule {
+        strict_function_types: true,
+        ..Default::default()
+    };
+    let mut jsx = JsxConfig::default();
+
+    for line in src.lines() {
+        let directive = match line.trim().strip_prefix("//").and_then(|s| s.trim_start().strip_prefix('@')) {
+            Some(directive) => directive,
+            None => continue,
+        };
+        let (name, value) = directive
+            .split_once(':')
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .unwrap_or_else(|| panic!("Invalid directive: {:?}", line));
+
+        match name {
+            // Like `tsconfig.json`, flags specified after this override it.
+            "strict" => {
+                let value = value.parse().unwrap();
+                rule.no_implicit_any = value;
+                rule.no_implicit_this = value;
+                rule.always_strict = value;
+                rule.strict_null_checks = value;
+                rule.strict_function_types = value;
+                rule.strict_bind_call_apply = value;
+                rule.strict_property_initialization = value;
+                rule.use_unknown_in_catch_variables = value;
+            }
+            "strictNullChecks" => rule.strict_null_checks = value.parse().unwrap(),
+            "strictBindCallApply" => rule.strict_bind_call_apply = value.parse().unwrap(),
+            "strictPropertyInitialization" => rule.strict_property_initialization = value.parse().unwrap(),
+            "useUnknownInCatchVariables" => rule.use_unknown_in_catch_variables = value.parse().unwrap(),
+            "noImplicitAny" => rule.no_implicit_any = value.parse().unwrap(),
+            "noUnusedLocals" => rule.no_unused_locals = value.parse().unwrap(),
+            "noUnusedParameters" => rule.no_unused_parameters = value.parse().unwrap(),
+            "noImplicitReturns" => rule.no_implicit_returns = value.parse().unwrap(),
+            "noFallthroughCasesInSwitch" => rule.no_fallthrough_cases_in_switch = value.parse().unwrap(),
+            "experimentalDecorators" => rule.experimental_decorators = value.parse().unwrap(),
+            "noUncheckedIndexedAccess" => rule.no_unchecked_indexed_access = value.parse().unwrap(),
+            "exactOptionalPropertyTypes" => rule.exact_optional_property_types = value.parse().unwrap(),
+            // Fixtures are checked using the latest target.
+            "target" => {}
+            "jsx" => jsx.mode = value.parse().unwrap(),
+            "jsxFactory" => jsx.factory = value.into(),
+            "jsxFragmentFactory" => jsx.fragment_factory = value.into(),
+            _ => panic!("Invalid directive: {:?}", line),
+        }
+    }
+
+    Env::simple(rule, EsVersion::latest(), ModuleConfig::None, &libs).with_jsx(jsx)
+}
+
+fn syntax(input: &Path) -> Syntax {
+    Syntax::Typescript(TsConfig {
+        tsx: input.extension().map_or(false, |ext| ext == "tsx"),
+        decorators: true,
+        ..Default::default()
+    })
 }
 
 fn validate(input: &Path) -> Vec<StcError> {
@@ -65,7 +119,7 @@ fn validate(input: &Path) -> Vec<StcError> {
 
             let fm = cm.load_file(input).unwrap();
 
-            let env = get_env();
+            let env = get_env(&fm.src);
 
             let generator = module_id::ModuleIdGenerator::default();
             let path = Arc::new(FileName::Real(input.to_path_buf()));
@@ -74,12 +128,7 @@ fn validate(input: &Path) -> Vec<StcError> {
 
             let mut node_id_gen = NodeIdGenerator::default();
             let mut module = {
-                let lexer = Lexer::new(
-                    Syntax::Typescript(TsConfig { ..Default::default() }),
-                    EsVersion::Es2021,
-                    SourceFileInput::from(&*fm),
-                    None,
-                );
+                let lexer = Lexer::new(syntax(input), EsVersion::Es2021, SourceFileInput::from(&*fm), None);
                 let mut parser = Parser::new_from(lexer);
 
                 parser.parse_module().unwrap()
@@ -147,6 +196,11 @@ fn validate(input: &Path) -> Vec<StcError> {
         .collect()
 }
 
+#[fixture("tests/errors/**/*.tsx")]
+fn errors_tsx(input: PathBuf) {
+    errors(input)
+}
+
 #[fixture("tests/errors/**/*.ts")]
 fn errors(input: PathBuf) {
     testing::run_test2(false, |cm, handler| {
@@ -154,7 +208,7 @@ fn errors(input: PathBuf) {
 
         let fm = cm.load_file(&input).unwrap();
 
-        let env = get_env();
+        let env = get_env(&fm.src);
 
         let generator = module_id::ModuleIdGenerator::default();
         let path = Arc::new(FileName::Real(input.to_path_buf()));
@@ -163,12 +217,7 @@ fn errors(input: PathBuf) {
 
         let mut node_id_gen = NodeIdGenerator::default();
         let mut module = {
-            let lexer = Lexer::new(
-                Syntax::Typescript(TsConfig { ..Default::default() }),
-                EsVersion::Es2021,
-                SourceFileInput::from(&*fm),
-                None,
-            );
+            let lexer = Lexer::new(syntax(&input), EsVersion::Es2021, SourceFileInput::from(&*fm), None);
             let mut parser = Parser::new_from(lexer);
 
             parser.parse_module().unwrap()
@@ -215,6 +264,11 @@ fn errors(input: PathBuf) {
     .unwrap_err();
 }
 
+#[fixture("tests/pass-only/**/*.tsx")]
+fn pass_only_tsx(input: PathBuf) {
+    pass_only(input)
+}
+
 #[fixture("tests/pass-only/**/*.ts")]
 fn pass_only(input: PathBuf) {
     testing::run_test2(false, |cm, handler| {
@@ -222,7 +276,7 @@ fn pass_only(input: PathBuf) {
 
         let fm = cm.load_file(&input).unwrap();
 
-        let env = get_env();
+        let env = get_env(&fm.src);
 
         let generator = module_id::ModuleIdGenerator::default();
         let path = Arc::new(FileName::Real(input.to_path_buf()));
@@ -231,12 +285,7 @@ fn pass_only(input: PathBuf) {
 
         let mut node_id_gen = NodeIdGenerator::default();
         let mut module = {
-            let lexer = Lexer::new(
-                Syntax::Typescript(TsConfig { ..Default::default() }),
-                EsVersion::Es2021,
-                SourceFileInput::from(&*fm),
-                None,
-            );
+            let lexer = Lexer::new(syntax(&input), EsVersion::Es2021, SourceFileInput::from(&*fm), None);
             let mut parser = Parser::new_from(lexer);
 
             parser.parse_module().unwrap()
@@ -356,9 +405,15 @@ fn run_test(file_name: PathBuf, for_error: bool) -> Option<NormalizedOutput> {
                 no_unused_parameters: false,
                 strict_function_types: false,
                 strict_null_checks: false,
+                strict_bind_call_apply: false,
+                strict_property_initialization: false,
+                use_unknown_in_catch_variables: false,
+                no_unchecked_indexed_access: false,
+                exact_optional_property_types: false,
                 suppress_excess_property_errors: false,
                 suppress_implicit_any_index_errors: false,
                 use_define_property_for_class_fields: false,
+                experimental_decorators: false,
             };
 
             for line in fm.src.lines() {
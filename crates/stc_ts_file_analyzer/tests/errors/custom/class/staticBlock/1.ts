@@ -0,0 +1,9 @@
+class C {
+    static {
+        this.a;
+    }
+
+    static a = 1;
+}
+
+export { }
@@ -0,0 +1,12 @@
+// @experimentalDecorators: true
+
+function dec(target: any, propertyKey: string, descriptor: PropertyDescriptor): number {
+    return 1;
+}
+
+export class Foo {
+    @dec
+    get x() {
+        return 1;
+    }
+}
@@ -0,0 +1,6 @@
+// @experimentalDecorators: true
+
+function dec(target: string) {}
+
+@dec
+export class Foo {}
@@ -0,0 +1,8 @@
+// @experimentalDecorators: true
+
+function dec(target: any, propertyKey: number) {}
+
+export class Foo {
+    @dec
+    method() {}
+}
@@ -0,0 +1,7 @@
+// @experimentalDecorators: true
+
+function dec(target: any, propertyKey: string, parameterIndex: string) {}
+
+export class Foo {
+    method(@dec x: number) {}
+}
@@ -0,0 +1,8 @@
+// @experimentalDecorators: true
+
+function dec(target: any, propertyKey: string, descriptor: PropertyDescriptor) {}
+
+export class Foo {
+    @dec
+    #method() {}
+}
@@ -0,0 +1,10 @@
+// @experimentalDecorators: false
+
+function dec(target: () => string, context: { kind: "getter" }) {}
+
+export class Foo {
+    @dec
+    get x() {
+        return 1;
+    }
+}
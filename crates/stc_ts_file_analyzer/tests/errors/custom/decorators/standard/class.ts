@@ -0,0 +1,6 @@
+// @experimentalDecorators: false
+
+function dec(target: Function, context: { kind: "method" }) {}
+
+@dec
+export class Foo {}
@@ -0,0 +1,8 @@
+// @experimentalDecorators: false
+
+function dec(target: undefined, context: { kind: "method" }) {}
+
+export class Foo {
+    @dec
+    x = 1;
+}
@@ -0,0 +1,8 @@
+// @experimentalDecorators: false
+
+function dec(target: Function, context: { kind: "field" }) {}
+
+export class Foo {
+    @dec
+    method() {}
+}
@@ -0,0 +1,8 @@
+// @experimentalDecorators: false
+
+function dec(target: (x: string) => void, context: ClassMethodDecoratorContext) {}
+
+export class Foo {
+    @dec
+    #method(x: number) {}
+}
@@ -0,0 +1,8 @@
+// @strictNullChecks: true
+// @exactOptionalPropertyTypes: true
+
+interface Props {
+    name?: string;
+}
+
+export const a: Props = { name: undefined };
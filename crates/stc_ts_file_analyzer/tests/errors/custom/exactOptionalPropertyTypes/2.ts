@@ -0,0 +1,10 @@
+// @strictNullChecks: true
+// @exactOptionalPropertyTypes: true
+
+interface Props {
+    name?: string;
+}
+
+declare const props: Props;
+
+props.name = undefined;
@@ -0,0 +1,8 @@
+// @jsx: react
+
+declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {}
+}
+
+export const a = <></>;
@@ -0,0 +1,10 @@
+// @jsx: preserve
+
+declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {}
+}
+
+declare function List<T>(props: { items: T[]; render: (item: T) => string }): JSX.Element;
+
+export const a = <List<number> items={["a"]} render={(item) => item.toFixed()} />;
@@ -0,0 +1,10 @@
+// @jsx: preserve
+
+declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {
+        div: { id?: string };
+    }
+}
+
+export const a = <div id={1} />;
@@ -0,0 +1,10 @@
+// @jsx: preserve
+
+declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {}
+}
+
+declare function Greeting(props: { name: string }): JSX.Element;
+
+export const a = <Greeting />;
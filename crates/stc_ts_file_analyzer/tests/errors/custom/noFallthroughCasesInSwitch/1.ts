@@ -0,0 +1,13 @@
+// @noFallthroughCasesInSwitch: true
+
+export function f(x: number) {
+    let y = 0;
+    switch (x) {
+        case 0:
+            y = 1;
+        case 1:
+            y = 2;
+            break;
+    }
+    return y;
+}
@@ -0,0 +1,15 @@
+// @noFallthroughCasesInSwitch: true
+
+export function f(x: number) {
+    let y = 0;
+    switch (x) {
+        case 0:
+            for (;;) {
+                break;
+            }
+        case 1:
+            y = 2;
+            break;
+    }
+    return y;
+}
@@ -0,0 +1,7 @@
+// @noImplicitReturns: true
+
+export function f(x: number): number {
+    if (x > 0) {
+        return 1;
+    }
+}
@@ -0,0 +1,10 @@
+// @noImplicitReturns: true
+
+export function f(x: number): number {
+    label: {
+        if (x > 0) {
+            break label;
+        }
+        return 1;
+    }
+}
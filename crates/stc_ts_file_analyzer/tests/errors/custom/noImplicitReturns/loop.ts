@@ -0,0 +1,9 @@
+// @noImplicitReturns: true
+
+export function f(xs: number[]): number {
+    for (const x of xs) {
+        if (x > 0) {
+            return x;
+        }
+    }
+}
@@ -0,0 +1,7 @@
+// @noImplicitReturns: true
+
+export function f(x: string): number {
+    try {
+        return JSON.parse(x);
+    } catch (e) {}
+}
@@ -0,0 +1,6 @@
+// @strictNullChecks: true
+// @noUncheckedIndexedAccess: true
+
+declare const record: { [key: string]: number };
+
+export const a: number = record["a"];
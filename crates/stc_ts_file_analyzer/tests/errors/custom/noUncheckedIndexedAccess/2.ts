@@ -0,0 +1,6 @@
+// @strictNullChecks: true
+// @noUncheckedIndexedAccess: true
+
+declare const arr: number[];
+
+export const a: number = arr[0];
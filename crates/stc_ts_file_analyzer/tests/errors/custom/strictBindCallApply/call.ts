@@ -0,0 +1,7 @@
+// @strictBindCallApply: true
+
+function add(a: number, b: number): number {
+    return a + b;
+}
+
+add.call(undefined, "1", 2);
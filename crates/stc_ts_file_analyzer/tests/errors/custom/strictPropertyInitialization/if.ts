@@ -0,0 +1,12 @@
+// @strictNullChecks: true
+// @strictPropertyInitialization: true
+
+export class A {
+    a: number;
+
+    constructor(cond: boolean) {
+        if (cond) {
+            this.a = 1;
+        }
+    }
+}
@@ -0,0 +1,12 @@
+// @strictNullChecks: true
+// @strictPropertyInitialization: true
+
+export class A {
+    a: number;
+
+    constructor(items: number[]) {
+        for (const item of items) {
+            this.a = item;
+        }
+    }
+}
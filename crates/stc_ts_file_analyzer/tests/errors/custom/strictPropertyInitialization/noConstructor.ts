@@ -0,0 +1,6 @@
+// @strictNullChecks: true
+// @strictPropertyInitialization: true
+
+export class A {
+    a: number;
+}
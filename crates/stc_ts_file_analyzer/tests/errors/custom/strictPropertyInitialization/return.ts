@@ -0,0 +1,13 @@
+// @strictNullChecks: true
+// @strictPropertyInitialization: true
+
+export class A {
+    a: number;
+
+    constructor(cond: boolean) {
+        if (cond) {
+            return;
+        }
+        this.a = 1;
+    }
+}
@@ -0,0 +1,12 @@
+// @strictNullChecks: true
+// @strictPropertyInitialization: true
+
+export class A {
+    a: number;
+
+    constructor() {
+        try {
+            this.a = JSON.parse("1");
+        } catch (e) {}
+    }
+}
@@ -0,0 +1,5 @@
+// @noUnusedLocals: true
+
+export function f() {
+    const unused = 1;
+}
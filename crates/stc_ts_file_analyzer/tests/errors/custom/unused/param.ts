@@ -0,0 +1,3 @@
+// @noUnusedParameters: true
+
+export function f(a: number) {}
@@ -0,0 +1,5 @@
+// @noUnusedLocals: true
+
+export class C {
+    private x = 1;
+}
@@ -0,0 +1,5 @@
+// @noUnusedLocals: true
+
+function unused() {}
+
+export {};
@@ -0,0 +1,14 @@
+// @noUnusedLocals: true
+
+class Point {
+    x = 1;
+}
+
+export class C {
+    // Not used by `p.x`, which is a property of another class.
+    private x = 1;
+
+    read(p: Point) {
+        return p.x;
+    }
+}
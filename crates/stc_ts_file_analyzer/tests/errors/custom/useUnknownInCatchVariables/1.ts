@@ -0,0 +1,6 @@
+// @useUnknownInCatchVariables: true
+
+try {
+} catch (e) {
+    e.message;
+}
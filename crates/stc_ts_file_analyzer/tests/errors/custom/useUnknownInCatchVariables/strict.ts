@@ -0,0 +1,6 @@
+// @strict: true
+
+try {
+} catch (e) {
+    e.message;
+}
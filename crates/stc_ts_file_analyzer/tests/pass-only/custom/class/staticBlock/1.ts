@@ -0,0 +1,18 @@
+class C {
+    static a = 1;
+    static b: number;
+
+    static {
+        this.b = this.a + 1;
+        C.b = C.a;
+    }
+
+    static {
+        const f = () => this.d;
+        f();
+    }
+
+    static d = 'd';
+}
+
+export { }
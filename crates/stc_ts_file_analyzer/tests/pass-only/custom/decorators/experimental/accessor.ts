@@ -0,0 +1,16 @@
+// @experimentalDecorators: true
+
+function configurable(value: boolean) {
+    return function (target: any, propertyKey: string, descriptor: PropertyDescriptor) {
+        descriptor.configurable = value;
+    };
+}
+
+export class Point {
+    private _x = 0;
+
+    @configurable(false)
+    get x() {
+        return this._x;
+    }
+}
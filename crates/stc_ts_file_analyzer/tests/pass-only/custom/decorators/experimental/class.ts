@@ -0,0 +1,17 @@
+// @experimentalDecorators: true
+
+function sealed(constructor: Function) {}
+
+function tag(name: string) {
+    return function (constructor: Function) {};
+}
+
+@sealed
+@tag("greeter")
+export class Greeter {
+    greeting: string;
+
+    constructor(message: string) {
+        this.greeting = message;
+    }
+}
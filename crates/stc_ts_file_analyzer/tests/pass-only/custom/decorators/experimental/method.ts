@@ -0,0 +1,19 @@
+// @experimentalDecorators: true
+
+function enumerable(value: boolean) {
+    return function (target: any, propertyKey: string, descriptor: PropertyDescriptor) {
+        descriptor.enumerable = value;
+    };
+}
+
+export class Greeter {
+    @enumerable(false)
+    greet() {
+        return "Hello";
+    }
+
+    @enumerable(true)
+    static create() {
+        return new Greeter();
+    }
+}
@@ -0,0 +1,9 @@
+// @experimentalDecorators: true
+
+function required(target: Object, propertyKey: string | symbol | undefined, parameterIndex: number) {}
+
+export class BugReport {
+    constructor(@required public title: string) {}
+
+    print(@required verbose: boolean) {}
+}
@@ -0,0 +1,10 @@
+// @experimentalDecorators: true
+
+type TypedPropertyDescriptor<T> = string;
+
+function dec(target: any, propertyKey: string, descriptor: PropertyDescriptor) {}
+
+export class Foo {
+    @dec
+    method() {}
+}
@@ -0,0 +1,19 @@
+// @experimentalDecorators: false
+
+function getter(target: () => number, context: { kind: "getter" }) {}
+
+function setter(target: (value: number) => void, context: { kind: "setter" }) {}
+
+export class Foo {
+    private _x = 0;
+
+    @getter
+    get x() {
+        return this._x;
+    }
+
+    @setter
+    set x(value: number) {
+        this._x = value;
+    }
+}
@@ -0,0 +1,6 @@
+// @experimentalDecorators: false
+
+function register(target: Function, context: { kind: "class"; name: string | undefined }) {}
+
+@register
+export class Foo {}
@@ -0,0 +1,8 @@
+// @experimentalDecorators: false
+
+function field(target: undefined, context: { kind: "field"; name: string | symbol }) {}
+
+export class Foo {
+    @field
+    x = 1;
+}
@@ -0,0 +1,10 @@
+// @experimentalDecorators: false
+
+function logged(target: Function, context: { kind: "method"; name: string | symbol }) {}
+
+export class Foo {
+    @logged
+    greet() {
+        return "Hello";
+    }
+}
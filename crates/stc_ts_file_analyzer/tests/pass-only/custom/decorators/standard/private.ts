@@ -0,0 +1,22 @@
+// @experimentalDecorators: false
+
+function method(target: (x: number) => string, context: ClassMethodDecoratorContext<Foo, (x: number) => string>) {}
+
+function field(target: undefined, context: ClassFieldDecoratorContext<Foo, number>) {}
+
+function getter(target: () => boolean, context: ClassGetterDecoratorContext<Foo, boolean>) {}
+
+export class Foo {
+    @field
+    #count = 1;
+
+    @method
+    #format(x: number) {
+        return `${x}`;
+    }
+
+    @getter
+    get #enabled() {
+        return true;
+    }
+}
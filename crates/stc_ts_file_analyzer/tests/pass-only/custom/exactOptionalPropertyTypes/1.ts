@@ -0,0 +1,14 @@
+// @strictNullChecks: true
+// @exactOptionalPropertyTypes: true
+
+interface Props {
+    name?: string;
+}
+
+interface LooseProps {
+    name?: string | undefined;
+}
+
+export const a: Props = {};
+export const b: Props = { name: "a" };
+export const c: LooseProps = { name: undefined };
@@ -0,0 +1,21 @@
+// @jsx: react
+
+declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {
+        div: {};
+    }
+}
+
+declare const React: {
+    createElement(...args: any[]): JSX.Element;
+    Fragment: any;
+};
+
+export const a = <></>;
+export const b = (
+    <>
+        <div />
+        {"text"}
+    </>
+);
@@ -0,0 +1,11 @@
+// @jsx: preserve
+
+declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {}
+}
+
+declare function List<T>(props: { items: T[]; render: (item: T) => string }): JSX.Element;
+
+export const a = <List items={[1, 2]} render={(item) => item.toFixed()} />;
+export const b = <List<string> items={["a"]} render={(item) => item.toUpperCase()} />;
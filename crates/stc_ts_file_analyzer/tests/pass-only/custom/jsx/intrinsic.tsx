@@ -0,0 +1,16 @@
+// @jsx: preserve
+
+declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {
+        div: { id?: string; hidden?: boolean };
+        input: { value: string };
+    }
+}
+
+export const a = <div />;
+export const b = <div id="a" hidden />;
+export const c = <input value="text" />;
+
+const tag = "div";
+export const d = <tag id="d" />;
@@ -0,0 +1,12 @@
+// @jsx: react-native
+
+declare namespace JSX {
+    interface Element {}
+    interface IntrinsicElements {
+        div: {};
+    }
+}
+
+// `react-native` preserves JSX, so `React` is not required to be in scope.
+export const a = <div />;
+export const b = <></>;
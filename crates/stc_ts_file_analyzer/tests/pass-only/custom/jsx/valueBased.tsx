@@ -0,0 +1,23 @@
+// @jsx: preserve
+
+declare namespace JSX {
+    interface Element {}
+    interface ElementClass {
+        render(): any;
+    }
+    interface ElementAttributesProperty {
+        props: {};
+    }
+    interface IntrinsicElements {}
+}
+
+declare function Greeting(props: { name: string; excited?: boolean }): JSX.Element;
+
+declare class Counter {
+    props: { count: number };
+    render(): null;
+}
+
+export const a = <Greeting name="stc" />;
+export const b = <Greeting name="stc" excited />;
+export const c = <Counter count={1} />;
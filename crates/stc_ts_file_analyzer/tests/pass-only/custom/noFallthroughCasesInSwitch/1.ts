@@ -0,0 +1,28 @@
+// @noFallthroughCasesInSwitch: true
+
+declare function fail(): never;
+
+export function f(x: number) {
+    let y = 0;
+    switch (x) {
+        case 0:
+        case 1:
+            y = 1;
+            break;
+        case 2: {
+            y = 2;
+            break;
+        }
+        case 3:
+            if (y) {
+                return 1;
+            } else {
+                throw new Error();
+            }
+        case 4:
+            fail();
+        default:
+            y = 5;
+    }
+    return y;
+}
@@ -0,0 +1,38 @@
+// @noImplicitReturns: true
+
+export function ifElse(x: number): number {
+    if (x > 0) {
+        return 1;
+    } else {
+        return 2;
+    }
+}
+
+export function switchWithDefault(x: number): number {
+    switch (x) {
+        case 1:
+            return 1;
+        default:
+            return 2;
+    }
+}
+
+export function tryCatch(x: string): number {
+    try {
+        return JSON.parse(x);
+    } catch (e) {
+        throw e;
+    }
+}
+
+export function noValue(x: number) {
+    if (x > 0) {
+        return;
+    }
+}
+
+export function labeled(): number {
+    label: {
+        return 1;
+    }
+}
@@ -0,0 +1,10 @@
+// @noImplicitReturns: true
+
+declare function fail(message: string): never;
+
+export function f(x: number): number {
+    if (x > 0) {
+        return 1;
+    }
+    fail("not positive");
+}
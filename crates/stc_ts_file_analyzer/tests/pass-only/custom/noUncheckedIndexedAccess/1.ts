@@ -0,0 +1,12 @@
+// @strictNullChecks: true
+// @noUncheckedIndexedAccess: true
+
+declare const record: { [key: string]: number };
+declare const arr: number[];
+
+export const a: number | undefined = record["a"];
+export const b: number | undefined = arr[0];
+export const c: number = arr.length;
+
+record["b"] = 1;
+arr[1] = 2;
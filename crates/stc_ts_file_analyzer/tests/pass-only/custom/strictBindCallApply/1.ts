@@ -0,0 +1,8 @@
+// @strict: true
+
+function add(a: number, b: number): number {
+    return a + b;
+}
+
+export const a: number = add.call(undefined, 1, 2);
+export const b: number = add.apply(undefined, [1, 2]);
@@ -0,0 +1,42 @@
+// @strictNullChecks: true
+// @strictPropertyInitialization: true
+
+export class A {
+    a: number;
+    b: number;
+    c: number = 1;
+    d?: number;
+    e!: number;
+    f: number | undefined;
+    g: number;
+    h: number;
+    i: number;
+    j: number;
+
+    constructor(cond: boolean) {
+        this.a = 1;
+
+        if (cond) {
+            this.b = 1;
+        } else {
+            this.b = 2;
+        }
+
+        try {
+            this.g = 1;
+        } catch {
+            this.g = 2;
+        }
+
+        do {
+            this.h = 1;
+        } while (cond);
+
+        cond ? (this.i = 1) : (this.i = 2);
+
+        if (!cond) {
+            throw new Error();
+        }
+        this.j = 1;
+    }
+}
@@ -0,0 +1,22 @@
+// @strictNullChecks: true
+// @strictPropertyInitialization: true
+
+export class A {
+    a: number;
+    b: number;
+
+    constructor(cond: boolean) {
+        if (cond) {
+            this.a = 1;
+            this.b = 1;
+            return;
+        }
+
+        try {
+            this.a = JSON.parse("1");
+        } finally {
+            this.a = 2;
+        }
+        this.b = this.a;
+    }
+}
@@ -0,0 +1,10 @@
+// @strictNullChecks: true
+// @strictPropertyInitialization: true
+
+export class A {
+    a: number;
+
+    constructor() {
+        throw new Error("not supported");
+    }
+}
@@ -0,0 +1,24 @@
+// @noUnusedLocals: true
+// @noUnusedParameters: true
+
+export function add(a: number, _b: number) {
+    const sum = a + 1;
+    return sum;
+}
+
+function helper() {}
+helper();
+
+export class Counter {
+    private count = 0;
+
+    increment(_by: number) {
+        this.count++;
+        return this.count;
+    }
+}
+
+for (const _ of [1, 2]) {
+}
+
+export const { x, ...rest } = { x: 1, y: 2 };
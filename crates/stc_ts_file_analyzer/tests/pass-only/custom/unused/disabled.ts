@@ -0,0 +1,3 @@
+export function f(a: number) {
+    const unused = 1;
+}
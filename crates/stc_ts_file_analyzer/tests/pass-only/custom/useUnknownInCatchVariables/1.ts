@@ -0,0 +1,13 @@
+// @useUnknownInCatchVariables: true
+
+try {
+} catch (e) {
+    if (e instanceof Error) {
+        e.message;
+    }
+}
+
+try {
+} catch (e: any) {
+    e.message;
+}
@@ -0,0 +1,91 @@
+//! Results of the last type check of open documents, used to answer requests
+//! like hover.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use fxhash::FxHashMap;
+use parking_lot::RwLock;
+use stc_ts_env::Env;
+use stc_ts_file_analyzer::{symbol_table::SymbolTable, type_table::TypeTable};
+use swc_common::{BytePos, FileName, SourceMap, Span};
+use tower_lsp::lsp_types::{Location, Range, Url};
+
+use crate::text::position_at;
+
+pub(crate) struct Analysis {
+    /// Version of the document which is analyzed.
+    pub version: i32,
+    pub text: Arc<String>,
+    /// Start of the document in the source map used to check it.
+    pub start_pos: BytePos,
+    /// Shared by all documents checked together.
+    pub type_table: TypeTable,
+    /// Shared by all documents checked together.
+    pub symbol_table: SymbolTable,
+    pub env: Env,
+    /// Source map used to check the document, which contains dependencies.
+    pub cm: Arc<SourceMap>,
+}
+
+impl Analysis {
+    /// Converts a byte offset in the document to a position in the source map.
+    pub fn pos(&self, offset: usize) -> BytePos {
+        self.start_pos + BytePos(offset as u32)
+    }
+
+    /// Converts a position in the source map to a byte offset in the document.
+    pub fn offset(&self, pos: BytePos) -> usize {
+        (pos - self.start_pos).0 as usize
+    }
+
+    /// Returns `true` if `span` is in the document.
+    pub fn contains(&self, span: Span) -> bool {
+        self.start_pos <= span.lo && span.hi <= self.pos(self.text.len())
+    }
+
+    /// Returns the location of `span`, which may be in another file.
+    pub fn location(&self, span: Span) -> Option<Location> {
+        let file = self.cm.lookup_source_file(span.lo);
+        let uri = match &file.name {
+            FileName::Real(path) => Url::from_file_path(path).ok()?,
+            _ => return None,
+        };
+
+        Some(Location {
+            uri,
+            range: Range {
+                start: position_at(&file.src, (span.lo - file.start_pos).0 as usize),
+                end: position_at(&file.src, (span.hi - file.start_pos).0 as usize),
+            },
+        })
+    }
+}
+
+/// Analyses keyed by the path of documents.
+#[derive(Default)]
+pub(crate) struct Analyses {
+    docs: RwLock<FxHashMap<PathBuf, Arc<Analysis>>>,
+}
+
+impl Analyses {
+    pub fn insert(&self, path: PathBuf, analysis: Analysis) {
+        self.docs.write().insert(path, Arc::new(analysis));
+    }
+
+    pub fn remove(&self, path: &Path) {
+        self.docs.write().remove(path);
+    }
+
+    /// Returns analyses of all documents.
+    pub fn all(&self) -> Vec<Arc<Analysis>> {
+        self.docs.read().values().cloned().collect()
+    }
+
+    /// Returns the analysis of `path` only if it's up to date.
+    pub fn get(&self, path: &Path, version: i32) -> Option<Arc<Analysis>> {
+        self.docs.read().get(path).filter(|analysis| analysis.version == version).cloned()
+    }
+}
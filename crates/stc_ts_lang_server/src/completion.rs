@@ -0,0 +1,228 @@
+//! `textDocument/completion`.
+
+use std::{fs, path::Path, sync::Arc};
+
+use anyhow::Error;
+use stc_ts_env::Env;
+use stc_ts_errors::type_to_string;
+use stc_ts_file_analyzer::{
+    completion::{Completion, CompletionKind, Completions},
+    symbol_table::SymbolKind,
+};
+use swc_common::{FileName, GLOBALS};
+use swc_ecma_loader::resolve::Resolve;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, CompletionTextEdit, Position, Range, TextEdit};
+
+use crate::{
+    diagnostics::Projects,
+    overlay::Overlay,
+    text::{offset_at, position_at},
+};
+
+/// Inserted if nothing is typed yet, so the identifier being completed is
+/// parsed.
+const PLACEHOLDER: &str = "__stc_completion__";
+
+/// Lists members after `.`, module specifiers in imports, and variables and
+/// types in scope otherwise.
+pub(crate) fn completion(projects: &Projects, overlay: &Arc<Overlay>, path: &Path, pos: Position) -> Result<Vec<CompletionItem>, Error> {
+    let doc = match overlay.get(path) {
+        Some(doc) => doc,
+        None => return Ok(vec![]),
+    };
+    let text = &*doc.text;
+    let offset = offset_at(text, pos);
+    let project = projects.for_file(path)?;
+
+    if let Some(start) = module_specifier_start(text, offset) {
+        return Ok(complete_module_specifiers(&*project.resolver(overlay), path, text, start, offset));
+    }
+
+    let start = text[..offset]
+        .trim_end_matches(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+        .len();
+    let is_member = text[..start].ends_with('.');
+
+    let mut patched = text.to_string();
+    if start == offset {
+        patched.insert_str(offset, PLACEHOLDER);
+    }
+
+    let name = FileName::Real(path.to_path_buf());
+    let completions = Completions::new(name.clone(), start);
+
+    let items = project.with_session(overlay, |session| {
+        session.check_patched(overlay, path, patched, completions.clone(), |checker, symbol_table| {
+            let mut items = completions.take()?;
+
+            // Imports are stored in the module, not in the scope.
+            if !is_member {
+                if let Some(file) = checker.source_file(&Arc::new(name)) {
+                    items.extend(
+                        symbol_table
+                            .symbols()
+                            .into_iter()
+                            .filter(|symbol| symbol.kind == SymbolKind::Import)
+                            .filter(|symbol| file.start_pos <= symbol.span.lo && symbol.span.hi <= file.end_pos)
+                            .filter(|symbol| items.iter().all(|item| item.name != *symbol.id.sym()))
+                            .map(|symbol| Completion {
+                                name: symbol.id.sym().clone(),
+                                kind: CompletionKind::Var,
+                                ty: None,
+                            })
+                            .collect::<Vec<_>>(),
+                    );
+                }
+            }
+
+            Some(items)
+        })
+    });
+
+    let items = match items {
+        Some(items) => items,
+        None => return Ok(vec![]),
+    };
+
+    Ok(items
+        .into_iter()
+        .filter(|item| &*item.name != PLACEHOLDER)
+        .map(|item| completion_item(&project.env, item))
+        .collect())
+}
+
+fn completion_item(env: &Env, item: Completion) -> CompletionItem {
+    let kind = match item.kind {
+        CompletionKind::Property => CompletionItemKind::PROPERTY,
+        CompletionKind::Method => CompletionItemKind::METHOD,
+        CompletionKind::Var => CompletionItemKind::VARIABLE,
+        CompletionKind::Function => CompletionItemKind::FUNCTION,
+        CompletionKind::Class => CompletionItemKind::CLASS,
+        CompletionKind::Enum => CompletionItemKind::ENUM,
+        CompletionKind::Type => CompletionItemKind::INTERFACE,
+    };
+
+    CompletionItem {
+        label: item.name.to_string(),
+        kind: Some(kind),
+        detail: item.ty.map(|ty| GLOBALS.set(env.shared().swc_globals(), || type_to_string(&ty))),
+        ..Default::default()
+    }
+}
+
+/// Returns the start of the module specifier being written at `offset`, if
+/// any.
+fn module_specifier_start(text: &str, offset: usize) -> Option<usize> {
+    let line_start = text[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+    let line = &text[line_start..offset];
+
+    let quote = line.rfind(|c| c == '\'' || c == '"')?;
+    let before = line[..quote].trim_end();
+
+    let is_specifier = ["from", "import", "require(", "import("]
+        .iter()
+        .any(|keyword| before.ends_with(keyword));
+
+    is_specifier.then(|| line_start + quote + 1)
+}
+
+fn complete_module_specifiers(resolver: &dyn Resolve, path: &Path, text: &str, start: usize, offset: usize) -> Vec<CompletionItem> {
+    let prefix = &text[start..offset];
+    let dir = match path.parent() {
+        Some(dir) => dir,
+        None => return vec![],
+    };
+
+    let mut specifiers = vec![];
+
+    if prefix.starts_with('.') {
+        match prefix.rsplit_once('/') {
+            Some((parent, _)) => {
+                for (name, is_dir) in read_dir(&dir.join(parent)) {
+                    if is_dir {
+                        if name != "node_modules" && !name.starts_with('.') {
+                            specifiers.push((format!("{}/{}", parent, name), CompletionItemKind::FOLDER));
+                        }
+                    } else if let Some(stem) = strip_ts_extension(&name) {
+                        if dir.join(parent).join(&name) != path {
+                            specifiers.push((format!("{}/{}", parent, stem), CompletionItemKind::FILE));
+                        }
+                    }
+                }
+            }
+            None => {
+                specifiers.push(("./".to_string(), CompletionItemKind::FOLDER));
+                specifiers.push(("../".to_string(), CompletionItemKind::FOLDER));
+            }
+        }
+    } else {
+        // Packages in `node_modules` of ancestor directories.
+        for dir in dir.ancestors() {
+            let node_modules = dir.join("node_modules");
+
+            for (name, is_dir) in read_dir(&node_modules) {
+                if !is_dir || name.starts_with('.') {
+                    continue;
+                }
+
+                if name == "@types" {
+                    for (name, _) in read_dir(&node_modules.join("@types")) {
+                        // `@types/scope__name` is the typings of `@scope/name`.
+                        let name = match name.split_once("__") {
+                            Some((scope, name)) => format!("@{}/{}", scope, name),
+                            None => name,
+                        };
+                        specifiers.push((name, CompletionItemKind::MODULE));
+                    }
+                } else if name.starts_with('@') {
+                    for (pkg, _) in read_dir(&node_modules.join(&name)) {
+                        specifiers.push((format!("{}/{}", name, pkg), CompletionItemKind::MODULE));
+                    }
+                } else {
+                    specifiers.push((name, CompletionItemKind::MODULE));
+                }
+            }
+        }
+    }
+
+    specifiers.sort_by(|a, b| a.0.cmp(&b.0));
+    specifiers.dedup_by(|a, b| a.0 == b.0);
+
+    let base = FileName::Real(path.to_path_buf());
+    let range = Range {
+        start: position_at(text, start),
+        end: position_at(text, offset),
+    };
+
+    specifiers
+        .into_iter()
+        .filter(|(specifier, _)| specifier.starts_with(prefix))
+        .filter(|(specifier, kind)| *kind == CompletionItemKind::FOLDER || resolver.resolve(&base, specifier).is_ok())
+        .map(|(specifier, kind)| CompletionItem {
+            label: specifier.clone(),
+            kind: Some(kind),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range,
+                new_text: specifier,
+            })),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Returns names of entries in `dir` and whether they are directories.
+fn read_dir(dir: &Path) -> Vec<(String, bool)> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(..) => return vec![],
+    };
+
+    entries
+        .flatten()
+        .map(|entry| (entry.file_name().to_string_lossy().into_owned(), entry.path().is_dir()))
+        .collect()
+}
+
+fn strip_ts_extension(name: &str) -> Option<&str> {
+    [".d.ts", ".tsx", ".ts"].iter().find_map(|ext| name.strip_suffix(ext))
+}
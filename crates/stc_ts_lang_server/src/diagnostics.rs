@@ -0,0 +1,365 @@
+//! Type checking of open documents.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{Context, Error};
+use fxhash::FxHashMap;
+use parking_lot::Mutex;
+use stc_ts_config::{find_config, CompilerOptions, ProjectConfig};
+use stc_ts_env::Env;
+use stc_ts_errors::ErrorKind;
+use stc_ts_file_analyzer::{completion::Completions, env::EnvFactory, symbol_table::SymbolTable, type_table::TypeTable};
+use stc_ts_module_loader::resolvers::{
+    node::{ModuleResolution, NodeResolver},
+    paths::PathsResolver,
+};
+use stc_ts_type_checker::Checker;
+use swc_common::{errors::Handler, FileName, FilePathMapping, SourceMap, Spanned};
+use swc_ecma_loader::resolve::Resolve;
+use swc_ecma_parser::TsConfig;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, PublishDiagnosticsParams, Range};
+
+use crate::{
+    analysis::{Analyses, Analysis},
+    overlay::{Document, Overlay, OverlayLoader, OverlayResolver},
+    text::position_at,
+};
+
+/// Compiler options and typings of a `tsconfig.json`.
+pub(crate) struct Project {
+    pub env: Env,
+    parser_config: TsConfig,
+    module_resolution: ModuleResolution,
+    opts: CompilerOptions,
+    /// Created by the first type check of open documents.
+    session: Mutex<Option<Session>>,
+}
+
+impl Project {
+    /// `dir` is used as the base directory if there's no `tsconfig.json`.
+    fn load(config_path: Option<&Path>, dir: &Path) -> Result<Self, Error> {
+        let (base_dir, opts) = match config_path {
+            Some(path) => {
+                let config = ProjectConfig::load(path).with_context(|| format!("failed to load project at `{}`", path.display()))?;
+                (config.dir().to_path_buf(), config.compiler_options)
+            }
+            None => (dir.to_path_buf(), CompilerOptions::default()),
+        };
+
+        let libs = opts.libs()?;
+        let module_resolution = opts.module_resolution()?;
+        let env = Env::simple(opts.rule(), opts.target()?, opts.module()?, &libs).with_jsx(opts.jsx()?);
+
+        let cm = Arc::new(SourceMap::default());
+        let checker = Checker::new(
+            cm.clone(),
+            silent_handler(cm),
+            env.clone(),
+            opts.parser_config(),
+            None,
+            Arc::new(paths_resolver(
+                &opts,
+                NodeResolver::default().with_module_resolution(module_resolution),
+            )),
+        );
+        checker.load_typings(&base_dir, opts.type_roots.as_deref(), opts.types.as_deref());
+
+        Ok(Project {
+            env,
+            parser_config: opts.parser_config(),
+            module_resolution,
+            opts,
+            session: Default::default(),
+        })
+    }
+
+    /// Creates a checker which reads open documents from `overlay`.
+    fn checker(&self, overlay: &Arc<Overlay>) -> (Arc<SourceMap>, Checker) {
+        let cm = Arc::new(SourceMap::with_file_loader(
+            Box::new(OverlayLoader::new(overlay.clone())),
+            FilePathMapping::empty(),
+        ));
+        let checker = Checker::new(
+            cm.clone(),
+            silent_handler(cm.clone()),
+            self.env.clone(),
+            self.parser_config,
+            None,
+            self.resolver(overlay),
+        );
+
+        (cm, checker)
+    }
+
+    /// Resolves imports like the checker of the project.
+    pub fn resolver(&self, overlay: &Arc<Overlay>) -> Arc<dyn Resolve> {
+        Arc::new(paths_resolver(
+            &self.opts,
+            OverlayResolver::new(
+                overlay.clone(),
+                Arc::new(NodeResolver::default().with_module_resolution(self.module_resolution)),
+            ),
+        ))
+    }
+
+    /// Calls `op` with the [Session] of the project, which is created by the
+    /// first call.
+    pub fn with_session<F, Ret>(&self, overlay: &Arc<Overlay>, op: F) -> Ret
+    where
+        F: FnOnce(&mut Session) -> Ret,
+    {
+        let mut session = self.session.lock();
+
+        op(session.get_or_insert_with(|| Session::new(self, overlay)))
+    }
+}
+
+fn paths_resolver<R>(opts: &CompilerOptions, inner: R) -> PathsResolver<R>
+where
+    R: Resolve,
+{
+    PathsResolver::new(inner)
+        .with_base_url(opts.base_url.clone())
+        .with_paths(opts.path_mappings())
+        .with_root_dirs(opts.root_dirs.clone().unwrap_or_default())
+}
+
+/// A [Checker] kept alive across edits, so only changed documents and modules
+/// depending on them are analyzed again.
+pub(crate) struct Session {
+    cm: Arc<SourceMap>,
+    checker: Checker,
+    type_table: TypeTable,
+    symbol_table: SymbolTable,
+    /// Texts of documents as of the last type check. A change of a document
+    /// replaces its text, so it's compared by pointer.
+    texts: FxHashMap<PathBuf, Arc<String>>,
+}
+
+impl Session {
+    fn new(project: &Project, overlay: &Arc<Overlay>) -> Self {
+        let type_table = TypeTable::default();
+        let symbol_table = SymbolTable::default();
+        let (cm, checker) = project.checker(overlay);
+        let checker = checker.with_type_table(type_table.clone()).with_symbol_table(symbol_table.clone());
+
+        Session {
+            cm,
+            checker,
+            type_table,
+            symbol_table,
+            texts: Default::default(),
+        }
+    }
+
+    /// Invalidates documents changed, opened or closed since the last type
+    /// check. Closed documents are read from the disk again.
+    fn invalidate(&mut self, docs: &[(PathBuf, Document)]) {
+        let mut changed = self
+            .texts
+            .keys()
+            .filter(|path| docs.iter().all(|(p, _)| p != *path))
+            .cloned()
+            .collect::<Vec<_>>();
+        changed.extend(
+            docs.iter()
+                .filter(|(path, doc)| self.texts.get(path).map_or(true, |text| !Arc::ptr_eq(text, &doc.text)))
+                .map(|(path, _)| path.clone()),
+        );
+
+        self.texts = docs.iter().map(|(path, doc)| (path.clone(), doc.text.clone())).collect();
+
+        if !changed.is_empty() {
+            let changed = changed.into_iter().map(|path| Arc::new(FileName::Real(path))).collect::<Vec<_>>();
+            self.checker.invalidate(&changed);
+        }
+    }
+
+    /// Analyzes `path` as if its content is `text` while collecting
+    /// `completions`, and calls `op` with the results.
+    ///
+    /// The module is analyzed again by the next type check, with the content
+    /// of the document.
+    pub fn check_patched<F, Ret>(&mut self, overlay: &Overlay, path: &Path, text: String, completions: Completions, op: F) -> Ret
+    where
+        F: FnOnce(&Checker, &SymbolTable) -> Ret,
+    {
+        // Documents of this session which are changed since the last type check.
+        let docs = overlay
+            .documents()
+            .into_iter()
+            .filter(|(path, _)| self.texts.contains_key(path))
+            .collect::<Vec<_>>();
+        self.invalidate(&docs);
+
+        let name = Arc::new(FileName::Real(path.to_path_buf()));
+        self.checker.invalidate(&[name.clone()]);
+
+        overlay.patch(path, text);
+        self.checker.set_completions(Some(completions));
+        self.checker.check(name.clone());
+        self.checker.set_completions(None);
+        overlay.unpatch();
+
+        let ret = op(&self.checker, &self.symbol_table);
+
+        self.checker.invalidate(&[name]);
+
+        ret
+    }
+}
+
+/// Projects keyed by the path of `tsconfig.json`.
+#[derive(Default)]
+pub(crate) struct Projects {
+    projects: Mutex<FxHashMap<Option<PathBuf>, Arc<Project>>>,
+}
+
+impl Projects {
+    fn get(&self, config_path: Option<&Path>, dir: &Path) -> Result<Arc<Project>, Error> {
+        let key = config_path.map(Path::to_path_buf);
+
+        if let Some(project) = self.projects.lock().get(&key) {
+            return Ok(project.clone());
+        }
+
+        let project = Arc::new(Project::load(config_path, dir)?);
+        self.projects.lock().insert(key, project.clone());
+
+        Ok(project)
+    }
+
+    /// Returns the project containing `path`.
+    pub fn for_file(&self, path: &Path) -> Result<Arc<Project>, Error> {
+        let dir = path.parent().unwrap_or_else(|| Path::new("/"));
+
+        self.get(find_config(dir).as_deref(), dir)
+    }
+}
+
+/// Type checks all open documents, and returns diagnostics for each of them.
+/// Results of the analysis are stored to `analyses`.
+///
+/// Documents are grouped by `tsconfig.json`, and each group is checked using
+/// one [Checker] so dependencies are analyzed only once.
+pub(crate) fn check_documents(
+    projects: &Projects,
+    overlay: &Arc<Overlay>,
+    analyses: &Analyses,
+) -> Vec<Result<PublishDiagnosticsParams, Error>> {
+    let mut groups = FxHashMap::<Option<PathBuf>, Vec<(PathBuf, Document)>>::default();
+    for (path, doc) in overlay.documents() {
+        let config_path = path.parent().and_then(find_config);
+        groups.entry(config_path).or_default().push((path, doc));
+    }
+
+    let mut results = vec![];
+
+    for (config_path, docs) in groups {
+        let dir = docs[0].0.parent().unwrap_or_else(|| Path::new("/")).to_path_buf();
+
+        match projects.get(config_path.as_deref(), &dir) {
+            Ok(project) => results.extend(
+                project
+                    .with_session(overlay, |session| check_project(&project, session, analyses, docs))
+                    .into_iter()
+                    .map(Ok),
+            ),
+            Err(err) => results.push(Err(err)),
+        }
+    }
+
+    results
+}
+
+fn check_project(
+    project: &Project,
+    session: &mut Session,
+    analyses: &Analyses,
+    docs: Vec<(PathBuf, Document)>,
+) -> Vec<PublishDiagnosticsParams> {
+    session.invalidate(&docs);
+
+    let Session {
+        cm,
+        checker,
+        type_table,
+        symbol_table,
+        ..
+    } = &*session;
+
+    for (path, _) in &docs {
+        checker.check(Arc::new(FileName::Real(path.clone())));
+    }
+
+    let errors = ErrorKind::flatten(checker.errors());
+
+    checker.run(|| {
+        docs.into_iter()
+            .map(|(path, doc)| {
+                let name = FileName::Real(path.clone());
+
+                if let Some(file) = checker.source_file(&Arc::new(name.clone())) {
+                    analyses.insert(
+                        path,
+                        Analysis {
+                            version: doc.version,
+                            text: file.src.clone(),
+                            start_pos: file.start_pos,
+                            type_table: type_table.clone(),
+                            symbol_table: symbol_table.clone(),
+                            env: project.env.clone(),
+                            cm: cm.clone(),
+                        },
+                    );
+                }
+
+                let diagnostics = errors
+                    .iter()
+                    .filter_map(|err| {
+                        let span = err.span();
+
+                        // Errors for invalid options do not have a location.
+                        let range = if span.is_dummy() {
+                            Range::default()
+                        } else {
+                            let file = cm.lookup_source_file(span.lo);
+                            if file.name != name {
+                                return None;
+                            }
+
+                            Range {
+                                start: position_at(&file.src, (span.lo - file.start_pos).0 as usize),
+                                end: position_at(&file.src, (span.hi - file.start_pos).0 as usize),
+                            }
+                        };
+
+                        Some(Diagnostic {
+                            range,
+                            severity: Some(DiagnosticSeverity::ERROR),
+                            code: Some(NumberOrString::Number(ErrorKind::normalize_error_code(err.code()) as i32)),
+                            source: Some("stc".into()),
+                            message: err.message(),
+                            ..Default::default()
+                        })
+                    })
+                    .collect();
+
+                PublishDiagnosticsParams {
+                    uri: doc.uri,
+                    diagnostics,
+                    version: Some(doc.version),
+                }
+            })
+            .collect()
+    })
+}
+
+/// Errors are published as diagnostics instead of being printed.
+fn silent_handler(cm: Arc<SourceMap>) -> Arc<Handler> {
+    Arc::new(Handler::with_emitter_writer(Box::new(io::sink()), Some(cm)))
+}
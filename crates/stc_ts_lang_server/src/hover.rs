@@ -0,0 +1,30 @@
+//! `textDocument/hover`.
+
+use stc_ts_errors::type_to_string;
+use swc_common::GLOBALS;
+use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position, Range};
+
+use crate::{
+    analysis::Analysis,
+    text::{offset_at, position_at},
+};
+
+/// Shows the type of the innermost expression or declared identifier at
+/// `pos`.
+pub(crate) fn hover(analysis: &Analysis, pos: Position) -> Option<Hover> {
+    let offset = offset_at(&analysis.text, pos);
+    let (span, ty) = analysis.type_table.type_at(analysis.pos(offset))?;
+
+    let ty = GLOBALS.set(analysis.env.shared().swc_globals(), || type_to_string(&ty));
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!("```typescript\n{}\n```", ty),
+        }),
+        range: Some(Range {
+            start: position_at(&analysis.text, analysis.offset(span.lo)),
+            end: position_at(&analysis.text, analysis.offset(span.hi)),
+        }),
+    })
+}
@@ -1,3 +1,5 @@
+use std::{sync::Arc, time::Duration};
+
 use clap::Args;
 use tower_lsp::{
     async_trait,
@@ -7,6 +9,24 @@ use tower_lsp::{
 };
 use tracing::info;
 
+use crate::{
+    analysis::{Analyses, Analysis},
+    diagnostics::{check_documents, Projects},
+    overlay::{Document, Overlay},
+};
+
+mod analysis;
+mod completion;
+mod diagnostics;
+mod hover;
+mod navigation;
+mod overlay;
+mod text;
+
+/// Changes in this duration are type checked at once, instead of on every
+/// keystroke.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
 #[derive(Debug, Args)]
 pub struct LspCommand {}
 
@@ -17,7 +37,7 @@ impl LspCommand {
         let stdin = tokio::io::stdin();
         let stdout = tokio::io::stdout();
 
-        let (service, socket) = LspService::new(|client| StcLangServer { client });
+        let (service, socket) = LspService::new(StcLangServer::new);
         Server::new(stdin, stdout, socket).serve(service).await;
 
         Ok(())
@@ -25,15 +45,83 @@ impl LspCommand {
 }
 
 pub struct StcLangServer {
-    #[allow(unused)]
     client: Client,
+    overlay: Arc<Overlay>,
+    projects: Arc<Projects>,
+    analyses: Arc<Analyses>,
+}
+
+impl StcLangServer {
+    fn new(client: Client) -> Self {
+        Self {
+            client,
+            overlay: Default::default(),
+            projects: Default::default(),
+            analyses: Default::default(),
+        }
+    }
+
+    /// Returns the analysis of an open document, if it's up to date.
+    fn analysis(&self, uri: &Url) -> Option<Arc<Analysis>> {
+        let path = uri.to_file_path().ok()?;
+        let doc = self.overlay.get(&path)?;
+
+        self.analyses.get(&path, doc.version)
+    }
+
+    /// Type checks all open documents and publishes diagnostics.
+    async fn publish_diagnostics(&self) {
+        let overlay = self.overlay.clone();
+        let projects = self.projects.clone();
+        let analyses = self.analyses.clone();
+
+        let results = match tokio::task::spawn_blocking(move || check_documents(&projects, &overlay, &analyses)).await {
+            Ok(results) => results,
+            Err(err) => {
+                self.client
+                    .log_message(MessageType::ERROR, format!("type checker panicked: {:?}", err))
+                    .await;
+                return;
+            }
+        };
+
+        for res in results {
+            match res {
+                Ok(params) => {
+                    // Skip outdated diagnostics.
+                    if let Some(doc) = params.uri.to_file_path().ok().and_then(|path| self.overlay.get(&path)) {
+                        if Some(doc.version) != params.version {
+                            continue;
+                        }
+                    }
+
+                    self.client
+                        .publish_diagnostics(params.uri, params.diagnostics, params.version)
+                        .await
+                }
+                Err(err) => self.client.log_message(MessageType::ERROR, format!("{:?}", err)).await,
+            }
+        }
+    }
 }
 
 #[async_trait]
 impl LanguageServer for StcLangServer {
     async fn initialize(&self, _params: InitializeParams) -> jsonrpc::Result<InitializeResult> {
         Ok(InitializeResult {
-            capabilities: ServerCapabilities { ..Default::default() },
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::INCREMENTAL)),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec![".".into(), "/".into(), "'".into(), "\"".into()]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
             server_info: Some(ServerInfo {
                 name: "stc-ts-lsp".to_string(),
                 version: Some(env!("CARGO_PKG_VERSION").to_string()),
@@ -45,10 +133,131 @@ impl LanguageServer for StcLangServer {
         Ok(())
     }
 
-    async fn hover(&self, _params: HoverParams) -> jsonrpc::Result<Option<Hover>> {
-        Ok(Some(Hover {
-            contents: HoverContents::Scalar(MarkedString::String("hover test".to_string())),
-            range: None,
-        }))
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let doc = params.text_document;
+        let path = match doc.uri.to_file_path() {
+            Ok(path) => path,
+            Err(()) => return,
+        };
+
+        self.overlay.open(
+            path,
+            Document {
+                uri: doc.uri,
+                version: doc.version,
+                text: Arc::new(doc.text),
+            },
+        );
+
+        self.publish_diagnostics().await;
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let path = match params.text_document.uri.to_file_path() {
+            Ok(path) => path,
+            Err(()) => return,
+        };
+
+        let version = params.text_document.version;
+        self.overlay.change(&path, version, params.content_changes);
+
+        tokio::time::sleep(DEBOUNCE).await;
+
+        // Diagnostics are published by the handler of the newer change.
+        if self.overlay.get(&path).map_or(true, |doc| doc.version != version) {
+            return;
+        }
+
+        self.publish_diagnostics().await;
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let path = match params.text_document.uri.to_file_path() {
+            Ok(path) => path,
+            Err(()) => return,
+        };
+
+        if self.overlay.close(&path).is_some() {
+            self.analyses.remove(&path);
+
+            // Diagnostics of a closed document are not updated anymore.
+            self.client.publish_diagnostics(params.text_document.uri, vec![], None).await;
+            self.publish_diagnostics().await;
+        }
+    }
+
+    async fn hover(&self, params: HoverParams) -> jsonrpc::Result<Option<Hover>> {
+        let params = params.text_document_position_params;
+        let analysis = match self.analysis(&params.text_document.uri) {
+            Some(analysis) => analysis,
+            None => return Ok(None),
+        };
+
+        Ok(hover::hover(&analysis, params.position))
+    }
+
+    async fn completion(&self, params: CompletionParams) -> jsonrpc::Result<Option<CompletionResponse>> {
+        let position = params.text_document_position;
+        let path = match position.text_document.uri.to_file_path() {
+            Ok(path) => path,
+            Err(()) => return Ok(None),
+        };
+
+        let overlay = self.overlay.clone();
+        let projects = self.projects.clone();
+
+        match tokio::task::spawn_blocking(move || completion::completion(&projects, &overlay, &path, position.position)).await {
+            Ok(Ok(items)) => Ok(Some(CompletionResponse::Array(items))),
+            Ok(Err(err)) => {
+                self.client.log_message(MessageType::ERROR, format!("{:?}", err)).await;
+                Ok(None)
+            }
+            Err(err) => {
+                self.client
+                    .log_message(MessageType::ERROR, format!("type checker panicked: {:?}", err))
+                    .await;
+                Ok(None)
+            }
+        }
+    }
+
+    async fn goto_definition(&self, params: GotoDefinitionParams) -> jsonrpc::Result<Option<GotoDefinitionResponse>> {
+        let params = params.text_document_position_params;
+        let analysis = match self.analysis(&params.text_document.uri) {
+            Some(analysis) => analysis,
+            None => return Ok(None),
+        };
+
+        Ok(Some(GotoDefinitionResponse::Array(navigation::definition(
+            &analysis,
+            params.position,
+        ))))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> jsonrpc::Result<Option<Vec<Location>>> {
+        let position = params.text_document_position;
+        let analysis = match self.analysis(&position.text_document.uri) {
+            Some(analysis) => analysis,
+            None => return Ok(None),
+        };
+
+        Ok(Some(navigation::references(
+            &analysis,
+            position.position,
+            params.context.include_declaration,
+        )))
+    }
+
+    async fn document_symbol(&self, params: DocumentSymbolParams) -> jsonrpc::Result<Option<DocumentSymbolResponse>> {
+        let analysis = match self.analysis(&params.text_document.uri) {
+            Some(analysis) => analysis,
+            None => return Ok(None),
+        };
+
+        Ok(Some(DocumentSymbolResponse::Flat(navigation::document_symbols(&analysis))))
+    }
+
+    async fn symbol(&self, params: WorkspaceSymbolParams) -> jsonrpc::Result<Option<Vec<SymbolInformation>>> {
+        Ok(Some(navigation::workspace_symbols(&self.analyses.all(), &params.query)))
     }
 }
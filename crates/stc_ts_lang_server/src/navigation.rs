@@ -0,0 +1,117 @@
+//! `textDocument/definition`, `textDocument/references`,
+//! `textDocument/documentSymbol` and `workspace/symbol`.
+
+use std::sync::Arc;
+
+use fxhash::FxHashSet;
+use stc_ts_file_analyzer::symbol_table::{Symbol, SymbolKind as Kind};
+use tower_lsp::lsp_types::{Location, Position, SymbolInformation, SymbolKind};
+
+use crate::{analysis::Analysis, text::offset_at};
+
+pub(crate) fn definition(analysis: &Analysis, pos: Position) -> Vec<Location> {
+    let offset = offset_at(&analysis.text, pos);
+    let (_, id) = match analysis.symbol_table.ref_at(analysis.pos(offset)) {
+        Some(v) => v,
+        None => return vec![],
+    };
+
+    analysis
+        .symbol_table
+        .definitions(&id)
+        .into_iter()
+        .filter_map(|symbol| analysis.location(symbol.span))
+        .collect()
+}
+
+pub(crate) fn references(analysis: &Analysis, pos: Position, include_declaration: bool) -> Vec<Location> {
+    let offset = offset_at(&analysis.text, pos);
+    let (_, id) = match analysis.symbol_table.ref_at(analysis.pos(offset)) {
+        Some(v) => v,
+        None => return vec![],
+    };
+
+    let decls = analysis
+        .symbol_table
+        .definitions(&id)
+        .into_iter()
+        .map(|symbol| symbol.span.lo)
+        .collect::<FxHashSet<_>>();
+
+    analysis
+        .symbol_table
+        .references(&id)
+        .into_iter()
+        .filter(|span| include_declaration || !decls.contains(&span.lo))
+        .filter_map(|span| analysis.location(span))
+        .collect()
+}
+
+pub(crate) fn document_symbols(analysis: &Analysis) -> Vec<SymbolInformation> {
+    analysis
+        .symbol_table
+        .symbols()
+        .into_iter()
+        .filter(|symbol| analysis.contains(symbol.span))
+        .filter_map(|symbol| symbol_information(analysis, symbol))
+        .collect()
+}
+
+/// Searches symbols of all analyzed files, including dependencies of open
+/// documents.
+pub(crate) fn workspace_symbols(analyses: &[Arc<Analysis>], query: &str) -> Vec<SymbolInformation> {
+    let query = query.to_lowercase();
+    let mut done = FxHashSet::default();
+    let mut symbols = vec![];
+
+    for analysis in analyses {
+        // Documents checked together share a symbol table.
+        if !done.insert(Arc::as_ptr(&analysis.cm)) {
+            continue;
+        }
+
+        symbols.extend(
+            analysis
+                .symbol_table
+                .symbols()
+                .into_iter()
+                .filter(|symbol| matches_query(&symbol.id.sym().to_lowercase(), &query))
+                .filter_map(|symbol| symbol_information(analysis, symbol)),
+        );
+    }
+
+    symbols
+}
+
+/// Returns `true` if `name` contains all characters of `query` in order.
+fn matches_query(name: &str, query: &str) -> bool {
+    let mut chars = name.chars();
+
+    query.chars().all(|q| chars.any(|c| c == q))
+}
+
+fn symbol_information(analysis: &Analysis, symbol: Symbol) -> Option<SymbolInformation> {
+    let kind = match symbol.kind {
+        Kind::Var => SymbolKind::VARIABLE,
+        Kind::Const => SymbolKind::CONSTANT,
+        Kind::Function => SymbolKind::FUNCTION,
+        Kind::Class => SymbolKind::CLASS,
+        Kind::Interface => SymbolKind::INTERFACE,
+        // Same as `typescript-language-server`.
+        Kind::TypeAlias => SymbolKind::CLASS,
+        Kind::Enum => SymbolKind::ENUM,
+        Kind::Namespace => SymbolKind::NAMESPACE,
+        // Parameters and imports are not listed.
+        Kind::Param | Kind::Import => return None,
+    };
+
+    #[allow(deprecated)]
+    Some(SymbolInformation {
+        name: symbol.id.sym().to_string(),
+        kind,
+        tags: None,
+        deprecated: None,
+        location: analysis.location(symbol.full_span)?,
+        container_name: symbol.container.map(|name| name.to_string()),
+    })
+}
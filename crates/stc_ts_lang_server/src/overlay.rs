@@ -0,0 +1,183 @@
+//! In-memory contents of documents opened by the editor.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::Error;
+use fxhash::FxHashMap;
+use parking_lot::RwLock;
+use path_clean::PathClean;
+use stc_ts_module_loader::resolvers::{is_relative, node::with_extension_appended};
+use swc_common::{FileLoader, FileName};
+use swc_ecma_loader::resolve::Resolve;
+use tower_lsp::lsp_types::{TextDocumentContentChangeEvent, Url};
+
+use crate::text::offset_at;
+
+/// Same as the extensions used by `NodeResolver`.
+static EXTENSIONS: &[&str] = &["ts", "tsx", "d.ts"];
+
+#[derive(Debug, Clone)]
+pub(crate) struct Document {
+    pub uri: Url,
+    pub version: i32,
+    pub text: Arc<String>,
+}
+
+/// Open documents, keyed by their paths.
+///
+/// Contents of these documents are used instead of files on the disk.
+#[derive(Debug, Default)]
+pub(crate) struct Overlay {
+    docs: RwLock<FxHashMap<PathBuf, Document>>,
+    /// See [Overlay::patch].
+    patch: RwLock<Option<(PathBuf, Arc<String>)>>,
+}
+
+impl Overlay {
+    pub fn open(&self, path: PathBuf, doc: Document) {
+        self.docs.write().insert(path, doc);
+    }
+
+    pub fn close(&self, path: &Path) -> Option<Document> {
+        self.docs.write().remove(path)
+    }
+
+    /// Applies changes in order. Changes without a range replace the whole
+    /// document.
+    pub fn change(&self, path: &Path, version: i32, changes: Vec<TextDocumentContentChangeEvent>) {
+        let mut docs = self.docs.write();
+        let doc = match docs.get_mut(path) {
+            Some(doc) => doc,
+            None => return,
+        };
+
+        let text = Arc::make_mut(&mut doc.text);
+        for change in changes {
+            match change.range {
+                Some(range) => {
+                    let start = offset_at(text, range.start);
+                    let end = offset_at(text, range.end).max(start);
+                    text.replace_range(start..end, &change.text);
+                }
+                None => *text = change.text,
+            }
+        }
+        doc.version = version;
+    }
+
+    pub fn get(&self, path: &Path) -> Option<Document> {
+        self.docs.read().get(path).cloned()
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        self.docs.read().contains_key(path)
+    }
+
+    /// Makes [OverlayLoader] read `text` for `path` until [Overlay::unpatch]
+    /// is called. Other readers see the content of the document.
+    pub fn patch(&self, path: &Path, text: String) {
+        *self.patch.write() = Some((path.to_path_buf(), Arc::new(text)));
+    }
+
+    pub fn unpatch(&self) {
+        *self.patch.write() = None;
+    }
+
+    /// Returns the text loaded for `path`.
+    fn text_to_load(&self, path: &Path) -> Option<Arc<String>> {
+        if let Some((patched, text)) = &*self.patch.read() {
+            if patched == path {
+                return Some(text.clone());
+            }
+        }
+
+        self.docs.read().get(path).map(|doc| doc.text.clone())
+    }
+
+    /// Returns all open documents.
+    pub fn documents(&self) -> Vec<(PathBuf, Document)> {
+        self.docs.read().iter().map(|(path, doc)| (path.clone(), doc.clone())).collect()
+    }
+}
+
+/// Reads files from [Overlay] if they are open, and from the disk otherwise.
+pub(crate) struct OverlayLoader {
+    overlay: Arc<Overlay>,
+}
+
+impl OverlayLoader {
+    pub fn new(overlay: Arc<Overlay>) -> Self {
+        Self { overlay }
+    }
+}
+
+impl FileLoader for OverlayLoader {
+    fn file_exists(&self, path: &Path) -> bool {
+        self.overlay.contains(path) || path.exists()
+    }
+
+    fn abs_path(&self, path: &Path) -> Option<PathBuf> {
+        if path.is_absolute() {
+            Some(path.to_path_buf())
+        } else {
+            std::env::current_dir().ok().map(|cwd| cwd.join(path))
+        }
+    }
+
+    fn read_file(&self, path: &Path) -> io::Result<String> {
+        match self.overlay.text_to_load(path) {
+            Some(text) => Ok((*text).clone()),
+            None => std::fs::read_to_string(path),
+        }
+    }
+}
+
+/// Resolves relative imports to open documents, which may not exist on the
+/// disk yet, and delegates everything else to `inner`.
+pub(crate) struct OverlayResolver {
+    overlay: Arc<Overlay>,
+    inner: Arc<dyn Resolve>,
+}
+
+impl OverlayResolver {
+    pub fn new(overlay: Arc<Overlay>, inner: Arc<dyn Resolve>) -> Self {
+        Self { overlay, inner }
+    }
+
+    fn resolve_in_overlay(&self, base: &FileName, module_specifier: &str) -> Option<PathBuf> {
+        let path = if Path::new(module_specifier).is_absolute() {
+            // Used by `PathsResolver`.
+            PathBuf::from(module_specifier).clean()
+        } else if is_relative(module_specifier) {
+            let base = match base {
+                FileName::Real(base) => base,
+                _ => return None,
+            };
+            base.parent()?.join(module_specifier).clean()
+        } else {
+            return None;
+        };
+
+        let index = path.join("index");
+        let mut candidates = Some(path.clone())
+            .into_iter()
+            .chain(EXTENSIONS.iter().map(|ext| with_extension_appended(&path, ext)))
+            .chain(EXTENSIONS.iter().map(|ext| with_extension_appended(&index, ext)));
+
+        candidates.find(|path| self.overlay.contains(path))
+    }
+}
+
+impl Resolve for OverlayResolver {
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error> {
+        if let Some(path) = self.resolve_in_overlay(base, module_specifier) {
+            return Ok(FileName::Real(path));
+        }
+
+        self.inner.resolve(base, module_specifier)
+    }
+}
@@ -0,0 +1,44 @@
+//! Conversion between byte offsets and positions of the language server
+//! protocol, which count UTF-16 code units.
+
+use tower_lsp::lsp_types::Position;
+
+/// Returns the byte offset of `pos`. Positions out of range are clamped.
+pub(crate) fn offset_at(text: &str, pos: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..pos.line {
+        match text[line_start..].find('\n') {
+            Some(idx) => line_start += idx + 1,
+            None => return text.len(),
+        }
+    }
+
+    let mut offset = line_start;
+    let mut character = 0;
+    for c in text[line_start..].chars() {
+        if c == '\n' || character >= pos.character as usize {
+            break;
+        }
+
+        character += c.len_utf16();
+        offset += c.len_utf8();
+    }
+
+    offset
+}
+
+/// Returns the position of the byte offset `offset`.
+pub(crate) fn position_at(text: &str, mut offset: usize) -> Position {
+    offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    fs,
     fs::read_to_string,
     path::{Path, PathBuf},
     process::{Command, Stdio},
@@ -9,8 +10,11 @@ use serde::{de::DeserializeOwned, Serialize};
 use serde_json::{json, Value};
 use stc_ts_testing::lsp::LspClient;
 use stc_utils::AHashSet;
+use tempdir::TempDir;
 use testing::run_test;
-use tower_lsp::lsp_types::{Diagnostic, PublishDiagnosticsParams};
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, Diagnostic, NumberOrString, PublishDiagnosticsParams, SymbolInformation, SymbolKind, Url,
+};
 use tracing::info;
 
 /// Builds the example lsp command, and returns to the path to it.
@@ -93,18 +97,17 @@ fn handle_configuration_request(client: &mut LspClient, result: Value) {
 }
 
 #[tracing::instrument(skip_all)]
-#[allow(unused)]
 fn read_diagnostics(client: &mut LspClient) -> CollectedDiagnostics {
-    CollectedDiagnostics(Default::default())
-
-    // TODO: Implement this after implementing lsp
+    loop {
+        let (method, response) = client.read_notification::<Value>().unwrap();
+        // Skip other notifications like `window/logMessage`.
+        if method != "textDocument/publishDiagnostics" {
+            continue;
+        }
 
-    // let mut diagnostics = vec![];
-    // let (method, response) =
-    // client.read_notification::<PublishDiagnosticsParams>().unwrap();
-    // assert_eq!(method, "textDocument/publishDiagnostics");
-    // diagnostics.push(response.unwrap());
-    // CollectedDiagnostics(diagnostics)
+        let diagnostics = serde_json::from_value(response.unwrap()).unwrap();
+        return CollectedDiagnostics(vec![diagnostics]);
+    }
 }
 
 fn shutdown(client: &mut LspClient) {
@@ -168,6 +171,166 @@ fn test_init() {
     shutdown(&mut client);
 }
 
+#[test]
+fn test_diagnostics() {
+    run_test(false, |_cm, _handler| {
+        let mut client = init("initialize_params.json");
+        let diagnostics = did_open(
+            &mut client,
+            json!({
+              "textDocument": {
+                "uri": "file:///a/file.ts",
+                "languageId": "typescript",
+                "version": 1,
+                "text": "const a: string = 1;\n"
+              }
+            }),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].version, Some(1));
+        assert!(diagnostics[0]
+            .diagnostics
+            .iter()
+            .any(|d| d.code == Some(NumberOrString::Number(2322)) && d.range.start.line == 0));
+
+        client
+            .write_notification(
+                "textDocument/didChange",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/file.ts",
+                    "version": 2
+                  },
+                  "contentChanges": [
+                    {
+                      "range": {
+                        "start": { "line": 0, "character": 18 },
+                        "end": { "line": 0, "character": 19 }
+                      },
+                      "text": "''"
+                    }
+                  ]
+                }),
+            )
+            .unwrap();
+        let diagnostics = read_diagnostics(&mut client).0;
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].version, Some(2));
+        assert_eq!(diagnostics[0].diagnostics, vec![]);
+
+        shutdown(&mut client);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_debounced_changes() {
+    run_test(false, |_cm, _handler| {
+        let mut client = init("initialize_params.json");
+        did_open(
+            &mut client,
+            json!({
+              "textDocument": {
+                "uri": "file:///a/file.ts",
+                "languageId": "typescript",
+                "version": 1,
+                "text": "const a: string = 1;\n"
+              }
+            }),
+        );
+
+        for (version, end, text) in [(2, 19, "''"), (3, 20, "2")] {
+            client
+                .write_notification(
+                    "textDocument/didChange",
+                    json!({
+                      "textDocument": {
+                        "uri": "file:///a/file.ts",
+                        "version": version
+                      },
+                      "contentChanges": [
+                        {
+                          "range": {
+                            "start": { "line": 0, "character": 18 },
+                            "end": { "line": 0, "character": end }
+                          },
+                          "text": text
+                        }
+                      ]
+                    }),
+                )
+                .unwrap();
+        }
+        let diagnostics = read_diagnostics(&mut client).0;
+
+        // Only the last change is checked.
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].version, Some(3));
+        assert!(diagnostics[0]
+            .diagnostics
+            .iter()
+            .any(|d| d.code == Some(NumberOrString::Number(2322))));
+
+        shutdown(&mut client);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_import_of_open_document() {
+    run_test(false, |_cm, _handler| {
+        let mut client = init("initialize_params.json");
+        did_open(
+            &mut client,
+            json!({
+              "textDocument": {
+                "uri": "file:///a/foo.service.ts",
+                "languageId": "typescript",
+                "version": 1,
+                "text": "export const a = 1;\n"
+              }
+            }),
+        );
+        client
+            .write_notification(
+                "textDocument/didOpen",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/main.ts",
+                    "languageId": "typescript",
+                    "version": 1,
+                    "text": "import { a } from './foo.service';\nconst b: string = a;\n"
+                  }
+                }),
+            )
+            .unwrap();
+
+        let diagnostics = loop {
+            let diagnostics = read_diagnostics(&mut client).0.remove(0);
+            if diagnostics.uri.path() == "/a/main.ts" {
+                break diagnostics.diagnostics;
+            }
+        };
+
+        // `./foo.service` is resolved to `foo.service.ts`, not `foo.ts`.
+        assert!(diagnostics.iter().all(|d| d.code != Some(NumberOrString::Number(2307))));
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.code == Some(NumberOrString::Number(2322)) && d.range.start.line == 1));
+
+        shutdown(&mut client);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
 #[test]
 fn test_hover() {
     run_test(false, |_cm, _handler| {
@@ -179,7 +342,7 @@ fn test_hover() {
                 "uri": "file:///a/file.ts",
                 "languageId": "typescript",
                 "version": 1,
-                "text": "console.log('foo');\n"
+                "text": "const a = 1;\n"
               }
             }),
         );
@@ -193,7 +356,7 @@ fn test_hover() {
                   },
                   "position": {
                     "line": 0,
-                    "character": 5
+                    "character": 6
                   }
                 }),
             )
@@ -204,9 +367,405 @@ fn test_hover() {
         assert_eq!(
             maybe_res,
             Some(json!({
-              "contents": "hover test",
+              "contents": {
+                "kind": "markdown",
+                "value": "```typescript\n1\n```"
+              },
+              "range": {
+                "start": { "line": 0, "character": 6 },
+                "end": { "line": 0, "character": 7 }
+              }
+            }))
+        );
+        shutdown(&mut client);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_definition() {
+    run_test(false, |_cm, _handler| {
+        let mut client = init("initialize_params.json");
+        did_open(
+            &mut client,
+            json!({
+              "textDocument": {
+                "uri": "file:///a/file.ts",
+                "languageId": "typescript",
+                "version": 1,
+                "text": "const a = 1;\nconsole.log(a);\n"
+              }
+            }),
+        );
+
+        let (maybe_res, maybe_err) = client
+            .write_request(
+                "textDocument/definition",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/file.ts"
+                  },
+                  "position": {
+                    "line": 1,
+                    "character": 12
+                  }
+                }),
+            )
+            .unwrap();
+
+        assert!(maybe_err.is_none());
+        assert_eq!(
+            maybe_res,
+            Some(json!([{
+              "uri": "file:///a/file.ts",
+              "range": {
+                "start": { "line": 0, "character": 6 },
+                "end": { "line": 0, "character": 7 }
+              }
+            }]))
+        );
+
+        let (maybe_res, maybe_err) = client
+            .write_request(
+                "textDocument/references",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/file.ts"
+                  },
+                  "position": {
+                    "line": 0,
+                    "character": 6
+                  },
+                  "context": {
+                    "includeDeclaration": false
+                  }
+                }),
+            )
+            .unwrap();
+
+        assert!(maybe_err.is_none());
+        assert_eq!(
+            maybe_res,
+            Some(json!([{
+              "uri": "file:///a/file.ts",
+              "range": {
+                "start": { "line": 1, "character": 12 },
+                "end": { "line": 1, "character": 13 }
+              }
+            }]))
+        );
+
+        shutdown(&mut client);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Results of a type check after a change use the new text of the document.
+#[test]
+fn test_hover_and_definition_after_change() {
+    run_test(false, |_cm, _handler| {
+        let mut client = init("initialize_params.json");
+        did_open(
+            &mut client,
+            json!({
+              "textDocument": {
+                "uri": "file:///a/file.ts",
+                "languageId": "typescript",
+                "version": 1,
+                "text": "const a = 1;\nconsole.log(a);\n"
+              }
+            }),
+        );
+
+        client
+            .write_notification(
+                "textDocument/didChange",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/file.ts",
+                    "version": 2
+                  },
+                  "contentChanges": [
+                    {
+                      "range": {
+                        "start": { "line": 0, "character": 0 },
+                        "end": { "line": 0, "character": 0 }
+                      },
+                      "text": "const b = '';\n\n"
+                    }
+                  ]
+                }),
+            )
+            .unwrap();
+        read_diagnostics(&mut client);
+
+        let (maybe_res, maybe_err) = client
+            .write_request(
+                "textDocument/hover",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/file.ts"
+                  },
+                  "position": {
+                    "line": 2,
+                    "character": 6
+                  }
+                }),
+            )
+            .unwrap();
+
+        assert!(maybe_err.is_none());
+        assert_eq!(
+            maybe_res,
+            Some(json!({
+              "contents": {
+                "kind": "markdown",
+                "value": "```typescript\n1\n```"
+              },
+              "range": {
+                "start": { "line": 2, "character": 6 },
+                "end": { "line": 2, "character": 7 }
+              }
             }))
         );
+
+        let (maybe_res, maybe_err) = client
+            .write_request(
+                "textDocument/definition",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/file.ts"
+                  },
+                  "position": {
+                    "line": 3,
+                    "character": 12
+                  }
+                }),
+            )
+            .unwrap();
+
+        assert!(maybe_err.is_none());
+        assert_eq!(
+            maybe_res,
+            Some(json!([{
+              "uri": "file:///a/file.ts",
+              "range": {
+                "start": { "line": 2, "character": 6 },
+                "end": { "line": 2, "character": 7 }
+              }
+            }]))
+        );
+
+        shutdown(&mut client);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_document_symbol() {
+    run_test(false, |_cm, _handler| {
+        let mut client = init("initialize_params.json");
+        did_open(
+            &mut client,
+            json!({
+              "textDocument": {
+                "uri": "file:///a/file.ts",
+                "languageId": "typescript",
+                "version": 1,
+                "text": "function foo(a: number) {\n    let b = a;\n}\n"
+              }
+            }),
+        );
+
+        let (maybe_res, maybe_err) = client
+            .write_request::<_, _, Vec<SymbolInformation>>(
+                "textDocument/documentSymbol",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/file.ts"
+                  }
+                }),
+            )
+            .unwrap();
+
+        assert!(maybe_err.is_none());
+        let symbols = maybe_res
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.name, s.kind, s.container_name))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            symbols,
+            vec![
+                ("foo".to_string(), SymbolKind::FUNCTION, None),
+                ("b".to_string(), SymbolKind::VARIABLE, Some("foo".to_string())),
+            ]
+        );
+
+        shutdown(&mut client);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_completion() {
+    run_test(false, |_cm, _handler| {
+        let mut client = init("initialize_params.json");
+        did_open(
+            &mut client,
+            json!({
+              "textDocument": {
+                "uri": "file:///a/file.ts",
+                "languageId": "typescript",
+                "version": 1,
+                "text": "const a = { foo: 1, bar: '' };\na.\n"
+              }
+            }),
+        );
+
+        let (maybe_res, maybe_err) = client
+            .write_request::<_, _, Vec<CompletionItem>>(
+                "textDocument/completion",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/file.ts"
+                  },
+                  "position": {
+                    "line": 1,
+                    "character": 2
+                  }
+                }),
+            )
+            .unwrap();
+
+        assert!(maybe_err.is_none());
+        let mut items = maybe_res
+            .unwrap()
+            .into_iter()
+            .map(|item| (item.label, item.kind))
+            .collect::<Vec<_>>();
+        items.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            items,
+            vec![
+                ("bar".to_string(), Some(CompletionItemKind::PROPERTY)),
+                ("foo".to_string(), Some(CompletionItemKind::PROPERTY)),
+            ]
+        );
+
+        shutdown(&mut client);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_completion_of_scope() {
+    run_test(false, |_cm, _handler| {
+        let mut client = init("initialize_params.json");
+        did_open(
+            &mut client,
+            json!({
+              "textDocument": {
+                "uri": "file:///a/file.ts",
+                "languageId": "typescript",
+                "version": 1,
+                "text": "const foo = 1;\nfunction bar() {}\n\n"
+              }
+            }),
+        );
+
+        let (maybe_res, maybe_err) = client
+            .write_request::<_, _, Vec<CompletionItem>>(
+                "textDocument/completion",
+                json!({
+                  "textDocument": {
+                    "uri": "file:///a/file.ts"
+                  },
+                  "position": {
+                    "line": 2,
+                    "character": 0
+                  }
+                }),
+            )
+            .unwrap();
+
+        assert!(maybe_err.is_none());
+        let items = maybe_res
+            .unwrap()
+            .into_iter()
+            .map(|item| (item.label, item.kind))
+            .collect::<Vec<_>>();
+        assert!(items.contains(&("foo".to_string(), Some(CompletionItemKind::VARIABLE))));
+        assert!(items.contains(&("bar".to_string(), Some(CompletionItemKind::FUNCTION))));
+
+        shutdown(&mut client);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_completion_of_import_path() {
+    run_test(false, |_cm, _handler| {
+        let dir = TempDir::new("stc-lsp-completion").unwrap();
+        fs::write(dir.path().join("dep.ts"), "export const a = 1;\n").unwrap();
+        fs::write(dir.path().join("style.css"), "").unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        let uri = Url::from_file_path(dir.path().join("main.ts")).unwrap();
+
+        let mut client = init("initialize_params.json");
+        did_open(
+            &mut client,
+            json!({
+              "textDocument": {
+                "uri": uri,
+                "languageId": "typescript",
+                "version": 1,
+                "text": "import { a } from './';\n"
+              }
+            }),
+        );
+
+        let (maybe_res, maybe_err) = client
+            .write_request::<_, _, Vec<CompletionItem>>(
+                "textDocument/completion",
+                json!({
+                  "textDocument": {
+                    "uri": uri
+                  },
+                  "position": {
+                    "line": 0,
+                    "character": 21
+                  }
+                }),
+            )
+            .unwrap();
+
+        assert!(maybe_err.is_none());
+        let items = maybe_res
+            .unwrap()
+            .into_iter()
+            .map(|item| (item.label, item.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            vec![
+                ("./dep".to_string(), Some(CompletionItemKind::FILE)),
+                ("./sub".to_string(), Some(CompletionItemKind::FOLDER)),
+            ]
+        );
+
         shutdown(&mut client);
 
         Ok(())
@@ -4,7 +4,12 @@ use swc_common::{comments::Comments, Span, Spanned};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{Visit, VisitWith};
 
-pub(crate) fn find_modules_and_deps<C>(comments: &C, m: &Module) -> (Vec<JsWord>, Vec<JsWord>)
+/// Returns declared modules, dependencies and augmented modules. Augmented
+/// modules are dependencies too.
+///
+/// `jsx_runtime` is added as a dependency if `m` contains a JSX element,
+/// because the `JSX` namespace is provided by the module.
+pub(crate) fn find_modules_and_deps<C>(comments: &C, m: &Module, jsx_runtime: Option<&JsWord>) -> (Vec<JsWord>, Vec<JsWord>, Vec<JsWord>)
 where
     C: Comments,
 {
@@ -12,11 +17,18 @@ where
         comments,
         declared_modules: Default::default(),
         deps: Default::default(),
+        augmented_modules: Default::default(),
+        has_jsx: false,
+        is_module: false,
     };
 
     m.visit_with(&mut v);
 
-    (v.declared_modules, v.deps)
+    if v.has_jsx {
+        v.deps.extend(jsx_runtime.cloned());
+    }
+
+    (v.declared_modules, v.deps, v.augmented_modules)
 }
 
 struct DepFinder<C>
@@ -26,6 +38,11 @@ where
     comments: C,
     declared_modules: Vec<JsWord>,
     deps: Vec<JsWord>,
+    augmented_modules: Vec<JsWord>,
+    has_jsx: bool,
+    /// `declare module "foo"` in a module augments `foo`, instead of
+    /// declaring it.
+    is_module: bool,
 }
 
 impl<C> DepFinder<C>
@@ -50,6 +67,8 @@ where
     }
 
     fn visit_module(&mut self, m: &Module) {
+        self.is_module = m.body.iter().any(|item| matches!(item, ModuleItem::ModuleDecl(..)));
+
         m.visit_children_with(self);
 
         self.check_comments(m.span)
@@ -73,11 +92,29 @@ where
         self.deps.push(import.expr.value.clone());
     }
 
+    fn visit_jsx_element(&mut self, n: &JSXElement) {
+        self.has_jsx = true;
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_jsx_fragment(&mut self, n: &JSXFragment) {
+        self.has_jsx = true;
+
+        n.visit_children_with(self);
+    }
+
     fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
         n.visit_children_with(self);
 
         if let TsModuleName::Str(s) = &n.id {
-            self.declared_modules.push(s.value.clone());
+            if self.is_module {
+                // The augmented module should be loaded first.
+                self.deps.push(s.value.clone());
+                self.augmented_modules.push(s.value.clone());
+            } else {
+                self.declared_modules.push(s.value.clone());
+            }
         }
     }
 }
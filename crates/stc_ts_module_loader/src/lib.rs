@@ -1,17 +1,18 @@
 #![deny(warnings)]
 
-use std::{mem::take, sync::Arc};
+use std::{mem::take, path::Path, sync::Arc};
 
 use anyhow::{anyhow, bail, Error};
 use dashmap::DashMap;
-use fxhash::FxBuildHasher;
+use fxhash::{FxBuildHasher, FxHashSet};
 use parking_lot::{Mutex, RwLock};
+use petgraph::EdgeDirection::{Incoming, Outgoing};
 use rayon::prelude::*;
 use stc_ts_types::{module_id::ModuleIdGenerator, ModuleId};
 use stc_utils::panic_ctx;
 use swc_atoms::JsWord;
-use swc_common::{collections::AHashMap, comments::Comments, FileName, Mark, SourceMap, DUMMY_SP};
-use swc_ecma_ast::{EsVersion, Module};
+use swc_common::{collections::AHashMap, comments::Comments, sync::Lrc, FileName, Mark, SourceFile, SourceMap, DUMMY_SP};
+use swc_ecma_ast::{EsVersion, Module, ModuleItem};
 use swc_ecma_loader::resolve::Resolve;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
 use swc_fast_graph::digraph::FastDiGraphMap;
@@ -24,6 +25,13 @@ use crate::resolvers::typescript::TsResolver;
 mod analyzer;
 pub mod resolvers;
 
+/// Returns `true` for `.d.ts`, `.d.mts` and `.d.cts` files.
+pub fn is_dts(path: &Path) -> bool {
+    let s = path.to_string_lossy();
+
+    [".d.ts", ".d.mts", ".d.cts"].iter().any(|ext| s.ends_with(ext))
+}
+
 #[derive(Debug, Clone)]
 struct ModuleRecord {
     pub module: Arc<Module>,
@@ -53,6 +61,8 @@ where
     parser_config: TsConfig,
     target: EsVersion,
     comments: C,
+    /// See [ModuleGraph::with_jsx_runtime].
+    jsx_runtime: Option<JsWord>,
 
     id_generator: ModuleIdGenerator,
     loaded: DashMap<ModuleId, Result<ModuleRecord, ()>, FxBuildHasher>,
@@ -64,6 +74,11 @@ where
     deps: RwLock<DepGraphData>,
 
     parse_cache: Mutex<AHashMap<Arc<FileName>, Arc<Module>>>,
+    /// Modules with imports which could not be resolved.
+    unresolved: Mutex<FxHashSet<ModuleId>>,
+    /// `declare module "./foo"` in modules, with the augmenting module and the
+    /// augmented module.
+    augmentations: Mutex<FxHashSet<(ModuleId, ModuleId)>>,
 }
 #[derive(Default)]
 struct DepGraphData {
@@ -88,6 +103,7 @@ where
             parser_config,
             target,
             comments,
+            jsx_runtime: None,
             id_generator: Default::default(),
             loaded: Default::default(),
             started: Default::default(),
@@ -96,9 +112,18 @@ where
             parsing_errors: Default::default(),
             deps: Default::default(),
             parse_cache: Default::default(),
+            unresolved: Default::default(),
+            augmentations: Default::default(),
         }
     }
 
+    /// Modules containing JSX elements will depend on `jsx_runtime`, which is
+    /// something like `react/jsx-runtime`.
+    pub fn with_jsx_runtime(mut self, jsx_runtime: Option<JsWord>) -> Self {
+        self.jsx_runtime = jsx_runtime;
+        self
+    }
+
     pub fn comments(&self) -> &C {
         &self.comments
     }
@@ -142,6 +167,92 @@ where
         Ok(module_id)
     }
 
+    /// Returns modules imported by `id`.
+    pub fn dependencies(&self, id: ModuleId) -> Vec<ModuleId> {
+        let deps = self.deps.read();
+
+        deps.graph.neighbors_directed(id, Outgoing).collect()
+    }
+
+    /// Returns `ids` and modules which depend on any of `ids`, directly or
+    /// indirectly.
+    pub fn dependents(&self, ids: &[ModuleId]) -> Vec<ModuleId> {
+        let deps = self.deps.read();
+
+        let mut found = ids.iter().copied().collect::<FxHashSet<_>>();
+        let mut queue = ids.to_vec();
+
+        while let Some(id) = queue.pop() {
+            for dependent in deps.graph.neighbors_directed(id, Incoming) {
+                if found.insert(dependent) {
+                    queue.push(dependent);
+                }
+            }
+        }
+
+        found.into_iter().collect()
+    }
+
+    /// Forgets modules of `ids`, so they are loaded again by the next call to
+    /// [ModuleGraph::load_all]. Files in `changed` are parsed again.
+    ///
+    /// `ids` should include all dependents of `changed`. See
+    /// [ModuleGraph::dependents].
+    pub fn invalidate(&self, ids: &[ModuleId], changed: &[Arc<FileName>]) {
+        {
+            let mut cache = self.parse_cache.lock();
+            for path in changed {
+                cache.remove(path);
+            }
+        }
+
+        for id in ids {
+            self.loaded.remove(id);
+            self.started.remove(id);
+        }
+        self.unresolved.lock().retain(|id| !ids.contains(id));
+        self.augmentations.lock().retain(|(id, _)| !ids.contains(id));
+
+        let mut deps = self.deps.write();
+
+        deps.all.retain(|id| !ids.contains(id));
+        // Cycles are computed again while loading.
+        deps.cycles.retain(|cycle| cycle.iter().all(|id| !ids.contains(id)));
+        for &id in ids {
+            deps.graph.remove_node(id);
+        }
+    }
+
+    /// Returns the source file of the parsed module of `path`.
+    ///
+    /// A file which is parsed again is added to the [SourceMap] again, and
+    /// [SourceMap::get_source_file] returns the first one.
+    pub fn source_file(&self, path: &Arc<FileName>) -> Option<Lrc<SourceFile>> {
+        let module = self.parse_cache.lock().get(path).cloned()?;
+
+        Some(self.cm.lookup_source_file(module.span.lo))
+    }
+
+    /// Returns `true` if `id` is loaded and not invalidated since then.
+    pub fn is_loaded(&self, id: ModuleId) -> bool {
+        self.loaded.contains_key(&id)
+    }
+
+    /// Returns loaded modules with imports which could not be resolved. They
+    /// may be resolved if a file is created.
+    pub fn modules_with_unresolved_imports(&self) -> Vec<ModuleId> {
+        self.unresolved.lock().iter().copied().collect()
+    }
+
+    /// Returns loaded modules which augment other modules with `declare
+    /// module`.
+    pub fn augmenting_modules(&self) -> Vec<ModuleId> {
+        let mut ids = self.augmentations.lock().iter().map(|&(id, _)| id).collect::<Vec<_>>();
+        ids.sort();
+        ids.dedup();
+        ids
+    }
+
     pub fn id_for_declare_module(&self, module_name: &JsWord) -> ModuleId {
         self.id_generator.generate(&Arc::new(FileName::Custom(module_name.to_string()))).0
     }
@@ -191,6 +302,14 @@ where
         self.with_module(id, |m| m.cloned())
     }
 
+    /// Returns `true` if `id` has an import or an export. Otherwise it's a
+    /// global script.
+    pub fn is_module(&self, id: ModuleId) -> bool {
+        self.with_module(id, |m| {
+            m.map_or(false, |m| m.body.iter().any(|item| matches!(item, ModuleItem::ModuleDecl(..))))
+        })
+    }
+
     pub fn top_level_mark(&self, id: ModuleId) -> Mark {
         self.id_generator.top_level_mark(id)
     }
@@ -295,7 +414,7 @@ where
 
         let _panic = panic_ctx!(format!("ModuleGraph.load({}, span = {:?})", filename, module.span));
 
-        let (declared_modules, deps) = find_modules_and_deps(&self.comments, &module);
+        let (declared_modules, deps, augmented_modules) = find_modules_and_deps(&self.comments, &module, self.jsx_runtime.as_ref());
 
         for decl in declared_modules {
             self.resolver.declare_module(decl);
@@ -303,17 +422,25 @@ where
 
         let resolver = &self.resolver;
 
-        let deps = if resolve_all {
-            deps.into_par_iter()
-                .map(|specifier| resolver.resolve(filename, &specifier))
-                .filter_map(|res| res.ok())
-                .collect()
-        } else {
-            deps.into_par_iter()
-                .map(|specifier| resolver.resolve(filename, &specifier))
-                .filter_map(|res| res.ok())
-                .collect()
-        };
+        for specifier in augmented_modules {
+            if let Ok(path) = resolver.resolve(filename, &specifier) {
+                if let FileName::Real(..) = &*path {
+                    let augmented = self.id_generator.generate(&path).0;
+                    self.augmentations.lock().insert((module_id, augmented));
+                }
+            }
+        }
+
+        let deps: Vec<_> = deps
+            .into_par_iter()
+            .map(|specifier| resolver.resolve(filename, &specifier))
+            .collect();
+
+        if deps.iter().any(|res| res.is_err()) {
+            self.unresolved.lock().insert(module_id);
+        }
+
+        let deps = deps.into_iter().filter_map(|res| res.ok()).collect();
 
         log::debug!("Loaded {:?}: {}", module_id, filename);
 
@@ -335,7 +462,7 @@ where
         let fm = self.cm.load_file(path)?;
         let lexer = Lexer::new(
             Syntax::Typescript(TsConfig {
-                dts: path.as_os_str().to_string_lossy().ends_with(".d.ts"),
+                dts: is_dts(path),
                 tsx: path.extension().map(|v| v == "tsx").unwrap_or(false),
                 ..self.parser_config
             }),
@@ -1,2 +1,10 @@
 pub mod node;
+pub mod paths;
+mod pattern;
 pub(crate) mod typescript;
+
+/// Returns `true` if `specifier` is relative to the importing file, like
+/// `./foo`, `../foo`, `.` and `..`.
+pub fn is_relative(specifier: &str) -> bool {
+    matches!(specifier, "." | "..") || specifier.starts_with("./") || specifier.starts_with("../")
+}
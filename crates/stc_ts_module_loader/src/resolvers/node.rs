@@ -1,29 +1,92 @@
 use std::{
+    collections::BTreeMap,
     fs::File,
     io::BufReader,
     path::{Path, PathBuf},
 };
 
 use anyhow::{bail, Context, Error};
+use dashmap::DashMap;
+use fxhash::FxBuildHasher;
 use path_clean::PathClean;
 use serde::Deserialize;
+use serde_json::{Map, Value};
 use swc_common::FileName;
 use swc_ecma_loader::resolve::Resolve;
 
-static EXTENSIONS: &[&str] = &["tsx", "ts", "d.ts"];
+use super::{is_relative, pattern::best_match};
+
+static EXTENSIONS: &[&str] = &["ts", "tsx", "d.ts"];
+
+/// Extensions of TypeScript files emitted as `.js` or `.jsx`.
+const JS_SOURCES: &[&str] = &["ts", "tsx", "d.ts"];
+/// Extensions of TypeScript files emitted as `.mjs`.
+const MJS_SOURCES: &[&str] = &["mts", "d.mts"];
+/// Extensions of TypeScript files emitted as `.cjs`.
+const CJS_SOURCES: &[&str] = &["cts", "d.cts"];
+
+/// Conditions of `exports` for ES modules in node.
+const NODE_IMPORT_CONDITIONS: &[&str] = &["types", "node", "import", "default"];
+/// Conditions of `exports` for CommonJS modules in node.
+const NODE_REQUIRE_CONDITIONS: &[&str] = &["types", "node", "require", "default"];
+const BUNDLER_CONDITIONS: &[&str] = &["types", "import", "default"];
+
+/// Version of TypeScript used to select `typesVersions`.
+const TS_VERSION: (u64, u64, u64) = (4, 9, 0);
+
+/// `compilerOptions.moduleResolution`.
+///
+/// `classic` is not supported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModuleResolution {
+    /// `node` or `node10`
+    #[default]
+    Node10,
+    /// `node16`
+    Node16,
+    /// `nodenext`
+    NodeNext,
+    /// `bundler`
+    Bundler,
+}
 
 #[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct PackageJson {
     #[serde(default)]
     types: Option<String>,
+    #[serde(default)]
+    typings: Option<String>,
+    #[serde(default)]
+    main: Option<String>,
+    /// `"module"` or `"commonjs"`.
+    #[serde(default, rename = "type")]
+    module_type: Option<String>,
+    #[serde(default)]
+    exports: Option<Value>,
+    /// Version ranges to path mappings, in the order of keys.
+    #[serde(default)]
+    types_versions: Option<Map<String, Value>>,
 }
 
 #[derive(Default)]
-pub struct NodeResolver;
+pub struct NodeResolver {
+    module_resolution: ModuleResolution,
+    /// Whether modules in a directory are ES modules, based on the nearest
+    /// package.json.
+    esm_dirs: DashMap<PathBuf, bool, FxBuildHasher>,
+}
 
 impl NodeResolver {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// `exports` of `package.json` is used for modes other than
+    /// [ModuleResolution::Node10].
+    pub fn with_module_resolution(mut self, module_resolution: ModuleResolution) -> Self {
+        self.module_resolution = module_resolution;
+        self
     }
 
     fn wrap(&self, path: PathBuf) -> Result<FileName, Error> {
@@ -33,14 +96,21 @@ impl NodeResolver {
 
     /// Resolve a path as a file. If `path` refers to a file, it is returned;
     /// otherwise the `path` + each extension is tried.
+    ///
+    /// `foo.js` refers to `foo.ts` if it exists, as it's the source of
+    /// `foo.js`.
     pub fn resolve_as_file(&self, path: &Path) -> Result<PathBuf, Error> {
+        if let Some(source) = resolve_ts_source(path) {
+            return Ok(source);
+        }
+
         // 1. If X is a file, load X as JavaScript text.
         if path.is_file() {
             return Ok(path.to_path_buf());
         }
 
         for ext in EXTENSIONS {
-            let ext_path = path.with_extension(ext);
+            let ext_path = with_extension_appended(path, ext);
             if ext_path.is_file() {
                 return Ok(ext_path);
             }
@@ -49,8 +119,9 @@ impl NodeResolver {
         bail!("file not found: {}", path.display())
     }
 
-    /// Resolve a path as a directory, using the "main" key from a package.json
-    /// file if it exists, or resolving to the index.EXT file if it exists.
+    /// Resolve a path as a directory, using the "types" key from a
+    /// package.json file if it exists, or resolving to the index.EXT file if
+    /// it exists.
     pub fn resolve_as_directory(&self, path: &Path) -> Result<PathBuf, Error> {
         // 1. If X/package.json is a file, use it.
         let pkg_path = path.join("package.json");
@@ -65,21 +136,35 @@ impl NodeResolver {
         self.resolve_index(path)
     }
 
-    /// Resolve using the package.json "main" key.
-    fn resolve_using_package_json(&self, pkg_path: &PathBuf) -> Result<PathBuf, Error> {
-        // TODO: how to not always initialize this here?
-        let root = PathBuf::from("/");
-        let pkg_dir = pkg_path.parent().unwrap_or(&root);
-        let file = File::open(pkg_path)?;
-        let reader = BufReader::new(file);
-        let pkg: PackageJson = serde_json::from_reader(reader).context("failed to deserialize package.json")?;
+    /// Resolve using `types`, `typings` or `main` of package.json, which may
+    /// be redirected by `typesVersions`.
+    fn resolve_using_package_json(&self, pkg_path: &Path) -> Result<PathBuf, Error> {
+        let pkg_dir = pkg_path.parent().unwrap_or_else(|| Path::new("/"));
+        let pkg = read_package_json(pkg_path)?;
+
+        let types = pkg.types.as_deref().or(pkg.typings.as_deref());
+
+        let subpath = types.map_or("index.d.ts", |types| types.trim_start_matches("./"));
+        if let Some(path) = self.resolve_using_types_versions(pkg_dir, &pkg, subpath) {
+            return Ok(path);
+        }
 
-        if let Some(target) = &pkg.types {
-            let path = pkg_dir.join(target);
+        if let Some(types) = types {
+            let path = pkg_dir.join(types);
             return self.resolve_as_file(&path).or_else(|_| self.resolve_as_directory(&path));
         }
 
-        bail!("package.json does not contain a \"main\" string")
+        // `main` is used only if there's a declaration file next to it.
+        if let Some(main) = &pkg.main {
+            let path = pkg_dir.join(main);
+            if let Ok(path) = self.resolve_as_file(&path).or_else(|_| self.resolve_as_directory(&path)) {
+                if is_ts_file(&path) {
+                    return Ok(path);
+                }
+            }
+        }
+
+        bail!("package.json does not contain \"types\", \"typings\" or \"main\"")
     }
 
     /// Resolve a directory to its index.EXT.
@@ -102,33 +187,159 @@ impl NodeResolver {
     }
 
     /// Resolve by walking up node_modules folders.
-    fn resolve_node_modules(&self, base_dir: &Path, target: &str) -> Result<PathBuf, Error> {
-        let node_modules = base_dir.join("node_modules");
-        if node_modules.is_dir() {
-            let path = node_modules.join(target);
-            let result = self.try_package(&path);
-            if result.is_ok() {
-                return result;
+    fn resolve_node_modules(&self, base: &Path, base_dir: &Path, target: &str) -> Result<PathBuf, Error> {
+        let (name, subpath) = split_package_name(target);
+
+        for dir in base_dir.ancestors() {
+            let node_modules = dir.join("node_modules");
+            if !node_modules.is_dir() {
+                continue;
             }
 
-            {
-                let types = node_modules.join("@types").join(target);
+            if let Some(path) = self.resolve_in_package(base, &node_modules.join(name), subpath) {
+                return Ok(path);
+            }
 
-                if types.is_dir() {
-                    let result = self.try_package(&types);
+            let types = node_modules.join("@types").join(types_package_name(name));
+            if types.is_dir() {
+                if let Some(path) = self.resolve_in_package(base, &types, subpath) {
+                    return Ok(path);
+                }
+            }
+        }
+
+        bail!("not found")
+    }
+
+    /// Resolves `subpath` of the package at `pkg_dir`, or its entry point if
+    /// `subpath` is [None].
+    fn resolve_in_package(&self, base: &Path, pkg_dir: &Path, subpath: Option<&str>) -> Option<PathBuf> {
+        let pkg = read_package_json(&pkg_dir.join("package.json")).ok();
 
-                    if result.is_ok() {
-                        return result;
-                    }
+        if let Some(pkg) = &pkg {
+            if self.module_resolution != ModuleResolution::Node10 {
+                if let Some(exports) = &pkg.exports {
+                    // Subpaths which are not exported cannot be imported.
+                    return self.resolve_using_exports(base, pkg_dir, exports, subpath);
                 }
             }
         }
 
-        match base_dir.parent() {
-            Some(parent) => self.resolve_node_modules(parent, target),
-            None => bail!("not found"),
+        match subpath {
+            Some(subpath) => {
+                if let Some(path) = pkg
+                    .as_ref()
+                    .and_then(|pkg| self.resolve_using_types_versions(pkg_dir, pkg, subpath))
+                {
+                    return Some(path);
+                }
+
+                self.try_package(&pkg_dir.join(subpath)).ok()
+            }
+            None => self.try_package(pkg_dir).ok(),
         }
     }
+
+    /// https://nodejs.org/api/packages.html#package-entry-points
+    fn resolve_using_exports(&self, base: &Path, pkg_dir: &Path, exports: &Value, subpath: Option<&str>) -> Option<PathBuf> {
+        let subpath_key = match subpath {
+            Some(subpath) => format!("./{}", subpath),
+            None => ".".to_string(),
+        };
+
+        let (target, matched) = match exports {
+            Value::Object(map) if map.keys().any(|key| key.starts_with('.')) => {
+                best_match(map.iter().map(|(key, target)| (&**key, target)), &subpath_key)?
+            }
+            // `"exports": "./index.js"` is `"exports": { ".": "./index.js" }`.
+            _ if subpath.is_none() => (exports, ""),
+            _ => return None,
+        };
+
+        self.resolve_export_target(base, pkg_dir, target, matched)
+    }
+
+    fn resolve_export_target(&self, base: &Path, pkg_dir: &Path, target: &Value, matched: &str) -> Option<PathBuf> {
+        match target {
+            Value::String(target) => {
+                let path = pkg_dir.join(target.replace('*', matched)).clean();
+
+                self.resolve_as_file(&path).ok().filter(|path| is_ts_file(path))
+            }
+            // Fallbacks.
+            Value::Array(targets) => targets
+                .iter()
+                .find_map(|target| self.resolve_export_target(base, pkg_dir, target, matched)),
+            // Conditions are tried in the order of keys.
+            Value::Object(conditions) => {
+                let enabled = self.conditions(base);
+
+                conditions
+                    .iter()
+                    .filter(|(condition, _)| matches_condition(enabled, condition))
+                    .find_map(|(_, target)| self.resolve_export_target(base, pkg_dir, target, matched))
+            }
+            _ => None,
+        }
+    }
+
+    /// Conditions of `exports` matched while resolving imports in `base`.
+    fn conditions(&self, base: &Path) -> &'static [&'static str] {
+        match self.module_resolution {
+            ModuleResolution::Bundler => BUNDLER_CONDITIONS,
+            _ if self.is_esm(base) => NODE_IMPORT_CONDITIONS,
+            _ => NODE_REQUIRE_CONDITIONS,
+        }
+    }
+
+    /// Returns `true` if `path` is an ES module, based on its extension or
+    /// `"type"` of the nearest package.json.
+    fn is_esm(&self, path: &Path) -> bool {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("mts" | "mjs") => return true,
+            Some("cts" | "cjs") => return false,
+            _ => {}
+        }
+
+        path.parent().map_or(false, |dir| self.is_esm_dir(dir))
+    }
+
+    /// Returns `true` if `"type"` of the nearest package.json of `dir` is
+    /// `"module"`.
+    fn is_esm_dir(&self, dir: &Path) -> bool {
+        if let Some(is_esm) = self.esm_dirs.get(dir) {
+            return *is_esm;
+        }
+
+        let pkg_path = dir.join("package.json");
+        let is_esm = if pkg_path.is_file() {
+            read_package_json(&pkg_path).map_or(false, |pkg| pkg.module_type.as_deref() == Some("module"))
+        } else {
+            dir.parent().map_or(false, |parent| self.is_esm_dir(parent))
+        };
+
+        self.esm_dirs.insert(dir.to_path_buf(), is_esm);
+        is_esm
+    }
+
+    /// Applies the path mapping of `typesVersions` matching [TS_VERSION] to
+    /// `subpath`.
+    fn resolve_using_types_versions(&self, pkg_dir: &Path, pkg: &PackageJson, subpath: &str) -> Option<PathBuf> {
+        let versions = pkg.types_versions.as_ref()?;
+
+        // The first matching range is used.
+        let paths = versions
+            .iter()
+            .find(|(range, _)| matches_version_range(range))
+            .and_then(|(_, paths)| BTreeMap::<String, Vec<String>>::deserialize(paths).ok())?;
+
+        let (substitutions, matched) = best_match(paths.iter().map(|(pat, subs)| (&**pat, subs)), subpath)?;
+
+        substitutions.iter().find_map(|sub| {
+            let path = pkg_dir.join(sub.replacen('*', matched, 1));
+            self.try_package(&path).ok()
+        })
+    }
 }
 
 impl Resolve for NodeResolver {
@@ -153,7 +364,7 @@ impl Resolve for NodeResolver {
         let cwd = &Path::new(".");
         let base_dir = base.parent().unwrap_or(cwd);
 
-        if target.starts_with("./") || target.starts_with("../") {
+        if is_relative(target) {
             let path = base_dir.join(target);
             return self
                 .resolve_as_file(&path)
@@ -165,8 +376,326 @@ impl Resolve for NodeResolver {
                 .and_then(|p| self.wrap(p));
         }
 
-        self.resolve_node_modules(base_dir, target)
+        self.resolve_node_modules(base, base_dir, target)
             .with_context(|| format!("failed to resolve `{}` as a node module from `{}`", target, base.display()))
             .and_then(|p| self.wrap(p))
     }
 }
+
+fn read_package_json(path: &Path) -> Result<PackageJson, Error> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+
+    serde_json::from_reader(reader).context("failed to deserialize package.json")
+}
+
+/// Returns the TypeScript file which is emitted as `path`, if any.
+fn resolve_ts_source(path: &Path) -> Option<PathBuf> {
+    let s = path.to_str()?;
+
+    let (stem, sources) = if let Some(stem) = s.strip_suffix(".js").or_else(|| s.strip_suffix(".jsx")) {
+        (stem, JS_SOURCES)
+    } else if let Some(stem) = s.strip_suffix(".mjs") {
+        (stem, MJS_SOURCES)
+    } else if let Some(stem) = s.strip_suffix(".cjs") {
+        (stem, CJS_SOURCES)
+    } else {
+        return None;
+    };
+
+    sources
+        .iter()
+        .map(|ext| PathBuf::from(format!("{}.{}", stem, ext)))
+        .find(|path| path.is_file())
+}
+
+/// Unlike [Path::with_extension], this preserves dots in the file name.
+pub fn with_extension_appended(path: &Path, ext: &str) -> PathBuf {
+    let mut s = path.as_os_str().to_os_string();
+    s.push(".");
+    s.push(ext);
+    PathBuf::from(s)
+}
+
+/// Returns `true` for `.ts`, `.tsx`, `.mts`, `.cts` and declaration files.
+fn is_ts_file(path: &Path) -> bool {
+    matches!(path.extension().and_then(|ext| ext.to_str()), Some("ts" | "tsx" | "mts" | "cts"))
+}
+
+/// Returns `true` if `condition` is one of `enabled`, or a `types@<range>`
+/// condition matching [TS_VERSION].
+fn matches_condition(enabled: &[&str], condition: &str) -> bool {
+    match condition.strip_prefix("types@") {
+        Some(range) => enabled.contains(&"types") && matches_version_range(range),
+        None => enabled.contains(&condition),
+    }
+}
+
+/// Splits `@scope/name/sub/path` into `@scope/name` and `sub/path`.
+fn split_package_name(target: &str) -> (&str, Option<&str>) {
+    // Names of scoped packages contain a `/`.
+    let skip = match target.strip_prefix('@') {
+        Some(..) => target.find('/').map_or(0, |idx| idx + 1),
+        None => 0,
+    };
+
+    match target[skip..].find('/') {
+        Some(idx) => (
+            &target[..skip + idx],
+            Some(&target[skip + idx + 1..]).filter(|subpath| !subpath.is_empty()),
+        ),
+        None => (target, None),
+    }
+}
+
+/// Typings of `@scope/name` are published as `@types/scope__name`.
+fn types_package_name(name: &str) -> String {
+    match name.strip_prefix('@') {
+        Some(name) => name.replacen('/', "__", 1),
+        None => name.to_string(),
+    }
+}
+
+/// Returns `true` if [TS_VERSION] is in `range`, which is something like
+/// `>=4.1` or `*`.
+fn matches_version_range(range: &str) -> bool {
+    range.split_whitespace().all(|comparator| {
+        let (op, version) = match comparator.find(|c: char| c.is_ascii_digit()) {
+            Some(idx) => comparator.split_at(idx),
+            None => return comparator == "*",
+        };
+        let version = parse_version(version);
+
+        match op {
+            ">=" => TS_VERSION >= version,
+            ">" => TS_VERSION > version,
+            "<=" => TS_VERSION <= version,
+            "<" => TS_VERSION < version,
+            "" | "=" => TS_VERSION == version,
+            _ => false,
+        }
+    })
+}
+
+/// Missing components are zero, so `4.1` is `4.1.0`.
+fn parse_version(s: &str) -> (u64, u64, u64) {
+    let mut parts = s.split('.').map(|part| part.parse().unwrap_or(0));
+
+    (parts.next().unwrap_or(0), parts.next().unwrap_or(0), parts.next().unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use swc_common::FileName;
+    use swc_ecma_loader::resolve::Resolve;
+    use tempdir::TempDir;
+
+    use super::{ModuleResolution, NodeResolver};
+
+    #[test]
+    fn exports_conditions() {
+        let dir = TempDir::new("stc-resolver").unwrap();
+        let root = dir.path();
+
+        let pkg_dir = root.join("node_modules/@scope/pkg");
+        fs::create_dir_all(pkg_dir.join("sub")).unwrap();
+        fs::write(
+            pkg_dir.join("package.json"),
+            r#"{
+                "types": "./legacy.d.ts",
+                "exports": {
+                    ".": {
+                        "import": { "types": "./index.d.mts" },
+                        "require": { "types": "./index.d.cts" }
+                    },
+                    "./sub/*": "./sub/*.js"
+                }
+            }"#,
+        )
+        .unwrap();
+        for file in ["legacy.d.ts", "index.d.mts", "index.d.cts", "sub/a.d.ts"] {
+            fs::write(pkg_dir.join(file), "").unwrap();
+        }
+
+        fs::write(root.join("package.json"), r#"{ "type": "module" }"#).unwrap();
+        let base = FileName::Real(root.join("main.ts"));
+        let resolve = |module_resolution, base: &FileName, target| {
+            NodeResolver::default()
+                .with_module_resolution(module_resolution)
+                .resolve(base, target)
+                .ok()
+        };
+
+        assert_eq!(
+            resolve(ModuleResolution::Node10, &base, "@scope/pkg"),
+            Some(FileName::Real(pkg_dir.join("legacy.d.ts")))
+        );
+        assert_eq!(
+            resolve(ModuleResolution::Node16, &base, "@scope/pkg"),
+            Some(FileName::Real(pkg_dir.join("index.d.mts")))
+        );
+        assert_eq!(
+            resolve(ModuleResolution::Node16, &FileName::Real(root.join("main.cts")), "@scope/pkg"),
+            Some(FileName::Real(pkg_dir.join("index.d.cts")))
+        );
+        assert_eq!(
+            resolve(ModuleResolution::Bundler, &base, "@scope/pkg/sub/a"),
+            Some(FileName::Real(pkg_dir.join("sub/a.d.ts")))
+        );
+        assert_eq!(resolve(ModuleResolution::Bundler, &base, "@scope/pkg/legacy"), None);
+    }
+
+    #[test]
+    fn exports_conditions_in_key_order() {
+        let dir = TempDir::new("stc-resolver").unwrap();
+        let root = dir.path();
+
+        for (name, exports) in [
+            ("import-first", r#"{ "import": "./import.d.ts", "types": "./types.d.ts" }"#),
+            ("types-first", r#"{ "types": "./types.d.ts", "import": "./import.d.ts" }"#),
+            (
+                "versioned",
+                r#"{ "types@>=99": "./future.d.ts", "types@>=4.1": "./ts4.d.ts", "types": "./types.d.ts" }"#,
+            ),
+        ] {
+            let pkg_dir = root.join("node_modules").join(name);
+            fs::create_dir_all(&pkg_dir).unwrap();
+            fs::write(pkg_dir.join("package.json"), format!(r#"{{ "exports": {} }}"#, exports)).unwrap();
+            for file in ["import.d.ts", "types.d.ts", "future.d.ts", "ts4.d.ts"] {
+                fs::write(pkg_dir.join(file), "").unwrap();
+            }
+        }
+
+        fs::write(root.join("package.json"), r#"{ "type": "module" }"#).unwrap();
+        let base = FileName::Real(root.join("main.ts"));
+        let resolver = NodeResolver::default().with_module_resolution(ModuleResolution::Node16);
+
+        assert_eq!(
+            resolver.resolve(&base, "import-first").ok(),
+            Some(FileName::Real(root.join("node_modules/import-first/import.d.ts")))
+        );
+        assert_eq!(
+            resolver.resolve(&base, "types-first").ok(),
+            Some(FileName::Real(root.join("node_modules/types-first/types.d.ts")))
+        );
+        assert_eq!(
+            resolver.resolve(&base, "versioned").ok(),
+            Some(FileName::Real(root.join("node_modules/versioned/ts4.d.ts")))
+        );
+    }
+
+    #[test]
+    fn types_versions() {
+        let dir = TempDir::new("stc-resolver").unwrap();
+        let root = dir.path();
+
+        let pkg_dir = root.join("node_modules/pkg");
+        fs::create_dir_all(pkg_dir.join("ts3.1")).unwrap();
+        fs::create_dir_all(pkg_dir.join("ts4.1")).unwrap();
+        fs::write(
+            pkg_dir.join("package.json"),
+            r#"{
+                "types": "index.d.ts",
+                "typesVersions": {
+                    ">=99": { "*": ["future/*"] },
+                    ">=4.1": { "*": ["ts4.1/*"] },
+                    ">=3.1": { "*": ["ts3.1/*"] }
+                }
+            }"#,
+        )
+        .unwrap();
+        for file in [
+            "index.d.ts",
+            "sub.d.ts",
+            "ts3.1/index.d.ts",
+            "ts3.1/sub.d.ts",
+            "ts4.1/index.d.ts",
+            "ts4.1/sub.d.ts",
+        ] {
+            fs::write(pkg_dir.join(file), "").unwrap();
+        }
+
+        let base = FileName::Real(root.join("main.ts"));
+        let resolver = NodeResolver::default();
+
+        assert_eq!(
+            resolver.resolve(&base, "pkg").ok(),
+            Some(FileName::Real(pkg_dir.join("ts4.1/index.d.ts")))
+        );
+        assert_eq!(
+            resolver.resolve(&base, "pkg/sub").ok(),
+            Some(FileName::Real(pkg_dir.join("ts4.1/sub.d.ts")))
+        );
+    }
+
+    #[test]
+    fn typings_and_main() {
+        let dir = TempDir::new("stc-resolver").unwrap();
+        let root = dir.path();
+
+        let files = [
+            ("typings/package.json", r#"{ "typings": "lib/index.d.ts", "main": "lib/main.js" }"#),
+            ("typings/lib/index.d.ts", ""),
+            ("typings/lib/main.d.ts", ""),
+            ("main/package.json", r#"{ "main": "lib/main.js" }"#),
+            ("main/lib/main.js", ""),
+            ("main/lib/main.d.ts", ""),
+            ("main/index.d.ts", ""),
+            ("untyped-main/package.json", r#"{ "main": "lib/main.js" }"#),
+            ("untyped-main/lib/main.js", ""),
+            ("untyped-main/index.d.ts", ""),
+        ];
+        for (file, content) in files {
+            let path = root.join("node_modules").join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        let base = FileName::Real(root.join("main.ts"));
+        let resolver = NodeResolver::default();
+
+        assert_eq!(
+            resolver.resolve(&base, "typings").ok(),
+            Some(FileName::Real(root.join("node_modules/typings/lib/index.d.ts")))
+        );
+        assert_eq!(
+            resolver.resolve(&base, "main").ok(),
+            Some(FileName::Real(root.join("node_modules/main/lib/main.d.ts")))
+        );
+        // `main` without a declaration file falls back to the index.
+        assert_eq!(
+            resolver.resolve(&base, "untyped-main").ok(),
+            Some(FileName::Real(root.join("node_modules/untyped-main/index.d.ts")))
+        );
+    }
+
+    #[test]
+    fn js_extensions_refer_to_sources() {
+        let dir = TempDir::new("stc-resolver").unwrap();
+        let root = dir.path();
+
+        for file in ["a.ts", "a.js", "b.mts", "c.d.cts", "d.d.ts", "e.tsx"] {
+            fs::write(root.join(file), "").unwrap();
+        }
+
+        let base = FileName::Real(root.join("main.ts"));
+        let resolver = NodeResolver::default();
+
+        for (target, expected) in [
+            ("./a.js", "a.ts"),
+            ("./b.mjs", "b.mts"),
+            ("./c.cjs", "c.d.cts"),
+            ("./d.js", "d.d.ts"),
+            ("./e.jsx", "e.tsx"),
+        ] {
+            assert_eq!(
+                resolver.resolve(&base, target).ok(),
+                Some(FileName::Real(root.join(expected))),
+                "{}",
+                target
+            );
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Error;
+use path_clean::PathClean;
+use swc_common::FileName;
+use swc_ecma_loader::resolve::Resolve;
+
+use super::{is_relative, pattern::best_match};
+
+/// Applies `baseUrl`, `paths` and `rootDirs` of `compilerOptions` before
+/// resolving using `inner`, like `moduleResolution: node` of tsc.
+///
+/// Candidates are resolved by passing absolute paths to `inner`.
+pub struct PathsResolver<R>
+where
+    R: Resolve,
+{
+    inner: R,
+    base_url: Option<PathBuf>,
+    /// Patterns and their absolute substitutions.
+    paths: Vec<(String, Vec<String>)>,
+    root_dirs: Vec<PathBuf>,
+}
+
+impl<R> PathsResolver<R>
+where
+    R: Resolve,
+{
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            base_url: None,
+            paths: vec![],
+            root_dirs: vec![],
+        }
+    }
+
+    /// Non-relative module specifiers are resolved from `base_url` before
+    /// `node_modules`.
+    pub fn with_base_url(mut self, base_url: Option<PathBuf>) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Substitutions should be absolute. See
+    /// https://www.typescriptlang.org/tsconfig#paths
+    pub fn with_paths(mut self, paths: Vec<(String, Vec<String>)>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// Relative module specifiers are resolved as if all of `root_dirs` are
+    /// merged into one directory.
+    pub fn with_root_dirs(mut self, root_dirs: Vec<PathBuf>) -> Self {
+        self.root_dirs = root_dirs;
+        self
+    }
+
+    fn resolve_path(&self, base: &FileName, path: &Path) -> Option<FileName> {
+        self.inner.resolve(base, &path.to_string_lossy()).ok()
+    }
+
+    /// Tries substitutions of the pattern matching `module_specifier`.
+    fn resolve_using_paths(&self, base: &FileName, module_specifier: &str) -> Option<FileName> {
+        let (substitutions, matched) = best_match(self.paths.iter().map(|(pat, subs)| (&**pat, subs)), module_specifier)?;
+
+        substitutions
+            .iter()
+            .find_map(|sub| self.resolve_path(base, Path::new(&sub.replacen('*', matched, 1))))
+    }
+
+    /// Resolves a relative module specifier in each of `rootDirs`.
+    fn resolve_using_root_dirs(&self, base: &FileName, module_specifier: &str) -> Option<FileName> {
+        let base_dir = match base {
+            FileName::Real(base) => base.parent()?,
+            _ => return None,
+        };
+        let candidate = base_dir.join(module_specifier).clean();
+
+        let matched = self
+            .root_dirs
+            .iter()
+            .filter(|dir| candidate.starts_with(dir))
+            .max_by_key(|dir| dir.components().count())?;
+        let suffix = candidate.strip_prefix(matched).ok()?;
+
+        self.root_dirs
+            .iter()
+            .filter(|dir| *dir != matched)
+            .find_map(|dir| self.resolve_path(base, &dir.join(suffix)))
+    }
+}
+
+impl<R> Resolve for PathsResolver<R>
+where
+    R: Resolve,
+{
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error> {
+        if is_relative(module_specifier) {
+            return self.inner.resolve(base, module_specifier).or_else(|err| {
+                // The directory containing `base` is tried first.
+                self.resolve_using_root_dirs(base, module_specifier).ok_or(err)
+            });
+        }
+
+        if Path::new(module_specifier).is_absolute() {
+            return self.inner.resolve(base, module_specifier);
+        }
+
+        if let Some(resolved) = self.resolve_using_paths(base, module_specifier) {
+            return Ok(resolved);
+        }
+
+        if let Some(base_url) = &self.base_url {
+            if let Some(resolved) = self.resolve_path(base, &base_url.join(module_specifier)) {
+                return Ok(resolved);
+            }
+        }
+
+        self.inner.resolve(base, module_specifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use swc_common::FileName;
+    use swc_ecma_loader::resolve::Resolve;
+    use tempdir::TempDir;
+
+    use super::PathsResolver;
+    use crate::resolvers::node::NodeResolver;
+
+    /// Creates empty files in `root`.
+    fn create_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    fn paths(root: &Path, paths: &[(&str, &[&str])]) -> Vec<(String, Vec<String>)> {
+        paths
+            .iter()
+            .map(|(pat, subs)| {
+                (
+                    pat.to_string(),
+                    subs.iter().map(|sub| root.join(sub).to_string_lossy().into_owned()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn exact_pattern_is_preferred() {
+        let dir = TempDir::new("stc-paths").unwrap();
+        let root = dir.path();
+        create_files(root, &["src/config.ts", "config/main.ts", "src/utils.ts"]);
+
+        let resolver = PathsResolver::new(NodeResolver::default())
+            .with_paths(paths(root, &[("@app/*", &["src/*"]), ("@app/config", &["config/main"])]));
+        let base = FileName::Real(root.join("main.ts"));
+
+        assert_eq!(
+            resolver.resolve(&base, "@app/config").unwrap(),
+            FileName::Real(root.join("config/main.ts"))
+        );
+        assert_eq!(
+            resolver.resolve(&base, "@app/utils").unwrap(),
+            FileName::Real(root.join("src/utils.ts"))
+        );
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        let dir = TempDir::new("stc-paths").unwrap();
+        let root = dir.path();
+        create_files(root, &["src/utils/a.ts", "lib/utils/a.ts"]);
+
+        let resolver = PathsResolver::new(NodeResolver::default())
+            .with_paths(paths(root, &[("@app/*", &["src/*"]), ("@app/utils/*", &["lib/utils/*"])]));
+        let base = FileName::Real(root.join("main.ts"));
+
+        assert_eq!(
+            resolver.resolve(&base, "@app/utils/a").unwrap(),
+            FileName::Real(root.join("lib/utils/a.ts"))
+        );
+    }
+
+    #[test]
+    fn substitutions_are_tried_in_order() {
+        let dir = TempDir::new("stc-paths").unwrap();
+        let root = dir.path();
+        create_files(root, &["generated/b.ts", "shared/a.ts", "shared/b.ts"]);
+
+        let resolver = PathsResolver::new(NodeResolver::default()).with_paths(paths(root, &[("shared/*", &["generated/*", "shared/*"])]));
+        let base = FileName::Real(root.join("main.ts"));
+
+        assert_eq!(
+            resolver.resolve(&base, "shared/a").unwrap(),
+            FileName::Real(root.join("shared/a.ts"))
+        );
+        assert_eq!(
+            resolver.resolve(&base, "shared/b").unwrap(),
+            FileName::Real(root.join("generated/b.ts"))
+        );
+    }
+
+    #[test]
+    fn base_url_without_paths() {
+        let dir = TempDir::new("stc-paths").unwrap();
+        let root = dir.path();
+        create_files(root, &["src/utils.ts", "src/app/main.ts"]);
+
+        let resolver = PathsResolver::new(NodeResolver::default()).with_base_url(Some(root.join("src")));
+        let base = FileName::Real(root.join("src/app/main.ts"));
+
+        assert_eq!(resolver.resolve(&base, "utils").unwrap(), FileName::Real(root.join("src/utils.ts")));
+        assert!(resolver.resolve(&base, "missing").is_err());
+    }
+
+    #[test]
+    fn dot_is_relative() {
+        let dir = TempDir::new("stc-paths").unwrap();
+        let root = dir.path();
+        create_files(root, &["index.ts", "src/index.ts", "src/app/index.ts"]);
+
+        let resolver = PathsResolver::new(NodeResolver::default())
+            .with_base_url(Some(root.to_path_buf()))
+            .with_paths(paths(root, &[("*", &["*"])]));
+        let base = FileName::Real(root.join("src/app/main.ts"));
+
+        assert_eq!(resolver.resolve(&base, ".").unwrap(), FileName::Real(root.join("src/app/index.ts")));
+        assert_eq!(resolver.resolve(&base, "..").unwrap(), FileName::Real(root.join("src/index.ts")));
+    }
+}
@@ -0,0 +1,62 @@
+//! Patterns with one `*`, used by `paths`, `typesVersions`, `exports` and
+//! `declare module "foo*"`.
+
+/// Returns the text matched by `*` if `s` matches `pat`.
+pub(crate) fn match_pattern<'a>(pat: &str, s: &'a str) -> Option<&'a str> {
+    let (prefix, suffix) = pat.split_once('*')?;
+
+    if s.len() < prefix.len() + suffix.len() {
+        return None;
+    }
+
+    s.strip_prefix(prefix)?.strip_suffix(suffix)
+}
+
+/// Returns the value of the best pattern matching `s` and the text matched by
+/// `*`.
+///
+/// An exact match is preferred, and the pattern with the longest prefix wins
+/// otherwise.
+pub(crate) fn best_match<'a, 's, T>(patterns: impl IntoIterator<Item = (&'a str, T)>, s: &'s str) -> Option<(T, &'s str)> {
+    let mut best: Option<(usize, T, &'s str)> = None;
+
+    for (pat, value) in patterns {
+        if pat == s {
+            return Some((value, ""));
+        }
+
+        if let Some(matched) = match_pattern(pat, s) {
+            let prefix_len = pat.find('*').unwrap_or_default();
+
+            if best.as_ref().map_or(true, |(len, ..)| prefix_len > *len) {
+                best = Some((prefix_len, value, matched));
+            }
+        }
+    }
+
+    best.map(|(_, value, matched)| (value, matched))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{best_match, match_pattern};
+
+    #[test]
+    fn pattern() {
+        assert_eq!(match_pattern("@app/*", "@app/foo/bar"), Some("foo/bar"));
+        assert_eq!(match_pattern("*.css", "a.css"), Some("a"));
+        assert_eq!(match_pattern("a*a", "a"), None);
+        assert_eq!(match_pattern("@app/*", "@lib/foo"), None);
+        assert_eq!(match_pattern("@app", "@app"), None);
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        let patterns = [("*", 0), ("@app/*", 1), ("@app/utils/*", 2), ("@app/utils/a", 3)];
+
+        assert_eq!(best_match(patterns, "@app/utils/b"), Some((2, "b")));
+        assert_eq!(best_match(patterns, "@app/utils/a"), Some((3, "")));
+        assert_eq!(best_match(patterns, "@app/b"), Some((1, "b")));
+        assert_eq!(best_match(patterns, "lodash"), Some((0, "lodash")));
+    }
+}
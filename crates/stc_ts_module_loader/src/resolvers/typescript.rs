@@ -6,6 +6,8 @@ use swc_atoms::JsWord;
 use swc_common::FileName;
 use swc_ecma_loader::resolve::Resolve;
 
+use super::pattern::best_match;
+
 pub(crate) struct TsResolver<R>
 where
     R: Resolve,
@@ -25,19 +27,29 @@ where
     }
 
     /// This returns [FileName::Custom] for `declare module "http"`-s.
+    ///
+    /// Like tsc, `declare module "*.css"` is used only if there's no file for
+    /// `module_specifier`, and the pattern with the longest prefix wins.
     pub(crate) fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<Arc<FileName>, Error> {
-        for (pat, path) in self.declared_modules.read().iter() {
-            if matches(pat, module_specifier) {
-                return Ok(path.clone());
-            }
+        if let Some((_, path)) = self.declared_modules.read().iter().find(|(name, _)| &**name == module_specifier) {
+            return Ok(path.clone());
         }
 
-        let resolved = self
-            .resolver
-            .resolve(base, module_specifier)
-            .with_context(|| "failed to resolve dependency")?;
+        let err = match self.resolver.resolve(base, module_specifier) {
+            Ok(resolved) => return Ok(Arc::new(resolved)),
+            Err(err) => err,
+        };
+
+        let declared_modules = self.declared_modules.read();
+        let patterns = declared_modules
+            .iter()
+            .filter(|(name, _)| name.contains('*'))
+            .map(|(name, path)| (&**name, path));
+        if let Some((path, _)) = best_match(patterns, module_specifier) {
+            return Ok(path.clone());
+        }
 
-        Ok(Arc::new(resolved))
+        Err(err).with_context(|| "failed to resolve dependency")
     }
 
     pub(crate) fn declare_module(&self, decl: JsWord) {
@@ -48,11 +60,3 @@ where
             .push((decl.clone(), Arc::new(FileName::Custom(decl.to_string()))));
     }
 }
-
-fn matches(pat: &JsWord, module_specifier: &str) -> bool {
-    if &**pat == module_specifier {
-        return true;
-    }
-
-    false
-}
@@ -70,7 +70,7 @@ fn run_bench(b: &mut Bencher, path: &Path) {
                 ),
                 TsConfig { ..Default::default() },
                 None,
-                Arc::new(NodeResolver),
+                Arc::new(NodeResolver::default()),
             );
 
             let id = checker.check(Arc::new(FileName::Real(path.to_path_buf())));
@@ -0,0 +1,305 @@
+//! Persistent cache of analysis results, which is an equivalent of
+//! `.tsbuildinfo` of `tsc`.
+//!
+//! Types can't be reused across processes because spans and syntax contexts
+//! differ, so the cache only records whether a module can be skipped. A module
+//! is skipped if its source and the signatures of its dependencies are not
+//! changed and it had no error. The signature of a module is the hash of its
+//! `.d.ts` output, which changes only if exported types change.
+//!
+//! As `.d.ts` output may re-export or reference types of other modules, a
+//! dependency is compared using the signatures of all modules it depends on,
+//! directly or indirectly.
+//!
+//! Global scripts and files declaring ambient modules are not skipped, and the
+//! whole cache is discarded if one of them is changed, because they affect
+//! modules which don't depend on them or their `.d.ts` output does not contain
+//! their declarations.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use fxhash::{FxHashMap, FxHashSet};
+use rnode::RNode;
+use serde::{Deserialize, Serialize};
+use stc_ts_ast_rnode::RModule;
+use stc_ts_types::ModuleId;
+use swc_common::{FileName, Span, DUMMY_SP};
+use swc_ecma_visit::{Fold, FoldWith};
+use xxhash_rust::xxh3::{xxh3_64, Xxh3};
+
+use crate::Checker;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BuildInfo {
+    /// Compiler version and options used to create the cache.
+    key: String,
+
+    /// Hashes of global scripts and files declaring ambient modules or
+    /// augmenting modules.
+    ambient: FxHashMap<PathBuf, u64>,
+
+    modules: FxHashMap<PathBuf, ModuleInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ModuleInfo {
+    hash: u64,
+    signature: u64,
+    /// Closure signatures of dependencies at the time of analysis. See
+    /// [Checker::closure_signature].
+    deps: Vec<(PathBuf, u64)>,
+}
+
+/// Cache loaded from and saved to a file.
+#[derive(Debug)]
+pub struct BuildCache {
+    path: PathBuf,
+    /// Loaded from `path`.
+    info: BuildInfo,
+    /// If `false`, all modules are analyzed and the cache is only saved.
+    skip: bool,
+}
+
+impl BuildCache {
+    /// Loads the cache at `path`. The cache is ignored if it does not exist,
+    /// it's created with a different `key` or an ambient module is changed.
+    ///
+    /// `key` should contain all inputs which affect analysis, other than
+    /// source files.
+    pub fn load(path: PathBuf, key: String) -> Self {
+        let info = fs::read(&path)
+            .ok()
+            .and_then(|data| rmp_serde::decode::from_slice::<BuildInfo>(&data).ok())
+            .filter(|info| info.key == key)
+            .filter(|info| {
+                info.ambient
+                    .iter()
+                    .all(|(path, &hash)| fs::read_to_string(path).ok().map(|src| hash_of(src.as_bytes())) == Some(hash))
+            })
+            .unwrap_or_else(|| BuildInfo { key, ..Default::default() });
+
+        log::debug!("Loaded {} modules from the build cache at `{}`", info.modules.len(), path.display());
+
+        BuildCache { path, info, skip: true }
+    }
+
+    /// If `skip` is `false`, unchanged modules are analyzed too and the cache
+    /// is only updated. `.d.ts` outputs are created only by analysis, so this
+    /// is required to emit them.
+    pub fn with_skipping(mut self, skip: bool) -> Self {
+        self.skip = skip;
+        self
+    }
+}
+
+impl Checker {
+    /// Returns true if analysis of `id` can be skipped.
+    pub(crate) fn is_fresh(&self, id: ModuleId) -> bool {
+        if self.cache.is_none() {
+            return false;
+        }
+
+        if let Some(fresh) = self.fresh.get(&id) {
+            return *fresh;
+        }
+
+        let fresh = self.is_fresh_uncached(id);
+        self.fresh.insert(id, fresh);
+        fresh
+    }
+
+    fn is_fresh_uncached(&self, id: ModuleId) -> bool {
+        let cache = match &self.cache {
+            Some(cache) if cache.skip => cache,
+            _ => return false,
+        };
+
+        // Circular modules are analyzed at once.
+        if self.module_graph.get_circular(id).is_some() {
+            return false;
+        }
+
+        let info = match self.real_path(id).and_then(|path| cache.info.modules.get(&path)) {
+            Some(info) => info,
+            None => return false,
+        };
+        if self.hash(id) != Some(info.hash) {
+            return false;
+        }
+
+        let deps = self.module_graph.dependencies(id);
+        if deps.len() != info.deps.len() {
+            return false;
+        }
+        for dep in deps {
+            let signature = match self.real_path(dep).and_then(|path| info.deps.iter().find(|(p, _)| *p == path)) {
+                Some((_, signature)) => *signature,
+                None => return false,
+            };
+
+            if self.closure_signature(dep, true) != Some(signature) {
+                return false;
+            }
+        }
+
+        self.signatures.insert(id, info.signature);
+
+        true
+    }
+
+    /// Analyzes `id` if it's not fresh.
+    fn signature(&self, id: ModuleId) -> Option<u64> {
+        if !self.is_fresh(id) {
+            self.analyze_module(None, self.module_graph.path(id));
+        }
+
+        self.signatures.get(&id).map(|v| *v)
+    }
+
+    /// Hash of the signatures of `id` and all modules it depends on, directly
+    /// or indirectly.
+    ///
+    /// If `analyze` is `true`, modules which are not fresh are analyzed to get
+    /// their signatures.
+    fn closure_signature(&self, id: ModuleId, analyze: bool) -> Option<u64> {
+        let mut closure = vec![];
+        let mut visited = FxHashSet::default();
+        let mut queue = vec![id];
+
+        while let Some(id) = queue.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+
+            let path = self.real_path(id)?;
+            let signature = if analyze { self.signature(id)? } else { *self.signatures.get(&id)? };
+            closure.push((path, signature));
+
+            queue.extend(self.module_graph.dependencies(id));
+        }
+
+        closure.sort();
+
+        let mut hasher = Xxh3::new();
+        for (path, signature) in &closure {
+            hasher.update(path.to_string_lossy().as_bytes());
+            hasher.update(&[0]);
+            hasher.update(&signature.to_le_bytes());
+        }
+        Some(hasher.digest())
+    }
+
+    /// Records the signature of `id`, if the cache is enabled.
+    pub(crate) fn record_signature(&self, id: ModuleId, dts_module: &RModule) {
+        if self.cache.is_none() {
+            return;
+        }
+
+        let module = dts_module.clone().into_orig().fold_with(&mut SpanRemover);
+
+        self.signatures.insert(id, hash_of(format!("{:?}", module).as_bytes()));
+    }
+
+    /// Writes the cache file. Modules with errors are not stored, so they are
+    /// analyzed again.
+    ///
+    /// This is noop if the cache is not enabled.
+    pub fn save_cache(&self) -> io::Result<()> {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return Ok(()),
+        };
+
+        let with_errors = self.errors.lock().iter().map(|(id, _)| *id).collect::<FxHashSet<_>>();
+        let mut ambient = self
+            .declared_modules
+            .read()
+            .iter()
+            .chain(self.module_augmentations.read().iter())
+            .map(|(base, ..)| *base)
+            .collect::<FxHashSet<_>>();
+        ambient.extend(
+            self.signatures
+                .iter()
+                .map(|entry| *entry.key())
+                .filter(|&id| !self.module_graph.is_module(id)),
+        );
+
+        let mut info = BuildInfo {
+            key: cache.info.key.clone(),
+            ..Default::default()
+        };
+
+        for &id in &ambient {
+            if let (Some(path), Some(hash)) = (self.real_path(id), self.hash(id)) {
+                info.ambient.insert(path, hash);
+            }
+        }
+
+        for entry in self.signatures.iter() {
+            let (id, signature) = (*entry.key(), *entry.value());
+            if with_errors.contains(&id) || ambient.contains(&id) || self.module_graph.get_circular(id).is_some() {
+                continue;
+            }
+
+            let (path, hash) = match (self.real_path(id), self.hash(id)) {
+                (Some(path), Some(hash)) => (path, hash),
+                _ => continue,
+            };
+            let deps = self
+                .module_graph
+                .dependencies(id)
+                .into_iter()
+                .map(|dep| Some((self.real_path(dep)?, self.closure_signature(dep, false)?)))
+                .collect::<Option<Vec<_>>>();
+
+            if let Some(deps) = deps {
+                info.modules.insert(path, ModuleInfo { hash, signature, deps });
+            }
+        }
+
+        let data = rmp_serde::encode::to_vec(&info).map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+
+        if let Some(dir) = cache.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&cache.path, data)
+    }
+
+    fn real_path(&self, id: ModuleId) -> Option<PathBuf> {
+        match &*self.module_graph.path(id) {
+            FileName::Real(path) => Some(path.clone()),
+            _ => None,
+        }
+    }
+
+    /// Hash of the source code of a loaded module.
+    fn hash(&self, id: ModuleId) -> Option<u64> {
+        let fm = self.module_graph.source_file(&self.module_graph.path(id))?;
+
+        Some(hash_of(fm.src.as_bytes()))
+    }
+}
+
+/// Returns the default path of the cache file, for a project at `dir`.
+pub fn default_cache_path(dir: &Path) -> PathBuf {
+    dir.join(".stc").join("build-info.rmp")
+}
+
+/// Unlike [std::collections::hash_map::DefaultHasher], xxh3 produces the same
+/// hash across processes and Rust versions.
+fn hash_of(data: &[u8]) -> u64 {
+    xxh3_64(data)
+}
+
+/// Makes the signature independent of the position of a module.
+struct SpanRemover;
+
+impl Fold for SpanRemover {
+    fn fold_span(&mut self, _: Span) -> Span {
+        DUMMY_SP
+    }
+}
@@ -0,0 +1,185 @@
+//! Comment directives like `// @ts-ignore` and `// @ts-nocheck`.
+
+use std::collections::BTreeMap;
+
+use stc_ts_errors::{Error, ErrorKind};
+use stc_ts_utils::{
+    directives::{parse_check_pragma, CommentDirective},
+    StcComments,
+};
+use swc_common::{comments::Comment, sync::Lrc, BytePos, FileName, SourceFile, SourceMap, Span, Spanned};
+
+/// Removes errors suppressed by comment directives, and reports unused
+/// `// @ts-expect-error`s.
+pub(crate) fn apply_comment_directives(cm: &SourceMap, comments: &StcComments, errors: Vec<Error>) -> Vec<Error> {
+    let mut files = BTreeMap::<BytePos, FileDirectives>::new();
+
+    for map in [&comments.leading, &comments.trailing] {
+        for entry in map.iter() {
+            for cmt in entry.value() {
+                if cmt.span.is_dummy() || !cmt.text.contains("@ts-") {
+                    continue;
+                }
+
+                let file = cm.lookup_source_file(cmt.span.lo);
+                files.entry(file.start_pos).or_insert_with(|| FileDirectives::new(file)).add(cmt);
+            }
+        }
+    }
+
+    let mut buf = Vec::with_capacity(errors.len());
+
+    for err in ErrorKind::flatten(errors) {
+        let span = err.span();
+
+        if !span.is_dummy() {
+            let file = cm.lookup_source_file(span.lo);
+            let file = files.entry(file.start_pos).or_insert_with(|| FileDirectives::new(file));
+
+            if file.suppresses(span.lo) {
+                continue;
+            }
+        }
+
+        buf.push(err);
+    }
+
+    for file in files.values() {
+        buf.extend(file.unused_expect_errors());
+    }
+
+    buf
+}
+
+struct FileDirectives {
+    file: Lrc<SourceFile>,
+    /// `// @ts-check` at the top of the file.
+    check: bool,
+    /// `// @ts-nocheck` at the top of the file.
+    no_check: bool,
+    /// Key is the line of the directive.
+    directives: BTreeMap<usize, Directive>,
+}
+
+struct Directive {
+    span: Span,
+    kind: CommentDirective,
+    used: bool,
+}
+
+impl FileDirectives {
+    fn new(file: Lrc<SourceFile>) -> Self {
+        Self {
+            file,
+            check: false,
+            no_check: false,
+            directives: Default::default(),
+        }
+    }
+
+    fn add(&mut self, cmt: &Comment) {
+        if let Some(kind) = CommentDirective::parse(cmt) {
+            if let Some(line) = self.file.lookup_line(cmt.span.hi) {
+                self.directives.insert(
+                    line,
+                    Directive {
+                        span: cmt.span,
+                        kind,
+                        used: false,
+                    },
+                );
+            }
+            return;
+        }
+
+        if let Some(check) = parse_check_pragma(cmt) {
+            let start = (cmt.span.lo - self.file.start_pos).0 as usize;
+            if !is_header(&self.file.src[..start]) {
+                return;
+            }
+
+            if check {
+                self.check = true;
+            } else {
+                self.no_check = true;
+            }
+        }
+    }
+
+    /// JavaScript files are checked only if `// @ts-check` is used.
+    fn is_checked(&self) -> bool {
+        if self.no_check {
+            return false;
+        }
+
+        self.check || !is_js(&self.file.name)
+    }
+
+    /// Returns `true` if an error at `pos` should not be reported.
+    ///
+    /// Like `tsc`, a directive applies to the next line which is not empty nor
+    /// a comment.
+    fn suppresses(&mut self, pos: BytePos) -> bool {
+        if !self.is_checked() {
+            return true;
+        }
+
+        let mut line = match self.file.lookup_line(pos) {
+            Some(line) => line,
+            None => return false,
+        };
+
+        while line > 0 {
+            line -= 1;
+
+            if let Some(directive) = self.directives.get_mut(&line) {
+                directive.used = true;
+                return true;
+            }
+
+            let text = self.file.get_line(line).unwrap_or_default();
+            let text = text.trim();
+            if !text.is_empty() && !text.starts_with("//") {
+                return false;
+            }
+        }
+
+        false
+    }
+
+    fn unused_expect_errors(&self) -> impl '_ + Iterator<Item = Error> {
+        self.directives
+            .values()
+            .filter(move |d| self.is_checked() && !d.used && d.kind == CommentDirective::ExpectError)
+            .map(|d| Error::from(ErrorKind::UnusedExpectErrorDirective { span: d.span }))
+    }
+}
+
+fn is_js(name: &FileName) -> bool {
+    match name {
+        FileName::Real(path) => matches!(path.extension().and_then(|ext| ext.to_str()), Some("js" | "jsx" | "mjs" | "cjs")),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `src` consists only of whitespaces and comments.
+fn is_header(mut src: &str) -> bool {
+    loop {
+        src = src.trim_start();
+
+        if src.is_empty() {
+            return true;
+        }
+
+        if let Some(rest) = src.strip_prefix("//").or_else(|| src.strip_prefix("#!")) {
+            src = rest.find('\n').map_or("", |idx| &rest[idx..]);
+        } else if let Some(rest) = src.strip_prefix("/*") {
+            match rest.find("*/") {
+                Some(idx) => src = &rest[idx + 2..],
+                None => return false,
+            }
+        } else {
+            return false;
+        }
+    }
+}
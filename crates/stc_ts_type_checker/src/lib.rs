@@ -12,14 +12,17 @@ use stc_ts_ast_rnode::{RModule, RStr, RTsModuleName};
 use stc_ts_dts::{apply_mutations, cleanup_module_for_dts};
 use stc_ts_env::Env;
 use stc_ts_errors::{debug::debugger::Debugger, Error};
-use stc_ts_file_analyzer::{analyzer::Analyzer, loader::Load, validator::ValidateWith, ModuleTypeData, VResult};
-use stc_ts_module_loader::ModuleGraph;
+use stc_ts_file_analyzer::{
+    analyzer::Analyzer, completion::Completions, loader::Load, symbol_table::SymbolTable, type_table::TypeTable, validator::ValidateWith,
+    ModuleTypeData, VResult,
+};
+use stc_ts_module_loader::{is_dts, ModuleGraph};
 use stc_ts_storage::{ErrorStore, File, Group, Single};
 use stc_ts_types::{ModuleId, Type};
 use stc_ts_utils::StcComments;
 use stc_utils::{cache::Freeze, early_error, panic_ctx};
 use swc_atoms::JsWord;
-use swc_common::{errors::Handler, FileName, SourceMap, Spanned, DUMMY_SP};
+use swc_common::{errors::Handler, sync::Lrc, FileName, SourceFile, SourceMap, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::Module;
 use swc_ecma_loader::resolve::Resolve;
 use swc_ecma_parser::TsConfig;
@@ -27,6 +30,11 @@ use swc_ecma_transforms::resolver;
 use swc_ecma_visit::FoldWith;
 use tracing::{info, warn};
 
+pub use crate::cache::{default_cache_path, BuildCache};
+use crate::directives::apply_comment_directives;
+
+mod cache;
+mod directives;
 mod typings;
 
 /// Onc instance per swc::Compiler
@@ -36,7 +44,12 @@ pub struct Checker {
     /// Cache
     module_types: RwLock<FxHashMap<ModuleId, Arc<OnceCell<Type>>>>,
 
-    declared_modules: RwLock<Vec<(ModuleId, Type)>>,
+    /// `declare module "foo"`, with the declaring module and the id of `foo`.
+    declared_modules: RwLock<Vec<(ModuleId, ModuleId, Type)>>,
+
+    /// `declare module "./foo"` in modules, with the declaring module and the
+    /// augmented module.
+    module_augmentations: RwLock<Vec<(ModuleId, ModuleId, Type)>>,
 
     /// Informatnion required to generate `.d.ts` files.
     dts_modules: Arc<DashMap<ModuleId, RModule, FxBuildHasher>>,
@@ -46,11 +59,27 @@ pub struct Checker {
     /// Modules which are being processed or analyzed.
     started: Arc<DashSet<ModuleId, FxBuildHasher>>,
 
-    errors: Mutex<Vec<Error>>,
+    /// Errors with the analyzed module, which is the first requested module
+    /// for a circular group.
+    errors: Mutex<Vec<(ModuleId, Error)>>,
 
     env: Env,
 
     debugger: Option<Debugger>,
+
+    type_table: Option<TypeTable>,
+
+    symbol_table: Option<SymbolTable>,
+
+    completions: Option<Completions>,
+
+    cache: Option<BuildCache>,
+
+    /// Whether modules can be skipped, computed using `cache`.
+    fresh: DashMap<ModuleId, bool, FxBuildHasher>,
+
+    /// Signatures of analyzed or skipped modules. Empty if `cache` is [None].
+    signatures: DashMap<ModuleId, u64, FxBuildHasher>,
 }
 
 impl Checker {
@@ -70,14 +99,57 @@ impl Checker {
             handler,
             module_types: Default::default(),
             dts_modules: Default::default(),
-            module_graph: Arc::new(ModuleGraph::new(cm, Default::default(), resolver, parser_config, env.target())),
+            module_graph: Arc::new(
+                ModuleGraph::new(cm, Default::default(), resolver, parser_config, env.target())
+                    .with_jsx_runtime(env.jsx().runtime_module()),
+            ),
             started: Default::default(),
             errors: Default::default(),
             debugger,
+            type_table: None,
+            symbol_table: None,
+            completions: None,
             declared_modules: Default::default(),
+            module_augmentations: Default::default(),
+            cache: None,
+            fresh: Default::default(),
+            signatures: Default::default(),
         }
     }
 
+    /// Skips analysis of modules which are not changed since `cache` is
+    /// saved. See [Checker::save_cache].
+    pub fn with_cache(mut self, cache: BuildCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Records types of expressions and declared identifiers of all analyzed
+    /// modules to `type_table`.
+    pub fn with_type_table(mut self, type_table: TypeTable) -> Self {
+        self.type_table = Some(type_table);
+        self
+    }
+
+    /// Records declarations, references and exports of all analyzed modules
+    /// to `symbol_table`.
+    pub fn with_symbol_table(mut self, symbol_table: SymbolTable) -> Self {
+        self.symbol_table = Some(symbol_table);
+        self
+    }
+
+    /// Collects completions requested by `completions` while analyzing
+    /// modules.
+    pub fn with_completions(mut self, completions: Completions) -> Self {
+        self.completions = Some(completions);
+        self
+    }
+
+    /// Same as [Checker::with_completions], but for a checker which is reused.
+    pub fn set_completions(&mut self, completions: Option<Completions>) {
+        self.completions = completions;
+    }
+
     pub fn run<F, R>(&self, op: F) -> R
     where
         F: FnOnce() -> R,
@@ -97,15 +169,39 @@ impl Checker {
         lock.get(&id).and_then(|v| v.get().cloned())
     }
 
+    /// Comments of all loaded files, which are required to print JSDoc
+    /// comments of `.d.ts` files.
+    pub fn comments(&self) -> &StcComments {
+        self.module_graph.comments()
+    }
+
     /// Removes dts module from `self` and return it.
     pub fn take_dts(&self, id: ModuleId) -> Option<Module> {
         self.dts_modules.remove(&id).map(|v| v.1.into_orig())
     }
 
+    /// Returns the source file which is being used for `path`.
+    ///
+    /// Unlike [SourceMap::get_source_file], this does not return a previous
+    /// version of a file which is changed and parsed again.
+    pub fn source_file(&self, path: &Arc<FileName>) -> Option<Lrc<SourceFile>> {
+        self.module_graph.source_file(path)
+    }
+
     pub fn id(&self, path: &Arc<FileName>) -> ModuleId {
         self.module_graph.id(path)
     }
 
+    /// Loads `entry` and its dependencies without analyzing them.
+    ///
+    /// Module augmentations in loaded files apply to modules analyzed after
+    /// this, even if they don't import the augmenting files.
+    pub fn load(&self, entry: Arc<FileName>) {
+        self.run(|| {
+            let _ = self.module_graph.load_all(&entry);
+        })
+    }
+
     /// After calling this method, you can get errors using `.take_errors()`
     pub fn check(&self, entry: Arc<FileName>) -> ModuleId {
         self.run(|| {
@@ -116,8 +212,23 @@ impl Checker {
             let end = Instant::now();
             log::debug!("Loading of `{}` and dependencies took {:?}", entry, end - start);
 
+            if let Ok(id) = id {
+                if self.is_fresh(id) {
+                    log::debug!("Skipping analysis of `{}` as it's not changed", entry);
+                    return id;
+                }
+            }
+
             let start = Instant::now();
 
+            // Augmentations are declared while analyzing, so modules declaring them are
+            // analyzed before the modules they augment are used.
+            for id in self.module_graph.augmenting_modules() {
+                if !self.started.contains(&id) {
+                    self.analyze_module(None, self.module_graph.path(id));
+                }
+            }
+
             self.analyze_module(None, entry.clone());
 
             let end = Instant::now();
@@ -127,8 +238,104 @@ impl Checker {
         })
     }
 
+    /// Errors suppressed by comment directives like `// @ts-ignore` are not
+    /// included.
     pub fn take_errors(&mut self) -> Vec<Error> {
-        take(self.errors.get_mut())
+        let errors = take(self.errors.get_mut()).into_iter().map(|(_, err)| err).collect();
+
+        apply_comment_directives(&self.cm, self.module_graph.comments(), errors)
+    }
+
+    /// Errors of all analyzed modules. Unlike [Checker::take_errors], errors
+    /// are kept until their modules are invalidated.
+    pub fn errors(&self) -> Vec<Error> {
+        let errors = self.errors.lock().iter().map(|(_, err)| err.clone()).collect();
+
+        apply_comment_directives(&self.cm, self.module_graph.comments(), errors)
+    }
+
+    /// Forgets changed files and modules depending on them, so they are
+    /// analyzed again by the next call to [Checker::check]. Types of other
+    /// modules are reused.
+    ///
+    /// Returns the number of invalidated modules.
+    pub fn invalidate(&self, changed: &[Arc<FileName>]) -> usize {
+        let mut changed_ids = changed.iter().map(|path| self.module_graph.id(path)).collect::<Vec<_>>();
+
+        // A file which is not loaded may be created, and imports which could not be
+        // resolved may be resolved to it.
+        if changed_ids.iter().any(|&id| !self.module_graph.is_loaded(id)) {
+            changed_ids.extend(self.module_graph.modules_with_unresolved_imports());
+        }
+
+        let mut ids = self.module_graph.dependents(&changed_ids);
+        // Modules declared or augmented by invalidated modules are changed too.
+        loop {
+            let declared = self
+                .declared_modules
+                .read()
+                .iter()
+                .chain(self.module_augmentations.read().iter())
+                .filter(|(base, ..)| ids.contains(base))
+                .map(|(_, id, _)| *id)
+                .collect::<Vec<_>>();
+
+            let new = self
+                .module_graph
+                .dependents(&declared)
+                .into_iter()
+                .filter(|id| !ids.contains(id))
+                .collect::<Vec<_>>();
+            if new.is_empty() {
+                break;
+            }
+            ids.extend(new);
+        }
+
+        // Entries of invalidated modules are recorded again while analyzing.
+        if self.type_table.is_some() || self.symbol_table.is_some() {
+            for &id in &ids {
+                let file = match self.module_graph.source_file(&self.module_graph.path(id)) {
+                    Some(file) => file,
+                    None => continue,
+                };
+
+                if let Some(type_table) = &self.type_table {
+                    type_table.forget(file.start_pos, file.end_pos);
+                }
+                if let Some(symbol_table) = &self.symbol_table {
+                    symbol_table.forget(id, file.start_pos, file.end_pos);
+                }
+            }
+        }
+
+        // Changed files are added to the source map again, with new comments.
+        for path in changed {
+            if let Some(file) = self.module_graph.source_file(path) {
+                self.module_graph.comments().remove_range(file.start_pos, file.end_pos);
+            }
+        }
+
+        self.module_graph.invalidate(&ids, changed);
+
+        // They are declared again while analyzing.
+        self.declared_modules.write().retain(|(base, ..)| !ids.contains(base));
+        self.module_augmentations.write().retain(|(base, ..)| !ids.contains(base));
+
+        {
+            let mut module_types = self.module_types.write();
+            for id in &ids {
+                module_types.remove(id);
+                self.started.remove(id);
+                self.dts_modules.remove(id);
+                self.fresh.remove(id);
+                self.signatures.remove(id);
+            }
+        }
+
+        self.errors.lock().retain(|(id, _)| !ids.contains(id));
+
+        ids.len()
     }
 
     /// Analyzes one module.
@@ -204,7 +411,10 @@ impl Checker {
                                 box &mut storage,
                                 self,
                                 self.debugger.clone(),
-                            );
+                            )
+                            .with_type_table(self.type_table.clone())
+                            .with_symbol_table(self.symbol_table.clone())
+                            .with_completions(self.completions.clone());
                             let _ = modules.validate_with(&mut a);
                             mutations = a.mutations.unwrap();
                         }
@@ -217,6 +427,8 @@ impl Checker {
                                 cleanup_module_for_dts(&mut dts_module.body, type_data);
                             }
 
+                            self.record_signature(*id, &dts_module);
+
                             // TODO(kdy1): Prevent duplicate work.
                             if let Some(..) = self.dts_modules.insert(*id, dts_module) {
                                 warn!("Duplicated work: `{}`: (.d.ts already computed)", path);
@@ -225,7 +437,7 @@ impl Checker {
 
                         {
                             let mut lock = self.errors.lock();
-                            lock.extend(storage.take_errors());
+                            lock.extend(storage.take_errors().into_iter().map(|err| (id, err)));
                         }
                         {
                             let mut lock = self.module_types.write();
@@ -288,6 +500,36 @@ impl Checker {
         })
     }
 
+    /// Merges exports of augmentations of `module_id` into `ty`, which is the
+    /// type of the module.
+    fn augment(&self, module_id: ModuleId, ty: Type) -> Type {
+        let augmentations = self.module_augmentations.read();
+        let mut augmentations = augmentations.iter().filter(|(_, id, _)| *id == module_id).peekable();
+        if augmentations.peek().is_none() {
+            return ty;
+        }
+
+        let mut module = match ty.normalize() {
+            Type::Module(module) => module.clone(),
+            _ => return ty,
+        };
+
+        for (_, _, augmentation) in augmentations {
+            if let Type::Module(augmentation) = augmentation.normalize() {
+                for (name, ty) in &augmentation.exports.vars {
+                    module.exports.vars.entry(name.clone()).or_insert_with(|| ty.clone());
+                }
+
+                // Interfaces and namespaces are merged by declaring them multiple times.
+                for (name, types) in &augmentation.exports.types {
+                    module.exports.types.entry(name.clone()).or_default().extend(types.iter().cloned());
+                }
+            }
+        }
+
+        Type::Module(module).freezed()
+    }
+
     fn analyze_non_circular_module(&self, module_id: ModuleId, path: Arc<FileName>) -> Type {
         self.run(|| {
             let _panic = panic_ctx!(format!("analyze_non_circular_module({})", path));
@@ -295,7 +537,7 @@ impl Checker {
             let start = Instant::now();
 
             let is_dts = match &*path {
-                FileName::Real(path) => path.to_string_lossy().ends_with(".d.ts"),
+                FileName::Real(path) => is_dts(path),
                 _ => false,
             };
 
@@ -331,7 +573,10 @@ impl Checker {
                     box &mut storage,
                     self,
                     self.debugger.clone(),
-                );
+                )
+                .with_type_table(self.type_table.clone())
+                .with_symbol_table(self.symbol_table.clone())
+                .with_completions(self.completions.clone());
 
                 module.visit_with(&mut a);
 
@@ -354,7 +599,7 @@ impl Checker {
                 }
             } else {
                 let mut errors = self.errors.lock();
-                errors.extend(storage.info.errors);
+                errors.extend(storage.info.errors.into_iter().map(|err| (module_id, err)));
             }
 
             let type_info = Type::Module(stc_ts_types::Module {
@@ -369,6 +614,7 @@ impl Checker {
             })
             .freezed();
 
+            self.record_signature(module_id, &module);
             self.dts_modules.insert(module_id, module);
 
             let dur = Instant::now() - start;
@@ -401,7 +647,7 @@ impl Load for Checker {
 
         let data = self.analyze_module(Some(base_path), dep_path);
 
-        Ok(data)
+        Ok(self.augment(dep, data))
     }
 
     fn load_non_circular_dep(&self, base: ModuleId, dep: ModuleId) -> VResult<Type> {
@@ -413,7 +659,7 @@ impl Load for Checker {
                 .declared_modules
                 .read()
                 .iter()
-                .find_map(|(v, ty)| if *v == dep { Some(ty.clone()) } else { None });
+                .find_map(|(_, v, ty)| if *v == dep { Some(ty.clone()) } else { None });
 
             if let Some(ty) = ty {
                 return Ok(ty);
@@ -424,15 +670,33 @@ impl Load for Checker {
 
         let data = self.analyze_module(Some(base_path), dep_path);
 
-        Ok(data)
+        Ok(self.augment(dep, data))
+    }
+
+    fn top_level_ctxt(&self, module: ModuleId) -> SyntaxContext {
+        SyntaxContext::empty().apply_mark(self.module_graph.top_level_mark(module))
     }
 
-    fn declare_module(&self, name: &JsWord, module: Type) {
+    fn declare_module(&self, base: ModuleId, name: &JsWord, module: Type) {
         module.assert_clone_cheap();
 
+        // Only a module can augment another module. In a global script, it declares
+        // an ambient module even if the name resolves to a file.
+        if self.module_graph.is_module(base) {
+            let base_path = self.module_graph.path(base);
+            if let Ok(path) = self.module_graph.resolve(&base_path, name) {
+                if let FileName::Real(..) = &*path {
+                    info!("Augmenting module `{}` from `{}`", path, base_path);
+                    let module_id = self.module_graph.id(&path);
+                    self.module_augmentations.write().push((base, module_id, module));
+                    return;
+                }
+            }
+        }
+
         let module_id = self.module_graph.id_for_declare_module(name);
 
         info!("Declaring module with type `{}`", name);
-        self.declared_modules.write().push((module_id, module));
+        self.declared_modules.write().push((base, module_id, module));
     }
 }
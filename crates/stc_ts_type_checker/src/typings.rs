@@ -17,9 +17,8 @@ impl Checker {
             return;
         }
 
-        let result = NodeResolver
-            .resolve_as_file(dir)
-            .or_else(|_| NodeResolver.resolve_as_directory(dir));
+        let resolver = NodeResolver::default();
+        let result = resolver.resolve_as_file(dir).or_else(|_| resolver.resolve_as_directory(dir));
 
         if let Ok(entry) = result {
             let entry = Arc::new(FileName::Real(entry));
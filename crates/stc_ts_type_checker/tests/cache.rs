@@ -0,0 +1,131 @@
+use std::{fs, path::Path, sync::Arc};
+
+use stc_ts_builtin_types::Lib;
+use stc_ts_env::{Env, ModuleConfig};
+use stc_ts_file_analyzer::env::EnvFactory;
+use stc_ts_module_loader::resolvers::node::NodeResolver;
+use stc_ts_type_checker::{default_cache_path, BuildCache, Checker};
+use swc_common::FileName;
+use swc_ecma_ast::EsVersion;
+use tempdir::TempDir;
+
+/// Checks `files` in order with the build cache of `dir`, and saves the cache.
+///
+/// Returns whether each file is analyzed, and the number of errors.
+fn check(dir: &Path, files: &[&str]) -> (Vec<bool>, usize) {
+    let mut analyzed = vec![];
+    let mut error_count = 0;
+
+    ::testing::run_test2(false, |cm, handler| {
+        let mut checker = Checker::new(
+            cm,
+            Arc::new(handler),
+            Env::simple(Default::default(), EsVersion::Es5, ModuleConfig::None, &[Lib::Es5]),
+            Default::default(),
+            None,
+            Arc::new(NodeResolver::default()),
+        )
+        .with_cache(BuildCache::load(default_cache_path(dir), "test".into()));
+
+        for file in files {
+            let id = checker.check(Arc::new(FileName::Real(dir.join(file))));
+            analyzed.push(checker.take_dts(id).is_some());
+        }
+        error_count = checker.take_errors().len();
+
+        checker.save_cache().unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+
+    (analyzed, error_count)
+}
+
+#[test]
+fn unchanged_modules_are_skipped() {
+    let dir = TempDir::new("stc-cache").unwrap();
+    fs::write(dir.path().join("a.ts"), "export const a = 1;\n").unwrap();
+    fs::write(dir.path().join("b.ts"), "import { a } from './a';\nexport const b = a;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["a.ts", "b.ts"]), (vec![true, true], 0));
+    assert_eq!(check(dir.path(), &["a.ts", "b.ts"]), (vec![false, false], 0));
+}
+
+#[test]
+fn changed_module_is_analyzed() {
+    let dir = TempDir::new("stc-cache").unwrap();
+    fs::write(dir.path().join("a.ts"), "export const a = 1;\n").unwrap();
+    fs::write(dir.path().join("b.ts"), "import { a } from './a';\nexport const b = a;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["a.ts", "b.ts"]), (vec![true, true], 0));
+
+    fs::write(dir.path().join("b.ts"), "import { a } from './a';\nexport const b: string = a;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["a.ts", "b.ts"]), (vec![false, true], 1));
+    // Modules with errors are not stored.
+    assert_eq!(check(dir.path(), &["a.ts", "b.ts"]), (vec![false, true], 1));
+}
+
+#[test]
+fn dependents_are_analyzed_if_signature_is_changed() {
+    let dir = TempDir::new("stc-cache").unwrap();
+    fs::write(dir.path().join("a.ts"), "export const a = 1;\n").unwrap();
+    fs::write(dir.path().join("b.ts"), "import { a } from './a';\nexport const b: number = a;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["a.ts", "b.ts"]), (vec![true, true], 0));
+
+    // The `.d.ts` output is not changed.
+    fs::write(dir.path().join("a.ts"), "// Comment\nexport const a = 1;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["a.ts", "b.ts"]), (vec![true, false], 0));
+
+    fs::write(dir.path().join("a.ts"), "export const a = '';\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["a.ts", "b.ts"]), (vec![true, true], 1));
+}
+
+#[test]
+fn changed_global_script_discards_cache() {
+    let dir = TempDir::new("stc-cache").unwrap();
+    fs::write(dir.path().join("globals.ts"), "declare var g: number;\n").unwrap();
+    fs::write(dir.path().join("a.ts"), "export const a = 1;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["globals.ts", "a.ts"]), (vec![true, true], 0));
+    assert_eq!(check(dir.path(), &["globals.ts", "a.ts"]), (vec![true, false], 0));
+
+    fs::write(dir.path().join("globals.ts"), "declare var g: string;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["globals.ts", "a.ts"]), (vec![true, true], 0));
+}
+
+#[test]
+fn changes_of_reexported_modules_are_detected() {
+    let dir = TempDir::new("stc-cache").unwrap();
+    fs::write(dir.path().join("c.ts"), "export const c = 1;\n").unwrap();
+    fs::write(dir.path().join("b.ts"), "export * from './c';\n").unwrap();
+    fs::write(dir.path().join("a.ts"), "import { c } from './b';\nexport const a: number = c;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["c.ts", "b.ts", "a.ts"]), (vec![true, true, true], 0));
+    assert_eq!(check(dir.path(), &["c.ts", "b.ts", "a.ts"]), (vec![false, false, false], 0));
+
+    // The `.d.ts` output of `b` is not changed.
+    fs::write(dir.path().join("c.ts"), "export const c = '';\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["c.ts", "b.ts", "a.ts"]), (vec![true, true, true], 1));
+}
+
+#[test]
+fn changes_of_referenced_types_are_detected() {
+    let dir = TempDir::new("stc-cache").unwrap();
+    fs::write(dir.path().join("c.ts"), "export type T = number;\n").unwrap();
+    fs::write(dir.path().join("b.ts"), "import { T } from './c';\nexport declare let v: T;\n").unwrap();
+    fs::write(dir.path().join("a.ts"), "import { v } from './b';\nexport const a: number = v;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["c.ts", "b.ts", "a.ts"]), (vec![true, true, true], 0));
+    assert_eq!(check(dir.path(), &["c.ts", "b.ts", "a.ts"]), (vec![false, false, false], 0));
+
+    fs::write(dir.path().join("c.ts"), "export type T = string;\n").unwrap();
+
+    assert_eq!(check(dir.path(), &["c.ts", "b.ts", "a.ts"]), (vec![true, true, true], 1));
+}
@@ -0,0 +1,98 @@
+use std::{fs, path::Path, sync::Arc};
+
+use stc_ts_builtin_types::Lib;
+use stc_ts_env::{Env, ModuleConfig};
+use stc_ts_file_analyzer::env::EnvFactory;
+use stc_ts_module_loader::resolvers::node::NodeResolver;
+use stc_ts_type_checker::Checker;
+use swc_common::FileName;
+use swc_ecma_ast::EsVersion;
+use tempdir::TempDir;
+
+/// Checks `src`, and then each of `edits` after writing it to the same file.
+///
+/// Returns codes of errors for each check.
+fn check(src: &str, edits: &[&str]) -> Vec<Vec<usize>> {
+    let dir = TempDir::new("stc-directives").unwrap();
+    let path = dir.path().join("index.ts");
+    let mut codes = vec![];
+
+    ::testing::run_test2(false, |cm, handler| {
+        let mut checker = Checker::new(
+            cm,
+            Arc::new(handler),
+            Env::simple(Default::default(), EsVersion::Es5, ModuleConfig::None, &[Lib::Es5]),
+            Default::default(),
+            None,
+            Arc::new(NodeResolver::default()),
+        );
+
+        codes.push(check_file(&mut checker, &path, src));
+
+        for edit in edits {
+            checker.invalidate(&[Arc::new(FileName::Real(path.clone()))]);
+            codes.push(check_file(&mut checker, &path, edit));
+        }
+
+        Ok(())
+    })
+    .unwrap();
+
+    codes
+}
+
+fn check_file(checker: &mut Checker, path: &Path, src: &str) -> Vec<usize> {
+    fs::write(path, src).unwrap();
+
+    checker.check(Arc::new(FileName::Real(path.to_path_buf())));
+    checker.take_errors().iter().map(|err| err.code()).collect()
+}
+
+#[test]
+fn ts_ignore() {
+    assert_eq!(check("// @ts-ignore\nconst a: string = 1;\n", &[]), vec![vec![]]);
+}
+
+#[test]
+fn ts_ignore_applies_to_next_line() {
+    assert_eq!(
+        check("// @ts-ignore\nconst a: string = 1;\nconst b: string = 1;\n", &[]),
+        vec![vec![2322]]
+    );
+}
+
+#[test]
+fn ts_expect_error() {
+    assert_eq!(check("// @ts-expect-error\nconst a: string = 1;\n", &[]), vec![vec![]]);
+}
+
+#[test]
+fn unused_ts_expect_error() {
+    assert_eq!(check("// @ts-expect-error\nconst a: string = '';\n", &[]), vec![vec![2578]]);
+}
+
+#[test]
+fn ts_nocheck() {
+    assert_eq!(check("// @ts-nocheck\nconst a: string = 1;\n", &[]), vec![vec![]]);
+}
+
+#[test]
+fn ts_nocheck_after_code() {
+    assert_eq!(check("const a: string = 1;\n// @ts-nocheck\n", &[]), vec![vec![2322]]);
+}
+
+/// Comments of the previous version of a file are not directives.
+#[test]
+fn edited_file() {
+    assert_eq!(
+        check(
+            "// @ts-expect-error\nconst a: string = 1;\n",
+            &[
+                "const b = 0;\n\n// @ts-expect-error\nconst a: string = 1;\n",
+                "// @ts-expect-error\nconst a: string = '';\n",
+                "const a: string = 1;\n",
+            ]
+        ),
+        vec![vec![], vec![], vec![2578], vec![2322]]
+    );
+}
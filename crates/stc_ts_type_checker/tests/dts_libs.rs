@@ -100,7 +100,7 @@ fn test_project(_name: &str, dir: &Path, entries: Vec<PathBuf>) {
             ),
             TsConfig { ..Default::default() },
             None,
-            Arc::new(NodeResolver),
+            Arc::new(NodeResolver::default()),
         );
 
         for main in entries {
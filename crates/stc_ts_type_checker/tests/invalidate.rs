@@ -0,0 +1,44 @@
+use std::{fs, sync::Arc};
+
+use stc_ts_builtin_types::Lib;
+use stc_ts_env::{Env, ModuleConfig};
+use stc_ts_file_analyzer::env::EnvFactory;
+use stc_ts_module_loader::resolvers::node::NodeResolver;
+use stc_ts_type_checker::Checker;
+use swc_common::FileName;
+use swc_ecma_ast::EsVersion;
+use tempdir::TempDir;
+
+#[test]
+fn created_file_resolves_import() {
+    let dir = TempDir::new("stc-invalidate").unwrap();
+    let index = dir.path().join("index.ts");
+    let dep = dir.path().join("dep.ts");
+
+    fs::write(&index, "import { a } from './dep';\nconst b: string = a;\n").unwrap();
+
+    ::testing::run_test2(false, |cm, handler| {
+        let mut checker = Checker::new(
+            cm,
+            Arc::new(handler),
+            Env::simple(Default::default(), EsVersion::Es5, ModuleConfig::None, &[Lib::Es5]),
+            Default::default(),
+            None,
+            Arc::new(NodeResolver::default()),
+        );
+
+        checker.check(Arc::new(FileName::Real(index.clone())));
+        assert!(!checker.take_errors().is_empty(), "`./dep` should not be resolved");
+
+        fs::write(&dep, "export const a = '';\n").unwrap();
+
+        let count = checker.invalidate(&[Arc::new(FileName::Real(dep.clone()))]);
+        assert_eq!(count, 2, "`./dep` and the module importing it should be invalidated");
+
+        checker.check(Arc::new(FileName::Real(index.clone())));
+        assert_eq!(checker.take_errors().len(), 0);
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -62,7 +62,7 @@ fn do_test(file_name: &Path) -> Result<(), StdErr> {
                 ..ts_config
             },
             None,
-            Arc::new(NodeResolver),
+            Arc::new(NodeResolver::default()),
         );
         checker.check(Arc::new(FileName::Real(file_name.into())));
 
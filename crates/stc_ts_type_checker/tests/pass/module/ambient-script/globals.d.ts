@@ -0,0 +1,4 @@
+// Not a module, so this declares an ambient module instead of augmenting `node_modules/lib`.
+declare module "lib" {
+    export const value: string;
+}
@@ -0,0 +1,5 @@
+/// <reference path="./globals.d.ts" />
+
+import { value } from "lib";
+
+const s: string = value;
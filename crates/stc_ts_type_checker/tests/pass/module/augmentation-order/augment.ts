@@ -0,0 +1,7 @@
+export {};
+
+declare module "./model" {
+    interface Model {
+        name: string;
+    }
+}
@@ -0,0 +1,5 @@
+import { Model } from "./model";
+
+declare const model: Model;
+
+export const name: string = model.name;
@@ -0,0 +1,5 @@
+// `consumer` does not import `augment`, and it's imported first.
+import { name } from "./consumer";
+import "./augment";
+
+const length: number = name.length;
@@ -0,0 +1,7 @@
+import "./augment";
+import { Model } from "./model";
+
+declare const model: Model;
+
+const id: number = model.id;
+const name: string = model.name;
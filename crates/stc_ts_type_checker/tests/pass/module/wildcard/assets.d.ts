@@ -0,0 +1,9 @@
+declare module "*.svg" {
+    const url: string;
+    export default url;
+}
+
+declare module "icons/*.svg" {
+    const name: "icon";
+    export default name;
+}
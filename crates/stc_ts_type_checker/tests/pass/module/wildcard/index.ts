@@ -0,0 +1,7 @@
+/// <reference path="./assets.d.ts" />
+
+import logo from "./logo.svg";
+import icon from "icons/arrow.svg";
+
+const url: string = logo;
+const name: "icon" = icon;
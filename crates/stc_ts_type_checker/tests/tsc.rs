@@ -26,7 +26,7 @@ use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use serde::Deserialize;
 use stc_ts_builtin_types::Lib;
-use stc_ts_env::{Env, ModuleConfig, Rule};
+use stc_ts_env::{Env, JsxConfig, ModuleConfig, Rule};
 use stc_ts_file_analyzer::env::EnvFactory;
 use stc_ts_module_loader::resolvers::node::NodeResolver;
 use stc_ts_type_checker::Checker;
@@ -300,6 +300,7 @@ struct TestSpec {
     ts_config: TsConfig,
     target: EsVersion,
     module_config: ModuleConfig,
+    jsx: JsxConfig,
 }
 
 fn parse_targets(s: &str) -> Vec<EsVersion> {
@@ -313,7 +314,9 @@ fn parse_targets(s: &str) -> Vec<EsVersion> {
         "es2018" => return vec![EsVersion::Es2018],
         "es2019" => return vec![EsVersion::Es2019],
         "es2020" => return vec![EsVersion::Es2020],
-        "esnext" => return vec![EsVersion::Es2020],
+        "es2021" => return vec![EsVersion::Es2021],
+        "es2022" => return vec![EsVersion::Es2022],
+        "esnext" => return vec![EsVersion::Es2022],
         _ => {}
     }
     if !s.contains(',') {
@@ -372,13 +375,18 @@ fn parse_test(file_name: &Path) -> Vec<TestSpec> {
             }
         }
 
-        let mut libs = vec![Lib::Es5, Lib::Dom];
+        let default_libs = vec![Lib::Decorators, Lib::DecoratorsLegacy, Lib::Es5, Lib::Dom];
+        let mut libs = default_libs.clone();
         let mut rule = Rule {
             allow_unreachable_code: false,
             ..Default::default()
         };
         let mut module_config = ModuleConfig::None;
-        let ts_config = TsConfig::default();
+        let mut jsx = JsxConfig::default();
+        let ts_config = TsConfig {
+            decorators: true,
+            ..Default::default()
+        };
 
         let mut had_comment = false;
 
@@ -418,6 +426,9 @@ fn parse_test(file_name: &Path) -> Vec<TestSpec> {
                     rule.always_strict = strict;
                     rule.strict_null_checks = strict;
                     rule.strict_function_types = strict;
+                    rule.strict_bind_call_apply = strict;
+                    rule.strict_property_initialization = strict;
+                    rule.use_unknown_in_catch_variables = strict;
                 } else if s.starts_with("noLib:") {
                     let v = s["noLib:".len()..].trim().parse().unwrap();
                     if v {
@@ -429,6 +440,15 @@ fn parse_test(file_name: &Path) -> Vec<TestSpec> {
                 } else if s.starts_with("noImplicitReturns:") {
                     let v = s["noImplicitReturns:".len()..].trim().parse().unwrap();
                     rule.no_implicit_returns = v;
+                } else if s.to_lowercase().starts_with("nofallthroughcasesinswitch:") {
+                    let v = s["noFallthroughCasesInSwitch:".len()..].trim().parse().unwrap();
+                    rule.no_fallthrough_cases_in_switch = v;
+                } else if s.to_lowercase().starts_with("nounusedlocals:") {
+                    let v = s["noUnusedLocals:".len()..].trim().parse().unwrap();
+                    rule.no_unused_locals = v;
+                } else if s.to_lowercase().starts_with("nounusedparameters:") {
+                    let v = s["noUnusedParameters:".len()..].trim().parse().unwrap();
+                    rule.no_unused_parameters = v;
                 } else if s.starts_with("declaration") {
                 } else if s.starts_with("stripInternal:") {
                     // TODO(kdy1): Handle
@@ -458,6 +478,21 @@ fn parse_test(file_name: &Path) -> Vec<TestSpec> {
                 } else if s.starts_with("strictNullChecks:") {
                     let v = s["strictNullChecks:".len()..].trim().parse().unwrap();
                     rule.strict_null_checks = v;
+                } else if s.starts_with("strictBindCallApply:") {
+                    let v = s["strictBindCallApply:".len()..].trim().parse().unwrap();
+                    rule.strict_bind_call_apply = v;
+                } else if s.starts_with("strictPropertyInitialization:") {
+                    let v = s["strictPropertyInitialization:".len()..].trim().parse().unwrap();
+                    rule.strict_property_initialization = v;
+                } else if s.starts_with("useUnknownInCatchVariables:") {
+                    let v = s["useUnknownInCatchVariables:".len()..].trim().parse().unwrap();
+                    rule.use_unknown_in_catch_variables = v;
+                } else if s.starts_with("noUncheckedIndexedAccess:") {
+                    let v = s["noUncheckedIndexedAccess:".len()..].trim().parse().unwrap();
+                    rule.no_unchecked_indexed_access = v;
+                } else if s.starts_with("exactOptionalPropertyTypes:") {
+                    let v = s["exactOptionalPropertyTypes:".len()..].trim().parse().unwrap();
+                    rule.exact_optional_property_types = v;
                 } else if s.starts_with("noImplicitThis:") {
                     let v = s["noImplicitThis:".len()..].trim().parse().unwrap();
                     rule.no_implicit_this = v;
@@ -474,10 +509,23 @@ fn parse_test(file_name: &Path) -> Vec<TestSpec> {
                     // Ignored as we don't generate them.
                 } else if s.to_lowercase().starts_with("usedefineforclassfields") {
                     rule.use_define_property_for_class_fields = true;
-                } else if s.to_lowercase().starts_with("noemit")
-                    || s.to_lowercase().starts_with("jsx")
-                    || s.to_lowercase().starts_with("preserveconstenums")
-                {
+                } else if s.to_lowercase().starts_with("experimentaldecorators:") {
+                    let v = s["experimentalDecorators:".len()..].trim().parse().unwrap();
+                    rule.experimental_decorators = v;
+                } else if s.to_lowercase().starts_with("jsxfactory:") {
+                    jsx.factory = s["jsxFactory:".len()..].trim().into();
+                } else if s.to_lowercase().starts_with("jsxfragmentfactory:") {
+                    jsx.fragment_factory = s["jsxFragmentFactory:".len()..].trim().into();
+                } else if s.to_lowercase().starts_with("jsximportsource:") {
+                    jsx.import_source = s["jsxImportSource:".len()..].trim().into();
+                } else if s.to_lowercase().starts_with("jsx:") {
+                    // Tests may be run with multiple modes, but we only use the first one.
+                    let v = s["jsx:".len()..].split(',').next().unwrap().trim().to_lowercase();
+                    // `tsc` reports an invalid value and uses the default mode.
+                    if let Ok(mode) = v.parse() {
+                        jsx.mode = mode;
+                    }
+                } else if s.to_lowercase().starts_with("noemit") || s.to_lowercase().starts_with("preserveconstenums") {
                     // Ignored as we only checks type.
                 } else if s.starts_with("strict") {
                     let strict = true;
@@ -486,6 +534,9 @@ fn parse_test(file_name: &Path) -> Vec<TestSpec> {
                     rule.always_strict = strict;
                     rule.strict_null_checks = strict;
                     rule.strict_function_types = strict;
+                    rule.strict_bind_call_apply = strict;
+                    rule.strict_property_initialization = strict;
+                    rule.use_unknown_in_catch_variables = strict;
                 } else {
                     panic!("Comment is not handled: {}", s);
                 }
@@ -500,9 +551,9 @@ fn parse_test(file_name: &Path) -> Vec<TestSpec> {
         Ok(targets
             .into_iter()
             .map(|(target, specified)| {
-                let libs = if specified && libs == vec![Lib::Es5, Lib::Dom] {
+                let libs = if specified && libs == default_libs {
                     match target {
-                        EsVersion::Es3 | EsVersion::Es5 => vec![Lib::Es5, Lib::Dom],
+                        EsVersion::Es3 | EsVersion::Es5 => default_libs.clone(),
                         EsVersion::Es2015 => Lib::load("es2015.full"),
                         EsVersion::Es2016 => Lib::load("es2016.full"),
                         EsVersion::Es2017 => Lib::load("es2017.full"),
@@ -525,6 +576,7 @@ fn parse_test(file_name: &Path) -> Vec<TestSpec> {
                     ts_config,
                     target,
                     module_config,
+                    jsx: jsx.clone(),
                 }
             })
             .collect())
@@ -547,6 +599,7 @@ fn do_test(file_name: &Path) -> Result<(), StdErr> {
         ts_config,
         target,
         module_config,
+        jsx,
     } in specs
     {
         let mut time_of_check = Duration::new(0, 0);
@@ -583,13 +636,13 @@ fn do_test(file_name: &Path) -> Result<(), StdErr> {
                 let mut checker = Checker::new(
                     cm,
                     handler.clone(),
-                    Env::simple(rule, target, module_config, &libs),
+                    Env::simple(rule, target, module_config, &libs).with_jsx(jsx.clone()),
                     TsConfig {
                         tsx: fname.contains("tsx"),
                         ..ts_config
                     },
                     None,
-                    Arc::new(NodeResolver),
+                    Arc::new(NodeResolver::default()),
                 );
 
                 // Install a logger
@@ -793,7 +846,7 @@ fn libs_with_deps(libs: &[Lib]) -> Vec<Lib> {
         libs.push(l);
 
         match l {
-            Lib::Es5 | Lib::Es5Full => {}
+            Lib::Decorators | Lib::DecoratorsLegacy | Lib::Es5 | Lib::Es5Full => {}
             Lib::Es2015
             | Lib::Es2015Core
             | Lib::Es2015Full
@@ -832,11 +885,26 @@ fn libs_with_deps(libs: &[Lib]) -> Vec<Lib> {
             | Lib::Es2020String
             | Lib::Es2020SymbolWellknown => add(libs, Lib::Es2019Full),
 
-            Lib::Esnext | Lib::EsnextIntl | Lib::EsnextPromise | Lib::EsnextString | Lib::EsnextWeakref | Lib::EsnextFull => {
+            Lib::Es2021 | Lib::Es2021Full | Lib::Es2021Intl | Lib::Es2021Promise | Lib::Es2021String | Lib::Es2021Weakref => {
                 add(libs, Lib::Es2020Full)
             }
+            Lib::Es2022
+            | Lib::Es2022Array
+            | Lib::Es2022Error
+            | Lib::Es2022Full
+            | Lib::Es2022Intl
+            | Lib::Es2022Object
+            | Lib::Es2022Regexp
+            | Lib::Es2022Sharedmemory
+            | Lib::Es2022String => add(libs, Lib::Es2021Full),
+            Lib::Es2023 | Lib::Es2023Array | Lib::Es2023Full => add(libs, Lib::Es2022Full),
+
+            Lib::Esnext | Lib::EsnextIntl | Lib::EsnextPromise | Lib::EsnextString | Lib::EsnextWeakref | Lib::EsnextFull => {
+                add(libs, Lib::Es2023Full)
+            }
 
             Lib::Dom
+            | Lib::DomAsynciterable
             | Lib::DomIterable
             | Lib::DomIterableGenerated
             | Lib::Header
@@ -221,7 +221,7 @@ fn do_test(path: &Path) -> Result<(), StdErr> {
                     cm,
                     handler: type_info_handler,
                 }),
-                Arc::new(NodeResolver),
+                Arc::new(NodeResolver::default()),
             );
 
             checker.check(Arc::new(FileName::Real(path.into())));
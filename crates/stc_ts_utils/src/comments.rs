@@ -16,6 +16,15 @@ pub struct StcComments {
     pub trailing: CommentMap,
 }
 
+impl StcComments {
+    /// Removes comments between `lo` and `hi`, like comments of a file which
+    /// is parsed again.
+    pub fn remove_range(&self, lo: BytePos, hi: BytePos) {
+        self.leading.retain(|&pos, _| pos < lo || hi < pos);
+        self.trailing.retain(|&pos, _| pos < lo || hi < pos);
+    }
+}
+
 impl Comments for StcComments {
     fn add_leading(&self, pos: BytePos, cmt: Comment) {
         self.leading.entry(pos).or_default().push(cmt);
@@ -0,0 +1,53 @@
+use swc_common::comments::{Comment, CommentKind};
+
+/// A comment directive which affects errors on the next line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentDirective {
+    /// `// @ts-ignore`
+    Ignore,
+    /// `// @ts-expect-error`
+    ExpectError,
+}
+
+impl CommentDirective {
+    /// Parses `// @ts-ignore` or `// @ts-expect-error`.
+    ///
+    /// For block comments, only the last line is checked, like `tsc`.
+    pub fn parse(cmt: &Comment) -> Option<Self> {
+        let text = match cmt.kind {
+            // `/// @ts-ignore` is also allowed.
+            CommentKind::Line => cmt.text.strip_prefix('/').unwrap_or(&*cmt.text),
+            CommentKind::Block => cmt.text.lines().last()?.trim_start_matches(|c: char| c == '/' || c == '*'),
+        }
+        .trim_start();
+
+        if text.starts_with("@ts-expect-error") {
+            Some(CommentDirective::ExpectError)
+        } else if text.starts_with("@ts-ignore") {
+            Some(CommentDirective::Ignore)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses `// @ts-check` and `// @ts-nocheck`.
+///
+/// Returns `Some(true)` for `@ts-check` and `Some(false)` for `@ts-nocheck`.
+/// Note that these are valid only at the top of a file.
+pub fn parse_check_pragma(cmt: &Comment) -> Option<bool> {
+    if cmt.kind != CommentKind::Line {
+        return None;
+    }
+
+    // Arguments like `// @ts-nocheck: reason` are allowed.
+    let name = cmt.text.trim().split(|c: char| c.is_whitespace() || c == ':').next()?;
+
+    if name.eq_ignore_ascii_case("@ts-nocheck") {
+        Some(false)
+    } else if name.eq_ignore_ascii_case("@ts-check") {
+        Some(true)
+    } else {
+        None
+    }
+}
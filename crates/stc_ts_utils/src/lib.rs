@@ -14,6 +14,7 @@ use swc_common::Spanned;
 pub use self::{comments::StcComments, map_with_mut::MapWithMut};
 
 mod comments;
+pub mod directives;
 pub mod imports;
 mod map_with_mut;
 
@@ -1,4 +1,21 @@
+use std::{path::PathBuf, sync::Arc, time::Instant};
+
+use anyhow::{Context, Error};
 use clap::Args;
+use rayon::prelude::*;
+use stc_ts_config::{find_config, CompilerOptions, ProjectConfig};
+use stc_ts_env::Env;
+use stc_ts_errors::ErrorKind;
+use stc_ts_file_analyzer::env::EnvFactory;
+use stc_ts_module_loader::resolvers::{node::NodeResolver, paths::PathsResolver};
+use stc_ts_type_checker::{default_cache_path, BuildCache, Checker};
+use swc_common::{errors::Handler, FileName, SourceMap};
+
+use crate::{
+    emit::{check_root_dir, common_source_dir, dts_path, emit_dts, emit_isolated_dts, DtsOptions},
+    report::{report, Format},
+    watch::{is_config, watch},
+};
 
 /// Perform type checking, but this command is not public api and is only used
 /// for testing.
@@ -16,3 +33,456 @@ pub struct TestCommand {
     #[clap(long)]
     pub types: Option<Vec<String>>,
 }
+
+/// Type check a project.
+#[derive(Debug, Args)]
+#[clap(rename_all = "camel-case")]
+pub struct CheckCommand {
+    /// Path to a `tsconfig.json`, or to a directory containing one.
+    ///
+    /// If not specified, `tsconfig.json` is searched from the current
+    /// directory and its ancestors.
+    #[clap(short, long)]
+    pub project: Option<PathBuf>,
+
+    /// Format of reported errors.
+    #[clap(long, value_enum, default_value_t = Format::Pretty)]
+    pub format: Format,
+
+    /// Generate `.d.ts` files.
+    ///
+    /// JavaScript files are never emitted, as if `--emitDeclarationOnly` is
+    /// specified.
+    #[clap(long)]
+    pub declaration: bool,
+
+    /// Same as `--declaration`.
+    #[clap(long)]
+    pub emit_declaration_only: bool,
+
+    /// Generate `.d.ts.map` files, which map `.d.ts` files to their sources.
+    #[clap(long)]
+    pub declaration_map: bool,
+
+    /// Generate `.d.ts` files without type information, which requires type
+    /// annotations on exported declarations.
+    #[clap(long)]
+    pub isolated_declarations: bool,
+
+    /// Directory of `.d.ts` files, if `--declarationDir` is not specified.
+    #[clap(long)]
+    pub out_dir: Option<PathBuf>,
+
+    /// Directory of `.d.ts` files.
+    #[clap(long)]
+    pub declaration_dir: Option<PathBuf>,
+
+    /// Skip analysis of files which are not changed since the last run. The
+    /// cache is stored at `.stc/build-info.rmp` unless `tsBuildInfoFile` is
+    /// specified.
+    #[clap(long)]
+    pub incremental: bool,
+
+    /// Check again whenever files are changed. Only changed modules and
+    /// modules depending on them are analyzed again.
+    #[clap(short, long)]
+    pub watch: bool,
+
+    /// Files to check. If specified, `tsconfig.json` is not loaded.
+    #[clap(name = "files")]
+    pub files: Vec<PathBuf>,
+}
+
+/// A checker of a project, which is kept to check changed files again with
+/// `--watch`.
+struct Build {
+    cm: Arc<SourceMap>,
+    handler: Arc<Handler>,
+    checker: Checker,
+    base_dir: PathBuf,
+    opts: CompilerOptions,
+    files: Vec<PathBuf>,
+    config_files: Vec<PathBuf>,
+    /// Errors reported by the last check.
+    errors: Vec<stc_ts_errors::Error>,
+    /// Total size of previous versions of changed files, which are kept by
+    /// [SourceMap] as it cannot remove files.
+    superseded: usize,
+}
+
+impl CheckCommand {
+    /// Returns the number of errors.
+    ///
+    /// `new_handler` creates a handler for the given [SourceMap]. A new
+    /// [SourceMap] is created whenever the checker is restarted.
+    ///
+    /// With `--watch`, this does not return unless an error occurs.
+    pub fn run(self, new_handler: impl Fn(&Arc<SourceMap>) -> Arc<Handler>) -> Result<usize, Error> {
+        loop {
+            let cm = Arc::new(SourceMap::default());
+            let mut build = self.start(cm.clone(), new_handler(&cm))?;
+
+            if !self.watch {
+                return Ok(build.errors.len());
+            }
+
+            let (base_dir, config_files) = (build.base_dir.clone(), build.config_files.clone());
+
+            // Returns if a new checker is required.
+            watch(&base_dir, &config_files, |changed| self.recheck(&mut build, changed))?;
+        }
+    }
+
+    /// Loads the project and checks it using a new checker.
+    fn start(&self, cm: Arc<SourceMap>, handler: Arc<Handler>) -> Result<Build, Error> {
+        let (base_dir, opts, files, config_files) = self.load_project()?;
+
+        let libs = {
+            let start = Instant::now();
+
+            let libs = opts.libs()?;
+
+            let end = Instant::now();
+
+            log::info!("Loading builtin libraries took {:?}", end - start);
+
+            libs
+        };
+
+        let env = Env::simple(opts.rule(), opts.target()?, opts.module()?, &libs).with_jsx(opts.jsx()?);
+
+        let resolver = Arc::new(
+            PathsResolver::new(NodeResolver::default().with_module_resolution(opts.module_resolution()?))
+                .with_base_url(opts.base_url.clone())
+                .with_paths(opts.path_mappings())
+                .with_root_dirs(opts.root_dirs.clone().unwrap_or_default()),
+        );
+
+        {
+            let start = Instant::now();
+
+            let checker = Checker::new(
+                cm.clone(),
+                handler.clone(),
+                env.clone(),
+                opts.parser_config(),
+                None,
+                resolver.clone(),
+            );
+
+            checker.load_typings(&base_dir, opts.type_roots.as_deref(), opts.types.as_deref());
+
+            let end = Instant::now();
+
+            log::info!("Loading typing libraries took {:?}", end - start);
+        }
+
+        let mut checker = Checker::new(cm.clone(), handler.clone(), env, opts.parser_config(), None, resolver);
+        if self.incremental || opts.incremental.unwrap_or(false) {
+            let path = opts.ts_build_info_file.clone().unwrap_or_else(|| default_cache_path(&base_dir));
+            let key = format!("{} {:?}", env!("CARGO_PKG_VERSION"), opts);
+
+            // Skipped modules have no `.d.ts` output.
+            let emits_dts = self.dts_options(&opts, &files)?.map_or(false, |dts_opts| !dts_opts.isolated);
+
+            checker = checker.with_cache(BuildCache::load(path, key).with_skipping(!emits_dts));
+        }
+
+        let errors = self.check_files(&checker, &cm, &handler, &opts, &files)?;
+
+        Ok(Build {
+            cm,
+            handler,
+            checker,
+            base_dir,
+            opts,
+            files,
+            config_files,
+            errors,
+            superseded: 0,
+        })
+    }
+
+    /// Checks `changed` files and modules depending on them again.
+    ///
+    /// Returns `false` if a new checker is required.
+    fn recheck(&self, build: &mut Build, changed: Vec<PathBuf>) -> Result<bool, Error> {
+        if changed.iter().any(|path| is_config(path, &build.config_files)) {
+            log::info!("Configuration is changed. Restarting...");
+            return Ok(false);
+        }
+
+        // Files may be added or removed.
+        build.files = self.load_project()?.2;
+
+        let outputs: Vec<_> = match self.dts_options(&build.opts, &build.files)? {
+            Some(dts_opts) => build.files.iter().filter_map(|path| dts_path(path, &dts_opts)).collect(),
+            None => vec![],
+        };
+        let changed = changed
+            .into_iter()
+            .filter(|path| !outputs.contains(path))
+            .map(|path| Arc::new(FileName::Real(path)))
+            .collect::<Vec<_>>();
+        if changed.is_empty() {
+            return Ok(true);
+        }
+
+        let source_len = |path: &Arc<FileName>| build.checker.source_file(path).map_or(0, |file| file.src.len());
+
+        // Previous versions of files are dropped with the source map. Once they are
+        // larger than the current ones, checking all files again is cheaper than
+        // keeping them.
+        build.superseded += changed.iter().map(source_len).sum::<usize>();
+        let current = build
+            .files
+            .iter()
+            .map(|path| source_len(&Arc::new(FileName::Real(path.clone()))))
+            .sum::<usize>();
+        if build.superseded > current {
+            log::info!("Restarting to drop previous versions of changed files...");
+            return Ok(false);
+        }
+
+        let count = build.checker.invalidate(&changed);
+
+        log::info!("{} files are changed and {} modules are invalidated", changed.len(), count);
+
+        build.errors = self.check_files(&build.checker, &build.cm, &build.handler, &build.opts, &build.files)?;
+
+        Ok(true)
+    }
+
+    /// Checks `files`, reports errors and emits `.d.ts` files if enabled.
+    /// Returns the reported errors.
+    ///
+    /// Modules already analyzed by `checker` are not analyzed again.
+    fn check_files(
+        &self,
+        checker: &Checker,
+        cm: &Arc<SourceMap>,
+        handler: &Handler,
+        opts: &CompilerOptions,
+        files: &[PathBuf],
+    ) -> Result<Vec<stc_ts_errors::Error>, Error> {
+        let dts_opts = self.dts_options(opts, files)?;
+
+        // Files are processed one by one, so this does not depend on type checking.
+        let isolated_errors = match &dts_opts {
+            Some(dts_opts) if dts_opts.isolated => {
+                let start = Instant::now();
+
+                let (count, errors) = emit_isolated_dts(cm, handler, opts.parser_config(), opts.target()?, files, dts_opts)?;
+
+                let end = Instant::now();
+
+                log::info!("Emitting {} `.d.ts` files took {:?}", count, end - start);
+
+                errors
+            }
+            _ => vec![],
+        };
+        let root_dir_errors = match &dts_opts {
+            Some(dts_opts) => check_root_dir(files, dts_opts),
+            None => vec![],
+        };
+
+        let start = Instant::now();
+
+        // Module augmentations apply to all files, even if they are checked first.
+        files.par_iter().for_each(|path| {
+            checker.load(Arc::new(FileName::Real(path.clone())));
+        });
+        files.par_iter().for_each(|path| {
+            checker.check(Arc::new(FileName::Real(path.clone())));
+        });
+
+        let mut errors = ErrorKind::flatten(checker.errors());
+        errors.extend(root_dir_errors);
+        errors.extend(isolated_errors);
+
+        checker
+            .run(|| report(self.format, cm, handler, &errors))
+            .context("failed to report errors")?;
+
+        checker.save_cache().context("failed to save the build cache")?;
+
+        if let Some(dts_opts) = dts_opts.filter(|dts_opts| !dts_opts.isolated) {
+            let start = Instant::now();
+
+            let count = checker.run(|| emit_dts(checker, cm, files, &dts_opts))?;
+
+            let end = Instant::now();
+
+            log::info!("Emitting {} `.d.ts` files took {:?}", count, end - start);
+        }
+
+        let end = Instant::now();
+
+        log::info!("Checking {} files took {:?}", files.len(), end - start);
+
+        Ok(errors)
+    }
+
+    /// Returns [None] if `.d.ts` files should not be emitted. Options passed
+    /// via the command line override ones in `tsconfig.json`.
+    fn dts_options(&self, opts: &CompilerOptions, files: &[PathBuf]) -> Result<Option<DtsOptions>, Error> {
+        let enabled = self.declaration
+            || self.emit_declaration_only
+            || opts.declaration.unwrap_or(false)
+            || opts.emit_declaration_only.unwrap_or(false);
+        if !enabled {
+            return Ok(None);
+        }
+
+        let cwd = std::env::current_dir().context("failed to get the current directory")?;
+
+        let declaration_dir = self
+            .declaration_dir
+            .as_ref()
+            .map(|dir| cwd.join(dir))
+            .or_else(|| opts.declaration_dir.clone());
+        let out_dir = self.out_dir.as_ref().map(|dir| cwd.join(dir)).or_else(|| opts.out_dir.clone());
+
+        let root_dir = opts.root_dir.clone().or_else(|| common_source_dir(files)).unwrap_or(cwd);
+
+        Ok(Some(DtsOptions {
+            root_dir,
+            out_dir: declaration_dir.or(out_dir),
+            declaration_map: self.declaration_map || opts.declaration_map.unwrap_or(false),
+            isolated: self.isolated_declarations || opts.isolated_declarations.unwrap_or(false),
+        }))
+    }
+
+    /// Returns the base directory, compiler options, files to check and the
+    /// loaded config files.
+    fn load_project(&self) -> Result<(PathBuf, CompilerOptions, Vec<PathBuf>, Vec<PathBuf>), Error> {
+        let cwd = std::env::current_dir().context("failed to get the current directory")?;
+
+        if !self.files.is_empty() {
+            let files = self.files.iter().map(|file| cwd.join(file)).collect();
+            return Ok((cwd, Default::default(), files, vec![]));
+        }
+
+        let path = match &self.project {
+            Some(path) => path.clone(),
+            None => find_config(&cwd).context("failed to find `tsconfig.json` in the current directory or its ancestors")?,
+        };
+
+        let start = Instant::now();
+
+        let config = ProjectConfig::load(&path).with_context(|| format!("failed to load project at `{}`", path.display()))?;
+
+        let end = Instant::now();
+
+        log::info!("Loading `{}` took {:?}", config.path.display(), end - start);
+
+        Ok((
+            config.dir().to_path_buf(),
+            config.compiler_options,
+            config.files,
+            config.config_files,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use stc_ts_config::CompilerOptions;
+    use swc_common::{
+        errors::{ColorConfig, EmitterWriter, Handler},
+        SourceMap,
+    };
+    use tempdir::TempDir;
+
+    use super::{Build, CheckCommand, Format};
+
+    fn command() -> CheckCommand {
+        CheckCommand {
+            project: None,
+            format: Format::Pretty,
+            declaration: true,
+            emit_declaration_only: false,
+            declaration_map: false,
+            isolated_declarations: false,
+            out_dir: None,
+            declaration_dir: None,
+            incremental: false,
+            watch: false,
+            files: vec![],
+        }
+    }
+
+    #[test]
+    fn declaration_dir_overrides_out_dir() {
+        let files = [PathBuf::from("/project/src/a.ts"), PathBuf::from("/project/src/lib/b.ts")];
+        let opts = CompilerOptions {
+            out_dir: Some(PathBuf::from("/project/out")),
+            ..Default::default()
+        };
+
+        let dts_opts = command().dts_options(&opts, &files).unwrap().unwrap();
+        assert_eq!(dts_opts.root_dir, Path::new("/project/src"));
+        assert_eq!(dts_opts.out_dir.as_deref(), Some(Path::new("/project/out")));
+
+        let opts = CompilerOptions {
+            declaration_dir: Some(PathBuf::from("/project/types")),
+            ..opts
+        };
+
+        let dts_opts = command().dts_options(&opts, &files).unwrap().unwrap();
+        assert_eq!(dts_opts.out_dir.as_deref(), Some(Path::new("/project/types")));
+
+        let dts_opts = CheckCommand {
+            declaration_dir: Some(PathBuf::from("/cli/types")),
+            ..command()
+        }
+        .dts_options(&opts, &files)
+        .unwrap()
+        .unwrap();
+        assert_eq!(dts_opts.out_dir.as_deref(), Some(Path::new("/cli/types")));
+    }
+
+    fn codes(build: &Build) -> Vec<usize> {
+        build.errors.iter().map(|err| err.code()).collect()
+    }
+
+    #[test]
+    fn recheck_changed_files() {
+        let dir = TempDir::new("stc-check").unwrap();
+        let path = dir.path().join("index.ts");
+        fs::write(dir.path().join("tsconfig.json"), r#"{ "include": ["*.ts"] }"#).unwrap();
+        fs::write(&path, "const a: string = '';\n").unwrap();
+
+        let cmd = CheckCommand {
+            project: Some(dir.path().to_path_buf()),
+            declaration: false,
+            watch: true,
+            ..command()
+        };
+        let cm = Arc::new(SourceMap::default());
+        let handler = Arc::new(Handler::with_emitter(
+            true,
+            false,
+            Box::new(EmitterWriter::stderr(ColorConfig::Never, Some(cm.clone()), false, false)),
+        ));
+
+        let mut build = cmd.start(cm, handler).unwrap();
+        assert_eq!(codes(&build), Vec::<usize>::new());
+
+        fs::write(&path, "const a: string = 1;\n").unwrap();
+        assert!(cmd.recheck(&mut build, vec![path.clone()]).unwrap());
+        assert_eq!(codes(&build), vec![2322]);
+
+        // Previous versions of `index.ts` are larger than the current one, so a new
+        // checker is required.
+        fs::write(&path, "const a: number = 1;\n").unwrap();
+        assert!(!cmd.recheck(&mut build, vec![path.clone()]).unwrap());
+    }
+}
@@ -0,0 +1,506 @@
+//! Emission of `.d.ts` files.
+
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::{Context, Error};
+use rayon::prelude::*;
+use rnode::{NodeIdGenerator, RNode};
+use stc_ts_ast_rnode::RModule;
+use stc_ts_dts::isolated_dts;
+use stc_ts_errors::ErrorKind;
+use stc_ts_module_loader::is_dts;
+use stc_ts_type_checker::Checker;
+use stc_ts_utils::StcComments;
+use swc_common::{
+    comments::{CommentKind, Comments},
+    errors::Handler,
+    source_map::SourceMapGenConfig,
+    BytePos, FileName, LineCol, SourceMap, DUMMY_SP,
+};
+use swc_ecma_ast::{EsVersion, Module};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+
+/// Where `.d.ts` files are written.
+#[derive(Debug)]
+pub struct DtsOptions {
+    /// The directory structure under `root_dir` is preserved in `out_dir`.
+    pub root_dir: PathBuf,
+    /// If [None], `.d.ts` files are written next to input files.
+    pub out_dir: Option<PathBuf>,
+    /// Write `.d.ts.map` files next to `.d.ts` files.
+    pub declaration_map: bool,
+    /// Use [emit_isolated_dts] instead of [emit_dts].
+    pub isolated: bool,
+}
+
+/// Writes `.d.ts` files of `files`, which should be checked by `checker`.
+///
+/// Returns the number of written files.
+pub fn emit_dts(checker: &Checker, cm: &Arc<SourceMap>, files: &[PathBuf], opts: &DtsOptions) -> Result<usize, Error> {
+    let comments = jsdoc_comments(checker.comments());
+    let mut count = 0;
+
+    for path in files {
+        let out_path = match dts_path(path, opts) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let id = checker.id(&Arc::new(FileName::Real(path.clone())));
+        let module = match checker.take_dts(id) {
+            Some(v) => v,
+            // Taken by a previous call, and the module is not changed since then.
+            None => continue,
+        };
+
+        write_dts(cm, &comments, &module, path, &out_path, opts)?;
+
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Writes `.d.ts` files of `files` without type checking, in parallel.
+///
+/// Returns the number of written files and errors for declarations which
+/// require type inference.
+pub fn emit_isolated_dts(
+    cm: &Arc<SourceMap>,
+    handler: &Handler,
+    parser_config: TsConfig,
+    target: EsVersion,
+    files: &[PathBuf],
+    opts: &DtsOptions,
+) -> Result<(usize, Vec<stc_ts_errors::Error>), Error> {
+    let results = files
+        .par_iter()
+        .filter_map(|path| Some((path, dts_path(path, opts)?)))
+        .map(|(path, out_path)| -> Result<_, Error> {
+            let fm = cm.load_file(path).with_context(|| format!("failed to load `{}`", path.display()))?;
+            let comments = StcComments::default();
+
+            let lexer = Lexer::new(
+                Syntax::Typescript(TsConfig {
+                    tsx: path.extension().map(|v| v == "tsx").unwrap_or(false),
+                    ..parser_config
+                }),
+                target,
+                StringInput::from(&*fm),
+                Some(&comments),
+            );
+            let module = match Parser::new_from(lexer).parse_module() {
+                Ok(v) => v,
+                Err(err) => {
+                    err.into_diagnostic(handler).emit();
+                    return Ok(None);
+                }
+            };
+
+            let mut module = RModule::from_orig(&mut NodeIdGenerator::default(), module);
+            let errors = isolated_dts(&mut module.body);
+
+            write_dts(cm, &jsdoc_comments(&comments), &module.into_orig(), path, &out_path, opts)?;
+
+            Ok(Some(errors))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let count = results.iter().flatten().count();
+    let errors = results.into_iter().flatten().flatten().collect();
+
+    Ok((count, errors))
+}
+
+/// Writes the `.d.ts` file of `path` to `out_path`, along with the
+/// `.d.ts.map` file if enabled.
+fn write_dts(
+    cm: &Arc<SourceMap>,
+    comments: &StcComments,
+    module: &Module,
+    path: &Path,
+    out_path: &Path,
+    opts: &DtsOptions,
+) -> Result<(), Error> {
+    let mut mappings = vec![];
+    let mut code = print(cm, comments, module, opts.declaration_map.then_some(&mut mappings))
+        .with_context(|| format!("failed to print `.d.ts` of `{}`", path.display()))?;
+
+    if let Some(dir) = out_path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create `{}`", dir.display()))?;
+    }
+
+    if opts.declaration_map {
+        let map_path = map_path(out_path);
+        let map = build_source_map(cm, &mut mappings, &map_path)?;
+        fs::write(&map_path, map).with_context(|| format!("failed to write `{}`", map_path.display()))?;
+
+        code.push_str(&format!(
+            "//# sourceMappingURL={}\n",
+            map_path.file_name().unwrap_or_default().to_string_lossy()
+        ));
+    }
+
+    fs::write(out_path, code).with_context(|| format!("failed to write `{}`", out_path.display()))?;
+
+    Ok(())
+}
+
+/// Returns the longest common directory of files which are not declaration
+/// files, which is the default of `rootDir`.
+pub fn common_source_dir(files: &[PathBuf]) -> Option<PathBuf> {
+    let mut dirs = files.iter().filter(|path| !is_dts(path)).filter_map(|path| path.parent());
+
+    let mut common = dirs.next()?.to_path_buf();
+    for dir in dirs {
+        while !dir.starts_with(&common) {
+            if !common.pop() {
+                return None;
+            }
+        }
+    }
+
+    Some(common)
+}
+
+/// Returns TS6059 for each file which is not under `rootDir`.
+pub fn check_root_dir(files: &[PathBuf], opts: &DtsOptions) -> Vec<stc_ts_errors::Error> {
+    files
+        .iter()
+        .filter(|path| !is_dts(path) && !path.starts_with(&opts.root_dir))
+        .map(|path| {
+            stc_ts_errors::Error::from(ErrorKind::FileNotUnderRootDir {
+                span: DUMMY_SP,
+                file: path.to_string_lossy().into(),
+                root_dir: opts.root_dir.to_string_lossy().into(),
+            })
+        })
+        .collect()
+}
+
+/// Returns [None] for declaration files, which are not emitted.
+///
+/// Like tsc, files which are not under `root_dir` are written outside of
+/// `out_dir`, using their paths relative to `root_dir`. See [check_root_dir].
+pub fn dts_path(path: &Path, opts: &DtsOptions) -> Option<PathBuf> {
+    if is_dts(path) {
+        return None;
+    }
+
+    let dts_ext = match path.extension()?.to_str()? {
+        "ts" | "tsx" => "d.ts",
+        "mts" => "d.mts",
+        "cts" => "d.cts",
+        _ => return None,
+    };
+    let dts = path.with_extension(dts_ext);
+
+    match &opts.out_dir {
+        Some(out_dir) => {
+            let relative = pathdiff::diff_paths(&dts, &opts.root_dir)?;
+
+            let mut out_path = out_dir.clone();
+            for component in relative.components() {
+                match component {
+                    Component::ParentDir => {
+                        out_path.pop();
+                    }
+                    _ => out_path.push(component),
+                }
+            }
+            Some(out_path)
+        }
+        None => Some(dts),
+    }
+}
+
+/// Only JSDoc comments are preserved, as other comments may belong to code
+/// which is removed from `.d.ts` files.
+fn jsdoc_comments(comments: &StcComments) -> StcComments {
+    let jsdoc = StcComments::default();
+
+    for entry in comments.leading.iter() {
+        let docs = entry
+            .value()
+            .iter()
+            .filter(|c| c.kind == CommentKind::Block && c.text.starts_with('*'))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !docs.is_empty() {
+            jsdoc.add_leading_comments(*entry.key(), docs);
+        }
+    }
+
+    jsdoc
+}
+
+/// `index.d.ts` => `index.d.ts.map`
+fn map_path(dts_path: &Path) -> PathBuf {
+    let mut map_path = dts_path.as_os_str().to_owned();
+    map_path.push(".map");
+    map_path.into()
+}
+
+/// Builds the content of a `.d.ts.map` file from `mappings` collected while
+/// printing.
+fn build_source_map(cm: &SourceMap, mappings: &mut Vec<(BytePos, LineCol)>, map_path: &Path) -> Result<Vec<u8>, Error> {
+    let map = cm.build_source_map_with_config(
+        mappings,
+        None,
+        SourcesConfig {
+            map_dir: map_path.parent().unwrap_or_else(|| Path::new("")),
+        },
+    );
+
+    let mut buf = vec![];
+    map.to_writer(&mut buf).context("failed to serialize the source map")?;
+    Ok(buf)
+}
+
+/// Sources are referenced using paths relative to the `.d.ts.map` file, like
+/// tsc.
+struct SourcesConfig<'a> {
+    map_dir: &'a Path,
+}
+
+impl SourceMapGenConfig for SourcesConfig<'_> {
+    fn file_name_to_source(&self, f: &FileName) -> String {
+        match f {
+            FileName::Real(path) => pathdiff::diff_paths(path, self.map_dir)
+                .unwrap_or_else(|| path.clone())
+                .to_string_lossy()
+                .replace('\\', "/"),
+            _ => f.to_string(),
+        }
+    }
+
+    fn inline_sources_content(&self, _: &FileName) -> bool {
+        false
+    }
+}
+
+/// Positions of printed nodes are pushed to `mappings` if it's [Some].
+fn print(
+    cm: &Arc<SourceMap>,
+    comments: &StcComments,
+    module: &Module,
+    mappings: Option<&mut Vec<(BytePos, LineCol)>>,
+) -> Result<String, Error> {
+    let mut buf = vec![];
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            comments: Some(comments),
+            cm: cm.clone(),
+            wr: JsWriter::new(cm.clone(), "\n", &mut buf, mappings),
+        };
+
+        emitter.emit_module(module)?;
+    }
+
+    Ok(String::from_utf8(buf)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use stc_ts_builtin_types::Lib;
+    use stc_ts_env::{Env, ModuleConfig};
+    use stc_ts_file_analyzer::env::EnvFactory;
+    use stc_ts_module_loader::resolvers::node::NodeResolver;
+    use stc_ts_type_checker::Checker;
+    use swc_common::{
+        errors::{ColorConfig, EmitterWriter, Handler},
+        FileName, SourceMap,
+    };
+    use swc_ecma_ast::EsVersion;
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn handler(cm: &Arc<SourceMap>) -> Handler {
+        Handler::with_emitter(
+            true,
+            false,
+            Box::new(EmitterWriter::stderr(ColorConfig::Never, Some(cm.clone()), false, false)),
+        )
+    }
+
+    fn opts(root_dir: &Path, out_dir: Option<&Path>) -> DtsOptions {
+        DtsOptions {
+            root_dir: root_dir.to_path_buf(),
+            out_dir: out_dir.map(Path::to_path_buf),
+            declaration_map: false,
+            isolated: false,
+        }
+    }
+
+    #[test]
+    fn dts_paths() {
+        let root_dir = Path::new("/project/src");
+        let next_to_inputs = opts(root_dir, None);
+        let out_dir = opts(root_dir, Some(Path::new("/project/types")));
+
+        assert_eq!(
+            dts_path(Path::new("/project/src/a/b.ts"), &next_to_inputs),
+            Some(PathBuf::from("/project/src/a/b.d.ts"))
+        );
+        assert_eq!(
+            dts_path(Path::new("/project/src/a/b.ts"), &out_dir),
+            Some(PathBuf::from("/project/types/a/b.d.ts"))
+        );
+        assert_eq!(
+            dts_path(Path::new("/project/src/c.tsx"), &out_dir),
+            Some(PathBuf::from("/project/types/c.d.ts"))
+        );
+        assert_eq!(
+            dts_path(Path::new("/project/src/d.mts"), &out_dir),
+            Some(PathBuf::from("/project/types/d.d.mts"))
+        );
+        assert_eq!(
+            dts_path(Path::new("/project/src/e.cts"), &out_dir),
+            Some(PathBuf::from("/project/types/e.d.cts"))
+        );
+        assert_eq!(dts_path(Path::new("/project/src/f.d.ts"), &out_dir), None);
+        assert_eq!(dts_path(Path::new("/project/src/g.js"), &out_dir), None);
+    }
+
+    #[test]
+    fn files_outside_root_dir() {
+        let opts = opts(Path::new("/project/src"), Some(Path::new("/project/types")));
+        let files = ["/project/src/a.ts", "/project/other/b.ts", "/project/other/c.d.ts"].map(PathBuf::from);
+
+        let errors = check_root_dir(&files, &opts);
+
+        assert_eq!(errors.iter().map(|err| err.code()).collect::<Vec<_>>(), vec![6059]);
+        assert_eq!(
+            errors[0].message(),
+            "File '/project/other/b.ts' is not under 'rootDir' '/project/src'. 'rootDir' is expected to contain all source files."
+        );
+        // Like tsc, it's written relative to `out_dir`.
+        assert_eq!(dts_path(&files[1], &opts), Some(PathBuf::from("/project/other/b.d.ts")));
+    }
+
+    #[test]
+    fn emit_to_out_dir() {
+        let dir = TempDir::new("stc-emit").unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src/lib")).unwrap();
+        fs::write(
+            root.join("src/index.ts"),
+            "import { Name } from './lib/name';\n\n/** Greets. */\nexport function greet(name: Name): string {\n    // Not a JSDoc \
+             comment.\n    return name;\n}\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/lib/name.ts"), "export type Name = string;\n").unwrap();
+
+        let files = ["src/index.ts", "src/lib/name.ts"].map(|file| root.join(file));
+        let opts = opts(&root.join("src"), Some(&root.join("types")));
+
+        let cm = Arc::new(SourceMap::default());
+        let checker = Checker::new(
+            cm.clone(),
+            Arc::new(handler(&cm)),
+            Env::simple(Default::default(), EsVersion::Es5, ModuleConfig::None, &[Lib::Es5]),
+            Default::default(),
+            None,
+            Arc::new(NodeResolver::default()),
+        );
+        for path in &files {
+            checker.check(Arc::new(FileName::Real(path.clone())));
+        }
+
+        let count = checker.run(|| emit_dts(&checker, &cm, &files, &opts)).unwrap();
+        assert_eq!(count, 2);
+
+        let index = fs::read_to_string(root.join("types/index.d.ts")).unwrap();
+        assert!(index.contains("/** Greets. */"), "{}", index);
+        assert!(index.contains("function greet(name: Name): string;"), "{}", index);
+        assert!(!index.contains("Not a JSDoc comment"), "{}", index);
+
+        let name = fs::read_to_string(root.join("types/lib/name.d.ts")).unwrap();
+        assert!(name.contains("export type Name = string;"), "{}", name);
+
+        // Modules are emitted once unless they are changed.
+        assert_eq!(checker.run(|| emit_dts(&checker, &cm, &files, &opts)).unwrap(), 0);
+    }
+
+    #[test]
+    fn emit_isolated_next_to_inputs() {
+        let dir = TempDir::new("stc-emit").unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("a.ts"),
+            "export const a = 'a';\nexport function f(x: number) {\n    return x;\n}\n",
+        )
+        .unwrap();
+        fs::write(root.join("b.d.ts"), "export declare const b: number;\n").unwrap();
+
+        let files = ["a.ts", "b.d.ts"].map(|file| root.join(file));
+        let opts = DtsOptions {
+            isolated: true,
+            ..opts(root, None)
+        };
+
+        let cm = Arc::new(SourceMap::default());
+        let (count, errors) = emit_isolated_dts(&cm, &handler(&cm), Default::default(), EsVersion::latest(), &files, &opts).unwrap();
+
+        assert_eq!(count, 1);
+        // The return type of `f` requires type inference.
+        assert_eq!(errors.iter().map(|err| err.code()).collect::<Vec<_>>(), vec![9007]);
+
+        let a = fs::read_to_string(root.join("a.d.ts")).unwrap();
+        assert!(a.contains("export declare const a: 'a';"), "{}", a);
+        assert_eq!(
+            fs::read_to_string(root.join("b.d.ts")).unwrap(),
+            "export declare const b: number;\n"
+        );
+    }
+
+    #[test]
+    fn declaration_map() {
+        let dir = TempDir::new("stc-emit").unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/index.ts"),
+            "export const a: number = 1;\n\nexport function f(x: string): string {\n    return x;\n}\n",
+        )
+        .unwrap();
+
+        let files = [root.join("src/index.ts")];
+        let opts = DtsOptions {
+            declaration_map: true,
+            isolated: true,
+            ..opts(&root.join("src"), Some(&root.join("types")))
+        };
+
+        let cm = Arc::new(SourceMap::default());
+        emit_isolated_dts(&cm, &handler(&cm), Default::default(), EsVersion::latest(), &files, &opts).unwrap();
+
+        let dts = fs::read_to_string(root.join("types/index.d.ts")).unwrap();
+        assert!(dts.ends_with("//# sourceMappingURL=index.d.ts.map\n"), "{}", dts);
+
+        let map = sourcemap::SourceMap::from_slice(&fs::read(root.join("types/index.d.ts.map")).unwrap()).unwrap();
+        // Relative to the `.d.ts.map` file.
+        assert_eq!(map.get_source(0), Some("../src/index.ts"));
+
+        // `f` is at line 3 and column 17 of the source.
+        let (line, col) = dts.lines().enumerate().find_map(|(line, s)| Some((line, s.find("f(")?))).unwrap();
+        let token = map.lookup_token(line as u32, col as u32).unwrap();
+        assert_eq!((token.get_dst_line(), token.get_dst_col()), (line as u32, col as u32));
+        assert_eq!((token.get_src_line(), token.get_src_col()), (2, 16));
+        assert_eq!(token.get_source(), Some("../src/index.ts"));
+    }
+}
@@ -18,13 +18,17 @@ use swc_ecma_ast::EsVersion;
 use swc_ecma_parser::TsConfig;
 use tracing_subscriber::EnvFilter;
 
-use crate::check::TestCommand;
+use crate::check::{CheckCommand, TestCommand};
 
 mod check;
+mod emit;
+mod report;
+mod watch;
 
 #[derive(Debug, Parser)]
 #[command(name = "stc", about = "Super fast type checker for typescript", author, rename_all = "camel")]
 enum Command {
+    Check(CheckCommand),
     Test(TestCommand),
     Lsp(LspCommand),
 }
@@ -35,6 +39,8 @@ async fn main() -> Result<(), Error> {
 
     env_logger::init();
 
+    stc_ts_builtin_snapshots::register();
+
     let sub = tracing_subscriber::FmtSubscriber::builder()
         .with_target(false)
         .with_ansi(true)
@@ -49,7 +55,7 @@ async fn main() -> Result<(), Error> {
 
     let cm = Arc::new(SourceMap::default());
     let handler = {
-        let emitter = Box::new(EmitterWriter::stderr(ColorConfig::Always, Some(cm.clone()), false, false));
+        let emitter = Box::new(EmitterWriter::stderr(ColorConfig::Auto, Some(cm.clone()), false, false));
         Arc::new(Handler::with_emitter(true, false, emitter))
     };
 
@@ -61,7 +67,15 @@ async fn main() -> Result<(), Error> {
         log::info!("Initialization took {:?}", end - start);
     }
 
+    let mut error_count = 0;
+
     match command {
+        Command::Check(cmd) => {
+            error_count = cmd.run(|cm| {
+                let emitter = Box::new(EmitterWriter::stderr(ColorConfig::Auto, Some(cm.clone()), false, false));
+                Arc::new(Handler::with_emitter(true, false, emitter))
+            })?;
+        }
         Command::Test(cmd) => {
             let libs = {
                 let start = Instant::now();
@@ -93,7 +107,7 @@ async fn main() -> Result<(), Error> {
                     env.clone(),
                     TsConfig { ..Default::default() },
                     None,
-                    Arc::new(NodeResolver),
+                    Arc::new(NodeResolver::default()),
                 );
 
                 checker.load_typings(&path, None, cmd.types.as_deref());
@@ -113,7 +127,7 @@ async fn main() -> Result<(), Error> {
                     env,
                     TsConfig { ..Default::default() },
                     None,
-                    Arc::new(NodeResolver),
+                    Arc::new(NodeResolver::default()),
                 );
 
                 checker.check(Arc::new(FileName::Real(path)));
@@ -144,5 +158,9 @@ async fn main() -> Result<(), Error> {
 
     log::info!("Done in {:?}", end - start);
 
+    if error_count > 0 {
+        std::process::exit(1);
+    }
+
     Ok(())
 }
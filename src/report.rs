@@ -0,0 +1,359 @@
+//! Output formats of errors.
+
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use stc_ts_errors::{Error, ErrorKind};
+use swc_common::{errors::Handler, BytePos, FileName, Loc, SourceMap, Spanned};
+
+/// Format used to report errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable output with code frames, printed to stderr.
+    ///
+    /// Colors are used only if stderr is a terminal.
+    Pretty,
+    /// `file(line,col): error TSxxxx: message`, which is the format of `tsc
+    /// --pretty false`.
+    Tsc,
+    /// A JSON object per line.
+    Json,
+    /// SARIF 2.1.0 log.
+    Sarif,
+    /// Workflow commands of GitHub Actions, which are shown as annotations.
+    Github,
+}
+
+/// Position of an error. Lines and columns are 1-based, and columns are
+/// counted in UTF-16 code units like `tsc`, LSP and SARIF consumers expect.
+struct Location {
+    file: String,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+/// Data of an error required to print it.
+struct Diagnostic {
+    loc: Option<Location>,
+    code: usize,
+    message: String,
+}
+
+impl Diagnostic {
+    fn new(cm: &SourceMap, err: &Error) -> Self {
+        let span = err.span();
+
+        // Errors for invalid options do not have a location.
+        let loc = if span.is_dummy() {
+            None
+        } else {
+            let lo = cm.lookup_char_pos(span.lo);
+            let hi = cm.lookup_char_pos(span.hi);
+
+            Some(Location {
+                file: display_file_name(&lo.file.name),
+                line: lo.line,
+                column: utf16_column(&lo, span.lo) + 1,
+                end_line: hi.line,
+                end_column: utf16_column(&hi, span.hi) + 1,
+            })
+        };
+
+        Diagnostic {
+            loc,
+            code: ErrorKind::normalize_error_code(err.code()),
+            message: err.message(),
+        }
+    }
+}
+
+/// 0-based column of `pos` in UTF-16 code units. `loc` is the location of
+/// `pos`, which only counts chars.
+fn utf16_column(loc: &Loc, pos: BytePos) -> usize {
+    let file = &loc.file;
+    let line_start = file.lines.get(loc.line - 1).copied().unwrap_or(file.start_pos);
+    let start = (line_start - file.start_pos).0 as usize;
+    let end = (pos - file.start_pos).0 as usize;
+
+    file.src[start..end].chars().map(char::len_utf16).sum()
+}
+
+/// Reports `errors` using `format`.
+///
+/// [Format::Pretty] uses `handler`, and other formats are printed to stdout so
+/// they can be piped to other tools.
+pub fn report(format: Format, cm: &SourceMap, handler: &Handler, errors: &[Error]) -> io::Result<()> {
+    if format == Format::Pretty {
+        for err in errors {
+            err.emit(handler);
+        }
+        return Ok(());
+    }
+
+    let diagnostics = errors.iter().map(|err| Diagnostic::new(cm, err)).collect::<Vec<_>>();
+
+    let stdout = io::stdout();
+    let mut w = stdout.lock();
+
+    match format {
+        Format::Pretty => unreachable!(),
+        Format::Tsc => write_tsc(&mut w, &diagnostics)?,
+        Format::Json => write_json(&mut w, &diagnostics)?,
+        Format::Sarif => write_sarif(&mut w, &diagnostics)?,
+        Format::Github => write_github(&mut w, &diagnostics)?,
+    }
+
+    w.flush()
+}
+
+fn write_tsc(w: &mut dyn Write, diagnostics: &[Diagnostic]) -> io::Result<()> {
+    for d in diagnostics {
+        match &d.loc {
+            Some(loc) => writeln!(w, "{}({},{}): error TS{}: {}", loc.file, loc.line, loc.column, d.code, d.message)?,
+            None => writeln!(w, "error TS{}: {}", d.code, d.message)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_json(w: &mut dyn Write, diagnostics: &[Diagnostic]) -> io::Result<()> {
+    for d in diagnostics {
+        let value = match &d.loc {
+            Some(loc) => json!({
+                "file": loc.file,
+                "line": loc.line,
+                "column": loc.column,
+                "end": {
+                    "line": loc.end_line,
+                    "column": loc.end_column,
+                },
+                "code": d.code,
+                "message": d.message,
+            }),
+            None => json!({
+                "code": d.code,
+                "message": d.message,
+            }),
+        };
+
+        serde_json::to_writer(&mut *w, &value)?;
+        writeln!(w)?;
+    }
+
+    Ok(())
+}
+
+/// https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+fn write_sarif(w: &mut dyn Write, diagnostics: &[Diagnostic]) -> io::Result<()> {
+    let mut codes = diagnostics.iter().map(|d| d.code).collect::<Vec<_>>();
+    codes.sort_unstable();
+    codes.dedup();
+
+    let rules = codes.iter().map(|code| json!({ "id": format!("TS{}", code) })).collect::<Vec<_>>();
+
+    let results = diagnostics
+        .iter()
+        .map(|d| {
+            let mut result = json!({
+                "ruleId": format!("TS{}", d.code),
+                "level": "error",
+                "message": {
+                    "text": d.message,
+                },
+            });
+
+            if let Some(loc) = &d.loc {
+                result["locations"] = json!([{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": loc.file.replace('\\', "/"),
+                        },
+                        "region": {
+                            "startLine": loc.line,
+                            "startColumn": loc.column,
+                            "endLine": loc.end_line,
+                            "endColumn": loc.end_column,
+                        },
+                    },
+                }]);
+            }
+
+            result
+        })
+        .collect::<Vec<Value>>();
+
+    let log = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "stc",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_writer_pretty(&mut *w, &log)?;
+    writeln!(w)
+}
+
+/// https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message
+fn write_github(w: &mut dyn Write, diagnostics: &[Diagnostic]) -> io::Result<()> {
+    for d in diagnostics {
+        let mut props = vec![];
+
+        if let Some(loc) = &d.loc {
+            props.push(format!("file={}", escape_github_property(&loc.file)));
+            props.push(format!("line={}", loc.line));
+            props.push(format!("col={}", loc.column));
+            props.push(format!("endLine={}", loc.end_line));
+            props.push(format!("endColumn={}", loc.end_column));
+        }
+        props.push(format!("title=TS{}", d.code));
+
+        writeln!(w, "::error {}::{}", props.join(","), escape_github_data(&d.message))?;
+    }
+
+    Ok(())
+}
+
+fn escape_github_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_github_property(s: &str) -> String {
+    escape_github_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Paths are printed relative to the current directory, like `tsc` does.
+fn display_file_name(name: &FileName) -> String {
+    match name {
+        FileName::Real(path) => {
+            let cwd = std::env::current_dir().ok();
+            let path = cwd.as_deref().and_then(|cwd| path.strip_prefix(cwd).ok()).unwrap_or(path);
+
+            path.to_string_lossy().into_owned()
+        }
+        _ => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use swc_common::{BytePos, FileName, SourceMap, Span, SyntaxContext};
+
+    use super::*;
+
+    fn diagnostics(src: &str, lo: usize, hi: usize) -> Vec<Diagnostic> {
+        let cm = SourceMap::default();
+        let fm = cm.new_source_file(FileName::Real(PathBuf::from("input.ts")), src.into());
+        let span = Span::new(
+            fm.start_pos + BytePos(lo as u32),
+            fm.start_pos + BytePos(hi as u32),
+            SyntaxContext::empty(),
+        );
+
+        let err = Error::from(ErrorKind::ClassPropNotInitialized { span });
+
+        vec![Diagnostic::new(&cm, &err)]
+    }
+
+    fn output(f: fn(&mut dyn Write, &[Diagnostic]) -> io::Result<()>, diagnostics: &[Diagnostic]) -> String {
+        let mut buf = vec![];
+        f(&mut buf, diagnostics).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn columns_are_utf16() {
+        // `😀` is 4 bytes in UTF-8, 1 char and 2 UTF-16 code units.
+        let src = "let a = '😀';\nfoo;\n";
+        let d = diagnostics(src, src.find("foo").unwrap(), src.find("foo").unwrap() + 3);
+        let loc = d[0].loc.as_ref().unwrap();
+        assert_eq!((loc.line, loc.column, loc.end_line, loc.end_column), (2, 1, 2, 4));
+
+        let src = "'😀' + foo;";
+        let d = diagnostics(src, src.find("foo").unwrap(), src.find("foo").unwrap() + 3);
+        let loc = d[0].loc.as_ref().unwrap();
+        assert_eq!((loc.line, loc.column, loc.end_line, loc.end_column), (1, 8, 1, 11));
+    }
+
+    #[test]
+    fn tsc() {
+        let d = diagnostics("foo;", 0, 3);
+        assert_eq!(
+            output(write_tsc, &d),
+            "input.ts(1,1): error TS2564: Property has no initializer and is not definitely assigned in the constructor.\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let d = diagnostics("foo;", 0, 3);
+        let value: Value = serde_json::from_str(&output(write_json, &d)).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "file": "input.ts",
+                "line": 1,
+                "column": 1,
+                "end": {
+                    "line": 1,
+                    "column": 4,
+                },
+                "code": 2564,
+                "message": "Property has no initializer and is not definitely assigned in the constructor.",
+            })
+        );
+    }
+
+    #[test]
+    fn sarif() {
+        let d = diagnostics("foo;", 0, 3);
+        let value: Value = serde_json::from_str(&output(write_sarif, &d)).unwrap();
+        let run = &value["runs"][0];
+
+        assert_eq!(value["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"], json!([{ "id": "TS2564" }]));
+        assert_eq!(run["results"][0]["ruleId"], "TS2564");
+        assert_eq!(
+            run["results"][0]["message"]["text"],
+            "Property has no initializer and is not definitely assigned in the constructor."
+        );
+        assert_eq!(
+            run["results"][0]["locations"][0]["physicalLocation"]["region"],
+            json!({
+                "startLine": 1,
+                "startColumn": 1,
+                "endLine": 1,
+                "endColumn": 4,
+            })
+        );
+    }
+
+    #[test]
+    fn github() {
+        let d = diagnostics("foo;", 0, 3);
+        assert_eq!(
+            output(write_github, &d),
+            "::error file=input.ts,line=1,col=1,endLine=1,endColumn=4,title=TS2564::Property has no initializer and is not definitely \
+             assigned in the constructor.\n"
+        );
+    }
+
+    #[test]
+    fn github_escapes() {
+        assert_eq!(escape_github_data("a%b\nc"), "a%25b%0Ac");
+        assert_eq!(escape_github_property("a:b,c"), "a%3Ab%2Cc");
+    }
+}
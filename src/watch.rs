@@ -0,0 +1,109 @@
+//! Watching files for `--watch`.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, RecvTimeoutError},
+    time::Duration,
+};
+
+use anyhow::{Context, Error};
+use notify::{EventKind, RecursiveMode, Watcher};
+
+/// Events in this duration are handled at once, because editors often write a
+/// file multiple times on save.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Returns true if `path` is one of `config_files`, which are the loaded
+/// `tsconfig.json` and config files extended by it.
+pub fn is_config(path: &Path, config_files: &[PathBuf]) -> bool {
+    config_files.iter().any(|config| config == path)
+}
+
+/// Returns true if a change of `path` may affect type checking.
+fn is_relevant(path: &Path, config_files: &[PathBuf]) -> bool {
+    if is_config(path, config_files) {
+        return true;
+    }
+
+    matches!(path.extension().and_then(|ext| ext.to_str()), Some("ts" | "tsx" | "mts" | "cts"))
+}
+
+/// Watches `dir` recursively and calls `on_change` with changed files.
+/// `config_files` outside of `dir` are watched too.
+///
+/// Returns if `on_change` returns `false`.
+pub fn watch(dir: &Path, config_files: &[PathBuf], mut on_change: impl FnMut(Vec<PathBuf>) -> Result<bool, Error>) -> Result<(), Error> {
+    let (tx, rx) = channel();
+
+    let mut watcher = notify::recommended_watcher(tx).context("failed to create a file watcher")?;
+    watcher
+        .watch(dir, RecursiveMode::Recursive)
+        .with_context(|| format!("failed to watch `{}`", dir.display()))?;
+    for config in config_files.iter().filter(|config| !config.starts_with(dir)) {
+        watcher
+            .watch(config, RecursiveMode::NonRecursive)
+            .with_context(|| format!("failed to watch `{}`", config.display()))?;
+    }
+
+    log::info!("Watching `{}` for changes", dir.display());
+
+    loop {
+        let mut changed = vec![];
+
+        let mut event = rx.recv().context("file watcher is stopped")?;
+        loop {
+            let event = event.context("failed to watch files")?;
+            if !matches!(event.kind, EventKind::Access(..)) {
+                for path in event.paths {
+                    if is_relevant(&path, config_files) && !changed.contains(&path) {
+                        changed.push(path);
+                    }
+                }
+            }
+
+            event = match rx.recv_timeout(DEBOUNCE) {
+                Ok(event) => event,
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return Err(Error::msg("file watcher is stopped")),
+            };
+        }
+
+        if changed.is_empty() {
+            continue;
+        }
+
+        if !on_change(changed)? {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{is_config, is_relevant};
+
+    #[test]
+    fn only_loaded_configs_are_configs() {
+        let config_files = vec![PathBuf::from("/project/tsconfig.json"), PathBuf::from("/shared/base.json")];
+
+        assert!(is_config(Path::new("/project/tsconfig.json"), &config_files));
+        assert!(is_config(Path::new("/shared/base.json"), &config_files));
+        assert!(!is_config(Path::new("/project/package.json"), &config_files));
+        assert!(!is_config(Path::new("/project/src/data.json"), &config_files));
+        assert!(!is_config(Path::new("/project/tsconfig.json"), &[]));
+    }
+
+    #[test]
+    fn relevant_files() {
+        let config_files = vec![PathBuf::from("/project/tsconfig.json")];
+
+        assert!(is_relevant(Path::new("/project/tsconfig.json"), &config_files));
+        assert!(is_relevant(Path::new("/project/src/a.ts"), &config_files));
+        assert!(is_relevant(Path::new("/project/src/b.tsx"), &config_files));
+        assert!(is_relevant(Path::new("/project/src/c.d.ts"), &config_files));
+        assert!(!is_relevant(Path::new("/project/src/d.js"), &config_files));
+        assert!(!is_relevant(Path::new("/project/package.json"), &config_files));
+    }
+}